@@ -4,10 +4,7 @@ use std::path::{Path, PathBuf};
 
 use bumpalo::Bump;
 use roc_error_macros::{internal_error, user_error};
-use roc_fmt::def::fmt_defs;
-use roc_fmt::header::fmt_header;
-use roc_fmt::Buf;
-use roc_fmt::MigrationFlags;
+use roc_fmt::{format_ast, FormatOptions, MigrationFlags};
 use roc_parse::ast::{FullAst, SpacesBefore};
 use roc_parse::header::parse_module_defs;
 use roc_parse::normalize::Normalize;
@@ -192,14 +189,13 @@ pub fn format_src(arena: &Bump, src: &str, flags: MigrationFlags) -> Result<Stri
     let ast = arena.alloc(parse_all(arena, src).unwrap_or_else(|e| {
         user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
     }));
-    let mut buf = Buf::new_in(arena, flags);
-    fmt_all(&mut buf, ast);
+    let formatted: &str = arena.alloc_str(&format_ast(ast, flags, FormatOptions::default()));
 
-    let reparsed_ast = match arena.alloc(parse_all(arena, buf.as_str())) {
+    let reparsed_ast = match arena.alloc(parse_all(arena, formatted)) {
         Ok(ast) => ast,
         Err(e) => {
             return Err(FormatProblem::ParsingFailed {
-                formatted_src: buf.as_str().to_string(),
+                formatted_src: formatted.to_string(),
                 parse_err: format!("{:?}", e),
             });
         }
@@ -217,25 +213,23 @@ pub fn format_src(arena: &Bump, src: &str, flags: MigrationFlags) -> Result<Stri
         && format!("{ast_normalized:?}") != format!("{reparsed_ast_normalized:?}")
     {
         return Err(FormatProblem::ReformattingChangedAst {
-            formatted_src: buf.as_str().to_string(),
+            formatted_src: formatted.to_string(),
             ast_before: format!("{ast_normalized:#?}\n"),
             ast_after: format!("{reparsed_ast_normalized:#?}\n"),
         });
     }
 
     // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
-    let mut reformatted_buf = Buf::new_in(arena, flags);
+    let reformatted = format_ast(reparsed_ast, flags, FormatOptions::default());
 
-    fmt_all(&mut reformatted_buf, reparsed_ast);
-
-    if buf.as_str() != reformatted_buf.as_str() {
+    if formatted != reformatted {
         return Err(FormatProblem::ReformattingUnstable {
-            formatted_src: buf.as_str().to_string(),
-            reformatted_src: reformatted_buf.as_str().to_string(),
+            formatted_src: formatted.to_string(),
+            reformatted_src: reformatted,
         });
     }
 
-    Ok(buf.as_str().to_string())
+    Ok(formatted.to_string())
 }
 
 fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<FullAst<'a>, SyntaxError<'a>> {
@@ -255,14 +249,6 @@ fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<FullAst<'a>, SyntaxErr
     })
 }
 
-fn fmt_all<'a>(buf: &mut Buf<'a>, ast: &'a FullAst) {
-    fmt_header(buf, &ast.header);
-
-    fmt_defs(buf, &ast.defs, 0);
-
-    buf.fmt_end_of_file();
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
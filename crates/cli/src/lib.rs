@@ -80,6 +80,7 @@ pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
 pub const FLAG_OUTPUT: &str = "output";
 pub const FLAG_FUZZ: &str = "fuzz";
 pub const FLAG_MAIN: &str = "main";
+pub const FLAG_ALLOW_SHADOWING: &str = "allow-shadowing";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const GLUE_DIR: &str = "GLUE_DIR";
 pub const GLUE_SPEC: &str = "GLUE_SPEC";
@@ -374,6 +375,13 @@ pub fn build_app() -> Command {
             .arg(flag_main.clone())
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(
+                Arg::new(FLAG_ALLOW_SHADOWING)
+                    .long(FLAG_ALLOW_SHADOWING)
+                    .help("Report shadowed names as warnings instead of errors")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file to check")
@@ -510,7 +518,7 @@ struct ModuleTestResults {
 #[cfg(not(windows))]
 pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
     use roc_build::program::report_problems_monomorphized;
-    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError};
+    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, ShadowPolicy};
     use roc_packaging::cache;
 
     let start_time = Instant::now();
@@ -569,6 +577,8 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
         let load_config = LoadConfig {
             target,
             function_kind,
+            step_budget: None,
+            shadow_policy: ShadowPolicy::Disallow,
             // TODO: expose this from CLI?
             render: roc_reporting::report::RenderTarget::ColorTerminal,
             palette: roc_reporting::report::DEFAULT_PALETTE,
@@ -951,6 +961,7 @@ pub fn build(
         emit_debug_info,
         emit_llvm_ir,
         fuzz,
+        overflow_checks: true,
     };
 
     let load_config = standard_load_config(target, build_ordering, threading);
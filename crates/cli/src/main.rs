@@ -5,17 +5,17 @@ use roc_build::program::{check_file, CodeGenBackend};
 use roc_cli::{
     build_app, format_files, format_src, test, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK,
     CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GLUE, CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN, CMD_TEST,
-    CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_LIB, FLAG_MAIN, FLAG_MIGRATE,
-    FLAG_NO_COLOR, FLAG_NO_HEADER, FLAG_NO_LINK, FLAG_OUTPUT, FLAG_PP_DYLIB, FLAG_PP_HOST,
-    FLAG_PP_PLATFORM, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, GLUE_DIR, GLUE_SPEC,
-    ROC_FILE, VERSION,
+    CMD_VERSION, DIRECTORY_OR_FILES, FLAG_ALLOW_SHADOWING, FLAG_CHECK, FLAG_DEV, FLAG_LIB,
+    FLAG_MAIN, FLAG_MIGRATE, FLAG_NO_COLOR, FLAG_NO_HEADER, FLAG_NO_LINK, FLAG_OUTPUT,
+    FLAG_PP_DYLIB, FLAG_PP_HOST, FLAG_PP_PLATFORM, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME,
+    GLUE_DIR, GLUE_SPEC, ROC_FILE, VERSION,
 };
 use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
 use roc_fmt::MigrationFlags;
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
-use roc_load::{LoadingProblem, Threading};
+use roc_load::{LoadingProblem, ShadowPolicy, Threading};
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_target::Target;
 use std::fs::{self, FileType};
@@ -206,6 +206,11 @@ fn main() -> io::Result<()> {
                 Some(1) => Threading::Single,
                 Some(n) => Threading::AtMost(*n),
             };
+            let shadow_policy = if matches.get_flag(FLAG_ALLOW_SHADOWING) {
+                ShadowPolicy::WarnAndAllow
+            } else {
+                ShadowPolicy::Disallow
+            };
 
             let opt_main_path = matches.get_one::<PathBuf>(FLAG_MAIN);
 
@@ -246,6 +251,7 @@ fn main() -> io::Result<()> {
                             emit_timings,
                             RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                             threading,
+                            shadow_policy,
                         ) {
                             Ok((problems, total_time)) => {
                                 problems.print_error_warning_count(total_time);
@@ -279,6 +285,7 @@ fn main() -> io::Result<()> {
                         emit_timings,
                         RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                         threading,
+                        shadow_policy,
                     ) {
                         Ok((problems, total_time)) => {
                             problems.print_error_warning_count(total_time);
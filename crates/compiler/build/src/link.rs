@@ -1237,7 +1237,6 @@ pub fn llvm_module_to_dylib(
     opt_level: OptLevel,
 ) -> Result<Library, Error> {
     use crate::target::{self, convert_opt_level};
-    use inkwell::targets::{FileType, RelocMode};
 
     let dir = tempfile::tempdir().unwrap();
     let filename = PathBuf::from("Test.roc");
@@ -1247,12 +1246,7 @@ pub fn llvm_module_to_dylib(
     app_o_file.set_file_name("app.o");
 
     // Emit the .o file using position-independent code (PIC) - needed for dylibs
-    let reloc = RelocMode::PIC;
-    let target_machine =
-        target::target_machine(target, convert_opt_level(opt_level), reloc).unwrap();
-
-    target_machine
-        .write_to_file(module, FileType::Object, &app_o_file)
+    target::write_object_file(module, target, convert_opt_level(opt_level), &app_o_file)
         .expect("Writing .o file failed");
 
     // Link app.o into a dylib - e.g. app.so or app.dylib
@@ -1,4 +1,7 @@
+use inkwell::module::Module;
+use inkwell::support::LLVMString;
 use roc_error_macros::internal_error;
+use roc_gen_llvm::llvm::build::verify_module;
 use roc_mono::ir::OptLevel;
 use roc_target::Target;
 use std::path::Path;
@@ -21,6 +24,25 @@ pub fn get_llvm_passes_str(opt_level: OptLevel) -> &'static str {
     }
 }
 
+/// Runs the pass pipeline of `opt_level` (see [get_llvm_passes_str]) on `module`.
+pub fn run_llvm_passes(
+    module: &Module,
+    target: Target,
+    opt_level: OptLevel,
+) -> Result<(), LLVMString> {
+    let inkwell_opt_level = crate::target::convert_opt_level(opt_level);
+    let inkwell_llvm_passes = get_llvm_passes_str(opt_level);
+    let inkwell_target_machine =
+        crate::target::target_machine(target, inkwell_opt_level, inkwell::targets::RelocMode::PIC)
+            .unwrap_or_else(|| internal_error!("invalid target machine"));
+
+    module.run_passes(
+        inkwell_llvm_passes,
+        &inkwell_target_machine,
+        inkwell::passes::PassBuilderOptions::create(),
+    )
+}
+
 pub fn optimize_llvm_ir(
     env: &roc_gen_llvm::llvm::build::Env,
     target: Target,
@@ -38,46 +60,80 @@ pub fn optimize_llvm_ir(
     // For real compilation, we may not want to pay the cost.
 
     // Verify the module before optimizing
-    if let Err(errors) = env.module.verify() {
+    if let Err(error) = verify_module(env.module) {
         // write the ll code to a file, so we can modify it
         env.module.print_to_file(ll_file_path).unwrap();
 
         internal_error!(
             "😱 LLVM errors when defining module; I wrote the full LLVM IR to {:?}\n\n {}",
             ll_file_path,
-            errors.to_string(),
+            error.message,
         );
     }
 
     // Uncomment this to see the module's optimized LLVM instruction output:
     // env.module.print_to_stderr();
 
-    let inkwell_opt_level = crate::target::convert_opt_level(opt_level);
-    let inkwell_llvm_passes = get_llvm_passes_str(opt_level);
-    let inkwell_target_machine =
-        crate::target::target_machine(target, inkwell_opt_level, inkwell::targets::RelocMode::PIC)
-            .unwrap_or_else(|| internal_error!("invalid target machine"));
-
-    env.module
-        .run_passes(
-            inkwell_llvm_passes,
-            &inkwell_target_machine,
-            inkwell::passes::PassBuilderOptions::create(),
-        )
+    run_llvm_passes(env.module, target, opt_level)
         .unwrap_or_else(|e| internal_error!("invalid llvm optimization passes: {:?}", e));
 
     // Verify the module after optimizing
-    if let Err(errors) = env.module.verify() {
+    if let Err(error) = verify_module(env.module) {
         // write the ll code to a file, so we can modify it
         env.module.print_to_file(ll_file_path).unwrap();
 
         internal_error!(
             "😱 LLVM errors when optimizing module; I wrote the full LLVM IR to {:?}\n\n {}",
             ll_file_path,
-            errors.to_string(),
+            error.message,
         );
     }
 
     // Uncomment this to see the module's optimized LLVM instruction output:
     // env.module.print_to_stderr();
 }
+
+#[cfg(all(test, feature = "target-x86_64"))]
+mod test {
+    use super::*;
+    use inkwell::context::Context;
+
+    /// IR for a function that returns a constant through a stack slot, after running the
+    /// passes of `opt_level`
+    fn optimized_ir(opt_level: OptLevel) -> String {
+        let context = Context::create();
+        let module = context.create_module("app");
+        let i64_type = context.i64_type();
+
+        let function = module.add_function("answer", i64_type.fn_type(&[], false), None);
+        let builder = context.create_builder();
+        builder.position_at_end(context.append_basic_block(function, "entry"));
+
+        let slot = builder.build_alloca(i64_type, "slot").unwrap();
+        builder
+            .build_store(slot, i64_type.const_int(42, false))
+            .unwrap();
+        let answer = builder.build_load(i64_type, slot, "answer").unwrap();
+        builder.build_return(Some(&answer)).unwrap();
+
+        run_llvm_passes(&module, Target::LinuxX64, opt_level).unwrap();
+
+        module.print_to_string().to_string()
+    }
+
+    #[test]
+    fn optimize_folds_constant_function() {
+        let ir = optimized_ir(OptLevel::Optimize);
+
+        assert!(ir.contains("ret i64 42"), "{ir}");
+        assert!(!ir.contains("alloca"), "{ir}");
+    }
+
+    #[test]
+    fn development_does_not_fold_constant_function() {
+        let ir = optimized_ir(OptLevel::Development);
+
+        assert!(ir.contains("alloca"), "{ir}");
+        assert!(!ir.contains("ret i64 42"), "{ir}");
+    }
+}
@@ -4,11 +4,11 @@ use bumpalo::Bump;
 use inkwell::memory_buffer::MemoryBuffer;
 use roc_error_macros::internal_error;
 use roc_gen_dev::AssemblyBackendMode;
-use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
+use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode, SourceFiles};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
 use roc_load::{
     EntryPoint, ExecutionMode, ExpectMetadata, FunctionKind, LoadConfig, LoadMonomorphizedError,
-    LoadedModule, LoadingProblem, MonomorphizedModule, Threading,
+    LoadedModule, LoadingProblem, MonomorphizedModule, ShadowPolicy, Threading,
 };
 use roc_mono::ir::{OptLevel, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
@@ -42,6 +42,7 @@ pub fn report_problems_monomorphized(loaded: &mut MonomorphizedModule) -> Proble
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
+        &mut loaded.can_warnings,
         &mut loaded.type_problems,
     )
 }
@@ -51,6 +52,7 @@ pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
+        &mut loaded.can_warnings,
         &mut loaded.type_problems,
     )
 }
@@ -85,6 +87,9 @@ pub struct CodeGenOptions {
     pub emit_debug_info: bool,
     pub emit_llvm_ir: bool,
     pub fuzz: bool,
+    /// Whether `+`, `-` and `*` on sized integers crash when they overflow, rather than wrap.
+    /// Only the LLVM backend can turn these checks off.
+    pub overflow_checks: bool,
 }
 
 type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>);
@@ -104,6 +109,7 @@ pub fn gen_from_mono_module<'a>(
     let emit_llvm_ir = code_gen_options.emit_llvm_ir;
     let fuzz = code_gen_options.fuzz;
     let opt = code_gen_options.opt_level;
+    let overflow_checks = code_gen_options.overflow_checks;
 
     match code_gen_options.backend {
         CodeGenBackend::Wasm => {
@@ -140,6 +146,7 @@ pub fn gen_from_mono_module<'a>(
             debug,
             emit_llvm_ir,
             fuzz,
+            overflow_checks,
         ),
     }
 }
@@ -158,6 +165,7 @@ fn gen_from_mono_module_llvm<'a>(
     emit_debug_info: bool,
     emit_llvm_ir: bool,
     fuzz: bool,
+    overflow_checks: bool,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
     use inkwell::attributes::{Attribute, AttributeLoc};
@@ -223,6 +231,12 @@ fn gen_from_mono_module_llvm<'a>(
             .keys()
             .copied()
             .collect(),
+        overflow_checks,
+        source_files: if emit_debug_info {
+            SourceFiles::new(&loaded.sources)
+        } else {
+            SourceFiles::default()
+        },
     };
 
     // does not add any externs for this mode (we have a host) but cleans up some functions around
@@ -703,6 +717,8 @@ pub fn standard_load_config(
     LoadConfig {
         target,
         function_kind: FunctionKind::from_env(),
+        step_budget: None,
+        shadow_policy: ShadowPolicy::Disallow,
         render: RenderTarget::ColorTerminal,
         palette: DEFAULT_PALETTE,
         threading,
@@ -1346,6 +1362,7 @@ pub fn check_file<'a>(
     emit_timings: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
+    shadow_policy: ShadowPolicy,
 ) -> Result<(Problems, Duration), LoadingProblem<'a>> {
     let compilation_start = Instant::now();
 
@@ -1358,6 +1375,8 @@ pub fn check_file<'a>(
     let load_config = LoadConfig {
         target,
         function_kind: FunctionKind::from_env(),
+        step_budget: None,
+        shadow_policy,
         // TODO: expose this from CLI?
         render: RenderTarget::ColorTerminal,
         palette: DEFAULT_PALETTE,
@@ -1431,6 +1450,7 @@ pub fn build_str_test<'a>(
         emit_debug_info: false,
         emit_llvm_ir: false,
         fuzz: false,
+        overflow_checks: true,
     };
 
     let emit_timings = false;
@@ -1,6 +1,7 @@
 use inkwell::{
+    module::Module,
     targets::{
-        CodeModel, InitializationConfig, RelocMode, Target as LlvmTarget, TargetMachine,
+        CodeModel, FileType, InitializationConfig, RelocMode, Target as LlvmTarget, TargetMachine,
         TargetTriple,
     },
     OptimizationLevel,
@@ -8,6 +9,7 @@ use inkwell::{
 use roc_error_macros::internal_error;
 use roc_mono::ir::OptLevel;
 use roc_target::{Architecture, Target};
+use std::path::Path;
 
 pub fn target_triple_str(target: Target) -> &'static str {
     // Best guide I've found on how to determine these magic strings:
@@ -92,7 +94,7 @@ pub fn target_machine(
         _ => CodeModel::Default,
     };
 
-    LlvmTarget::from_name(arch).unwrap().create_target_machine(
+    LlvmTarget::from_name(arch)?.create_target_machine(
         &TargetTriple::create(target_triple_str(target)),
         "generic",
         "",
@@ -102,6 +104,30 @@ pub fn target_machine(
     )
 }
 
+#[derive(Debug)]
+pub enum WriteObjectError {
+    /// LLVM could not create a target machine for this target
+    UnsupportedTarget(Target),
+    /// LLVM failed to emit code for the module, or to write it to disk
+    Llvm(String),
+}
+
+/// Writes `module` to `path` as a relocatable object file (`.o`) for `target`,
+/// ready to be linked by an external linker.
+pub fn write_object_file(
+    module: &Module,
+    target: Target,
+    opt: OptimizationLevel,
+    path: &Path,
+) -> Result<(), WriteObjectError> {
+    let target_machine = target_machine(target, opt, RelocMode::PIC)
+        .ok_or(WriteObjectError::UnsupportedTarget(target))?;
+
+    target_machine
+        .write_to_file(module, FileType::Object, path)
+        .map_err(|message| WriteObjectError::Llvm(message.to_string()))
+}
+
 pub fn convert_opt_level(level: OptLevel) -> OptimizationLevel {
     match level {
         OptLevel::Development | OptLevel::Normal => OptimizationLevel::None,
@@ -110,3 +136,45 @@ pub fn convert_opt_level(level: OptLevel) -> OptimizationLevel {
         OptLevel::Optimize => OptimizationLevel::Aggressive,
     }
 }
+
+#[cfg(all(test, feature = "target-x86_64"))]
+mod test {
+    use super::*;
+    use inkwell::context::Context;
+
+    /// The equivalent of `main = 42`
+    fn write_main(target: Target) -> Vec<u8> {
+        let context = Context::create();
+        let module = context.create_module("app");
+        let i64_type = context.i64_type();
+
+        let main = module.add_function("main", i64_type.fn_type(&[], false), None);
+        let builder = context.create_builder();
+        builder.position_at_end(context.append_basic_block(main, "entry"));
+        builder
+            .build_return(Some(&i64_type.const_int(42, false)))
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.o");
+
+        write_object_file(&module, target, OptimizationLevel::None, &path).unwrap();
+
+        std::fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn write_elf_object_file() {
+        let bytes = write_main(Target::LinuxX64);
+
+        assert_eq!(&bytes[..4], b"\x7fELF");
+    }
+
+    #[test]
+    fn write_macho_object_file() {
+        let bytes = write_main(Target::MacX64);
+
+        // MH_MAGIC_64, little endian
+        assert_eq!(&bytes[..4], &[0xcf, 0xfa, 0xed, 0xfe]);
+    }
+}
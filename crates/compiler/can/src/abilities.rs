@@ -157,6 +157,10 @@ pub struct IAbilitiesStore<Phase: ResolvePhase> {
     /// member `member`, to how that implementation is defined.
     declared_implementations: MutMap<ImplKey, MemberImpl>,
 
+    /// Maps an ability member to the types that declare an implementation of it, in the order the
+    /// implementations were registered. Every entry has a key in `declared_implementations`.
+    implementations_of_member: MutMap<Symbol, Vec<Symbol>>,
+
     /// Information about specialized ability member implementations for a type.
     specializations: MutMap<Symbol, MemberSpecializationInfo<Phase>>,
 
@@ -174,6 +178,7 @@ impl<Phase: ResolvePhase> Default for IAbilitiesStore<Phase> {
             specialization_to_root: Default::default(),
             ability_members: Default::default(),
             declared_implementations: Default::default(),
+            implementations_of_member: Default::default(),
             specializations: Default::default(),
             next_specialization_id:
                 // Safety: 1 != 0
@@ -236,7 +241,25 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
             self.specialization_to_root
                 .insert(specialization_symbol, impl_key);
         }
-        self.declared_implementations.insert(impl_key, member_impl);
+        self.insert_declared_impl(impl_key, member_impl);
+    }
+
+    /// Inserts into `declared_implementations`, keeping `implementations_of_member` in sync.
+    fn insert_declared_impl(
+        &mut self,
+        impl_key: ImplKey,
+        member_impl: MemberImpl,
+    ) -> Option<MemberImpl> {
+        let old_impl = self.declared_implementations.insert(impl_key, member_impl);
+
+        if old_impl.is_none() {
+            self.implementations_of_member
+                .entry(impl_key.ability_member)
+                .or_default()
+                .push(impl_key.opaque);
+        }
+
+        old_impl
     }
 
     /// Records the implementations of an ability an opaque type declares to have.
@@ -262,6 +285,36 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
         }
     }
 
+    /// Returns how a type declares to implement an ability member, if it does.
+    pub fn declared_implementation(&self, impl_key: ImplKey) -> Option<&MemberImpl> {
+        self.declared_implementations.get(&impl_key)
+    }
+
+    /// Iterates over the `(type, implementation)` pairs of all types declaring a custom
+    /// implementation of the given ability member, in the order they were registered.
+    /// Implementations that are known to be erroneous are skipped.
+    pub fn specializations_of(
+        &self,
+        member: Symbol,
+    ) -> impl Iterator<Item = (Symbol, Symbol)> + '_ {
+        let opaques = match self.implementations_of_member.get(&member) {
+            Some(opaques) => opaques.as_slice(),
+            None => &[],
+        };
+
+        opaques.iter().filter_map(move |&opaque| {
+            let impl_key = ImplKey {
+                opaque,
+                ability_member: member,
+            };
+
+            match self.declared_implementations.get(&impl_key) {
+                Some(MemberImpl::Impl(impl_symbol)) => Some((opaque, *impl_symbol)),
+                Some(MemberImpl::Error) | None => None,
+            }
+        })
+    }
+
     /// Returns whether a symbol is declared to specialize an ability member.
     pub fn is_specialization_name(&self, symbol: Symbol) -> bool {
         self.specialization_to_root.contains_key(&symbol)
@@ -314,6 +367,7 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
             members_of_ability,
             ability_members,
             declared_implementations,
+            implementations_of_member,
             specializations,
 
             // Covered by `declared_implementations`
@@ -363,18 +417,25 @@ impl<Phase: ResolvePhase> IAbilitiesStore<Phase> {
             new.register_ability(ability, imported_member_data);
 
             // Add any specializations of the ability's members we know about.
-            declared_implementations
-                .iter()
-                .filter(|(impl_key, _)| members.contains(&impl_key.ability_member))
-                .for_each(|(&impl_key, member_impl)| {
-                    new.register_one_declared_impl(impl_key, *member_impl);
+            for &ability_member in members {
+                let opaques = implementations_of_member.get(&ability_member);
+
+                for &opaque in opaques.into_iter().flatten() {
+                    let impl_key = ImplKey {
+                        opaque,
+                        ability_member,
+                    };
+                    let member_impl = declared_implementations[&impl_key];
+
+                    new.register_one_declared_impl(impl_key, member_impl);
 
                     if let MemberImpl::Impl(spec_symbol) = member_impl {
-                        if let Some(specialization_info) = specializations.get(spec_symbol) {
+                        if let Some(specialization_info) = specializations.get(&spec_symbol) {
                             new.import_specialization(specialization_info);
                         }
                     }
-                });
+                }
+            }
         }
 
         new
@@ -497,7 +558,7 @@ impl IAbilitiesStore<Pending> {
             ResolvedImpl::Error => MemberImpl::Error,
         };
 
-        let old_declared_impl = self.declared_implementations.insert(impl_key, member_impl);
+        let old_declared_impl = self.insert_declared_impl(impl_key, member_impl);
         debug_assert!(
             old_declared_impl.is_none() ||
                 // Can happen between we import declared implementations during canonicalization, but
@@ -535,6 +596,7 @@ impl IAbilitiesStore<Pending> {
             ability_members: mut other_ability_members,
             specialization_to_root,
             declared_implementations,
+            implementations_of_member,
             next_specialization_id,
             resolved_specializations,
             specializations,
@@ -558,9 +620,17 @@ impl IAbilitiesStore<Pending> {
             debug_assert!(old_root.is_none() || old_root.unwrap() == member);
         }
 
-        for (impl_key, impl_) in declared_implementations.into_iter() {
-            let old_impl = self.declared_implementations.insert(impl_key, impl_);
-            debug_assert!(old_impl.is_none() || old_impl.unwrap() == impl_);
+        for (ability_member, opaques) in implementations_of_member.into_iter() {
+            for opaque in opaques {
+                let impl_key = ImplKey {
+                    opaque,
+                    ability_member,
+                };
+                let impl_ = declared_implementations[&impl_key];
+
+                let old_impl = self.insert_declared_impl(impl_key, impl_);
+                debug_assert!(old_impl.is_none() || old_impl.unwrap() == impl_);
+            }
         }
 
         for (symbol, specialization_info) in specializations.into_iter() {
@@ -595,6 +665,7 @@ impl IAbilitiesStore<Pending> {
             ability_members,
             specialization_to_root,
             declared_implementations,
+            implementations_of_member,
             next_specialization_id,
             resolved_specializations,
             specializations,
@@ -673,6 +744,7 @@ impl IAbilitiesStore<Pending> {
             ability_members,
             specialization_to_root,
             declared_implementations,
+            implementations_of_member,
             next_specialization_id,
             resolved_specializations,
             specializations,
@@ -716,6 +788,7 @@ mod serialize {
                 specialization_to_root,
                 ability_members,
                 declared_implementations,
+                implementations_of_member: _, // rebuilt from declared_implementations
                 specializations,
                 next_specialization_id,
                 resolved_specializations,
@@ -753,6 +826,7 @@ mod serialize {
             specialization_to_root,
             ability_members,
             declared_implementations,
+            implementations_of_member: _, // rebuilt from declared_implementations
             specializations,
             next_specialization_id: _, // written in the header
             resolved_specializations,
@@ -788,6 +862,7 @@ mod serialize {
             header.declared_implementations as _,
             offset,
         );
+        let implementations_of_member = implementations_of_member(&declared_implementations);
         let (specializations, offset) =
             deserialize_specializations(bytes, header.specializations as _, offset);
         let (resolved_specializations, offset) = deserialize_resolved_specializations(
@@ -802,6 +877,7 @@ mod serialize {
                 specialization_to_root,
                 ability_members,
                 declared_implementations,
+                implementations_of_member,
                 specializations,
                 next_specialization_id: (header.next_specialization_id as u32).try_into().unwrap(),
                 resolved_specializations,
@@ -991,6 +1067,28 @@ mod serialize {
         )
     }
 
+    /// The registration order is not serialized, so order deserialized implementations by type.
+    fn implementations_of_member(
+        declared_implementations: &MutMap<ImplKey, MemberImpl>,
+    ) -> MutMap<Symbol, Vec<Symbol>> {
+        let mut impl_keys: Vec<_> = declared_implementations.keys().copied().collect();
+        impl_keys.sort();
+
+        let mut implementations_of_member: MutMap<Symbol, Vec<Symbol>> = MutMap::default();
+        for ImplKey {
+            opaque,
+            ability_member,
+        } in impl_keys
+        {
+            implementations_of_member
+                .entry(ability_member)
+                .or_default()
+                .push(opaque);
+        }
+
+        implementations_of_member
+    }
+
     fn deserialize_declared_implementations(
         bytes: &[u8],
         length: usize,
@@ -1242,7 +1340,7 @@ mod serialize {
 
 #[cfg(test)]
 mod test {
-    use roc_collections::VecMap;
+    use roc_collections::{MutMap, VecMap};
     use roc_module::symbol::Symbol;
     use roc_region::all::Region;
     use roc_types::{subs::Variable, types::MemberImpl};
@@ -1351,6 +1449,7 @@ mod test {
             specialization_to_root,
             ability_members,
             declared_implementations,
+            implementations_of_member,
             specializations,
             next_specialization_id,
             resolved_specializations,
@@ -1364,8 +1463,61 @@ mod test {
         assert_eq!(specialization_to_root, de_store.specialization_to_root);
         assert_eq!(ability_members, de_store.ability_members);
         assert_eq!(declared_implementations, de_store.declared_implementations);
+        // the registration order isn't serialized, only which opaques implement each member
+        let sorted = |implementations: MutMap<Symbol, Vec<Symbol>>| {
+            let mut implementations: Vec<_> = implementations.into_iter().collect();
+            implementations.sort();
+            for (_, opaques) in implementations.iter_mut() {
+                opaques.sort();
+            }
+            implementations
+        };
+        assert_eq!(
+            sorted(implementations_of_member),
+            sorted(de_store.implementations_of_member)
+        );
         assert_eq!(specializations, de_store.specializations);
         assert_eq!(next_specialization_id, de_store.next_specialization_id);
         assert_eq!(resolved_specializations, de_store.resolved_specializations);
     }
+
+    #[test]
+    fn specializations_of_member_in_registration_order() {
+        let mut store = AbilitiesStore::default();
+        store.register_ability(
+            Symbol::ARG_1,
+            [(
+                Symbol::ARG_2,
+                AbilityMemberData {
+                    parent_ability: Symbol::ARG_1,
+                    region: Region::zero(),
+                    typ: ResolvedMemberType(Variable::BOOL),
+                },
+            )],
+        );
+
+        // register in the opposite order of the symbols, to make sure we don't sort
+        store.register_declared_implementations(
+            Symbol::ATTR_INVALID,
+            [(Symbol::ARG_2, MemberImpl::Impl(Symbol::ARG_4))],
+        );
+        store.register_declared_implementations(
+            Symbol::ATTR_ATTR,
+            [(Symbol::ARG_2, MemberImpl::Impl(Symbol::ARG_3))],
+        );
+        store.register_declared_implementations(
+            Symbol::ARG_CLOSURE,
+            [(Symbol::ARG_2, MemberImpl::Error)],
+        );
+
+        assert_eq!(
+            store.specializations_of(Symbol::ARG_2).collect::<Vec<_>>(),
+            [
+                (Symbol::ATTR_INVALID, Symbol::ARG_4),
+                (Symbol::ATTR_ATTR, Symbol::ARG_3),
+            ]
+        );
+
+        assert_eq!(store.specializations_of(Symbol::ARG_1).count(), 0);
+    }
 }
@@ -231,7 +231,7 @@ impl IntroducedVariables {
             .map(|(_, var)| var)
     }
 
-    pub fn iter_named(&self) -> impl Iterator<Item = NamedOrAbleVariable> {
+    pub fn iter_named(&self) -> impl Iterator<Item = NamedOrAbleVariable> + Clone {
         (self.named.iter().map(NamedOrAbleVariable::Named))
             .chain(self.able.iter().map(NamedOrAbleVariable::Able))
     }
@@ -392,7 +392,7 @@ pub(crate) fn make_apply_symbol(
             }
         }
     } else {
-        match env.qualified_lookup(scope, module_name, ident, region) {
+        match scope.lookup_qualified_str(env, module_name, ident, region) {
             Ok(SymbolLookup {
                 symbol,
                 module_params: _,
@@ -523,7 +523,7 @@ pub fn find_type_def_symbols(
 }
 
 fn find_fresh_var_name(introduced_variables: &IntroducedVariables) -> Lowercase {
-    name_type_var("", 0, &mut introduced_variables.iter_named(), |var, str| {
+    name_type_var("", 0, introduced_variables.iter_named(), |var, str| {
         var.name().as_str() == str
     })
     .0
@@ -630,7 +630,7 @@ fn can_annotation_help(
                 args.push(Loc::at(arg.region, arg_ann));
             }
 
-            match scope.lookup_alias(symbol) {
+            match scope.reference_alias(symbol) {
                 Some(alias) => {
                     // use a known alias
 
@@ -843,14 +843,19 @@ fn can_annotation_help(
             // TODO: handle implicit ext variables in `as` aliases
             let infer_ext_in_output = vec![];
 
-            scope.add_alias(
+            if let Err(error) = scope.add_alias_with_names(
                 symbol,
                 region,
                 lowercase_vars,
                 infer_ext_in_output,
                 alias_actual,
                 AliasKind::Structural, // aliases in "as" are never opaque
-            );
+                introduced_variables,
+            ) {
+                env.problem(Problem::RuntimeError(error));
+
+                return Type::Error;
+            }
 
             let alias = scope.lookup_alias(symbol).unwrap();
             local_aliases.insert(symbol, alias.clone());
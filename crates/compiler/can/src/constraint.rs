@@ -1008,6 +1008,111 @@ pub enum ExpectEffectfulReason {
     Ignored,
 }
 
+#[cfg(debug_assertions)]
+impl Constraint {
+    /// Renders this constraint and the ones nested in it as an indented tree, one node per
+    /// line, looking up the categories, expectations and symbols it points to in `constraints`
+    /// and the types it mentions in `types`.
+    ///
+    /// Meant for dumping the constraints generated for a snippet while debugging inference.
+    pub fn pretty(&self, constraints: &Constraints, types: &Types) -> String {
+        let mut buf = String::new();
+        self.pretty_help(constraints, types, 0, &mut buf)
+            .expect("writing to a String cannot fail");
+
+        buf
+    }
+
+    fn pretty_help(
+        &self,
+        constraints: &Constraints,
+        types: &Types,
+        indent: usize,
+        buf: &mut String,
+    ) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        let pad = "  ".repeat(indent);
+
+        match self {
+            Self::Eq(Eq(typ, expected, category, region)) => {
+                let expected = constraints.expectations[expected.index()].get_type_ref();
+                let category = &constraints.categories[category.index()];
+
+                writeln!(
+                    buf,
+                    "{pad}Eq {} ~ {} ({category:?}) {region:?}",
+                    pretty_type_or_var(types, *typ),
+                    pretty_type_or_var(types, *expected),
+                )
+            }
+            Self::Lookup(symbol, expected, region) => {
+                let expected = constraints.expectations[expected.index()].get_type_ref();
+
+                writeln!(
+                    buf,
+                    "{pad}Lookup {symbol:?} ~ {} {region:?}",
+                    pretty_type_or_var(types, *expected),
+                )
+            }
+            Self::Pattern(typ, expected, category, region) => {
+                let expected = constraints.pattern_expectations[expected.index()].get_type_ref();
+                let category = &constraints.pattern_categories[category.index()];
+
+                writeln!(
+                    buf,
+                    "{pad}Pattern {} ~ {} ({category:?}) {region:?}",
+                    pretty_type_or_var(types, *typ),
+                    pretty_type_or_var(types, *expected),
+                )
+            }
+            Self::Let(index, pool_variables) => {
+                let let_con = &constraints.let_constraints[index.index()];
+                let rigid_vars = &constraints.variables[let_con.rigid_vars.indices()];
+                let flex_vars = &constraints.variables[let_con.flex_vars.indices()];
+                let symbols = &constraints.loc_symbols[let_con.def_types.loc_symbols.indices()];
+                let pool_variables = &constraints.variables[pool_variables.indices()];
+
+                writeln!(
+                    buf,
+                    "{pad}Let rigid={rigid_vars:?} flex={flex_vars:?} pool={pool_variables:?}"
+                )?;
+
+                for (symbol, region) in symbols {
+                    writeln!(buf, "{pad}  def {symbol:?} {region:?}")?;
+                }
+
+                // defs and ret constraint are stored consecutively
+                let offset = let_con.defs_and_ret_constraint.index();
+
+                writeln!(buf, "{pad}  defs:")?;
+                constraints.constraints[offset].pretty_help(constraints, types, indent + 2, buf)?;
+                writeln!(buf, "{pad}  ret:")?;
+                constraints.constraints[offset + 1].pretty_help(constraints, types, indent + 2, buf)
+            }
+            Self::And(slice) => {
+                writeln!(buf, "{pad}And")?;
+
+                for constraint in &constraints.constraints[slice.indices()] {
+                    constraint.pretty_help(constraints, types, indent + 1, buf)?;
+                }
+
+                Ok(())
+            }
+            other => writeln!(buf, "{pad}{other:?}"),
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+fn pretty_type_or_var(types: &Types, type_or_var: TypeOrVar) -> String {
+    match type_or_var.split() {
+        Ok(type_index) => format!("{:?}", types.dbg(type_index)),
+        // the variable is stored directly in the index, see `push_type_variable`
+        Err(var_index) => format!("{}", var_index.index()),
+    }
+}
+
 /// Custom impl to limit vertical space used by the debug output
 impl std::fmt::Debug for Constraint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1066,3 +1171,48 @@ impl std::fmt::Debug for Constraint {
         }
     }
 }
+
+#[cfg(all(test, debug_assertions))]
+mod test {
+    use super::Constraints;
+    use crate::expected::Expected;
+    use roc_collections::all::SendMap;
+    use roc_region::all::{Position, Region};
+    use roc_types::subs::VarStore;
+    use roc_types::types::{Category, RecordField, Type, TypeExtension, Types};
+
+    #[test]
+    fn pretty_renders_nested_constraints_and_their_types() {
+        let mut var_store = VarStore::default();
+        let mut types = Types::new();
+        let mut constraints = Constraints::new();
+
+        let x = var_store.fresh();
+
+        let mut fields = SendMap::default();
+        fields.insert("x".into(), RecordField::Required(Type::Variable(x)));
+        let record = types.from_old_type(&Type::Record(fields, TypeExtension::Closed));
+        let record = constraints.push_type(&types, record);
+
+        let expected = constraints.push_variable(var_store.fresh());
+        let expected = constraints.push_expected_type(Expected::NoExpectation(expected));
+        let eq = constraints.equal_types(record, expected, Category::Record, Region::zero());
+
+        let x_type = constraints.push_variable(x);
+        let empty_record = types.from_old_type(&Type::EmptyRec);
+        let expected = constraints.push_type(&types, empty_record);
+        let expected = constraints.push_expected_type(Expected::NoExpectation(expected));
+        let region = Region::new(Position::new(3), Position::new(5));
+        let unit_eq = constraints.equal_types(x_type, expected, Category::Unknown, region);
+
+        let constraint = constraints.and_constraint([eq, unit_eq]);
+
+        assert_eq!(
+            constraint.pretty(&constraints, &types),
+            format!(
+                "And\n  Eq {{x: {x:?}}} ~ {:?} (Record) …\n  Eq {x:?} ~ {{}} (Unknown) @3-5\n",
+                x.index() + 1,
+            )
+        );
+    }
+}
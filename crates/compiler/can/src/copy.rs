@@ -799,6 +799,7 @@ fn deep_copy_pattern_help<C: CopyEnv>(
             whole_var,
             ext_var,
             destructs,
+            opt_rest,
         } => RecordDestructure {
             whole_var: sub!(*whole_var),
             ext_var: sub!(*ext_var),
@@ -829,6 +830,7 @@ fn deep_copy_pattern_help<C: CopyEnv>(
                     )
                 })
                 .collect(),
+            opt_rest: opt_rest.as_ref().map(|(var, rest)| (sub!(*var), *rest)),
         },
         TupleDestructure {
             whole_var,
@@ -990,7 +992,7 @@ fn deep_copy_type_vars<C: CopyEnv>(
 
             // Everything else is a mechanical descent.
             Structure(flat_type) => match flat_type {
-                EmptyRecord | EmptyTagUnion | EffectfulFunc => Structure(flat_type),
+                EmptyRecord | EmptyTuple | EmptyTagUnion | EffectfulFunc => Structure(flat_type),
                 Apply(symbol, arguments) => {
                     descend_slice!(arguments);
 
@@ -538,12 +538,18 @@ fn pattern<'a>(
         } => text!(f, "@{} ", opaque.module_string(c.interns))
             .append(pattern(c, Free, f, &argument.1.value))
             .group(),
-        RecordDestructure { destructs, .. } => f
+        RecordDestructure {
+            destructs,
+            opt_rest,
+            ..
+        } => f
             .text("{")
             .append(
                 f.intersperse(
-                    destructs.iter().map(|l| &l.value).map(
-                        |RecordDestruct { label, typ, .. }| match typ {
+                    destructs
+                        .iter()
+                        .map(|l| &l.value)
+                        .map(|RecordDestruct { label, typ, .. }| match typ {
                             crate::pattern::DestructType::Required => f.text(label.as_str()),
                             crate::pattern::DestructType::Optional(_, e) => f
                                 .text(label.as_str())
@@ -553,8 +559,12 @@ fn pattern<'a>(
                                 .text(label.as_str())
                                 .append(f.text(": "))
                                 .append(pattern(c, Free, f, &p.value)),
-                        },
-                    ),
+                        })
+                        .chain(
+                            opt_rest
+                                .iter()
+                                .map(|(_, rest)| f.text("..").append(pp_sym(c, f, rest.value))),
+                        ),
                     f.text(", "),
                 ),
             )
@@ -22,8 +22,9 @@ use crate::expr::{canonicalize_expr, Output, Recursive};
 use crate::pattern::{canonicalize_def_header_pattern, BindingsFromPattern, Pattern};
 use crate::procedure::QualifiedReference;
 use crate::procedure::References;
-use crate::scope::create_alias;
+use crate::scope::ScopeSnapshot;
 use crate::scope::SymbolLookup;
+use crate::scope::{create_alias, cycle_chain, AliasError};
 use crate::scope::{PendingAbilitiesInScope, Scope};
 use roc_collections::ReferenceMatrix;
 use roc_collections::VecMap;
@@ -45,7 +46,7 @@ use roc_parse::ast::TypeHeader;
 use roc_parse::ident::Accessor;
 use roc_parse::pattern::PatternType;
 use roc_problem::can::ShadowKind;
-use roc_problem::can::{CycleEntry, Problem, RuntimeError};
+use roc_problem::can::{CycleEntry, Problem, RuntimeError, Warning};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::IllegalCycleMark;
 use roc_types::subs::{VarStore, Variable};
@@ -405,9 +406,30 @@ fn canonicalize_alias<'a>(
     // Record all the annotation's references in output.references.lookups
     output.references.union_mut(&can_ann.references);
 
+    // A repeated name only binds one type variable, so the repetition would otherwise be
+    // reported as a phantom type argument.
+    for (index, duplicate) in vars.iter().enumerate() {
+        if let Some(first) = vars[..index]
+            .iter()
+            .find(|first| first.value == duplicate.value)
+        {
+            env.problems
+                .push(Problem::RuntimeError(RuntimeError::DuplicateTypeVariable {
+                    name: duplicate.value.clone(),
+                    first_region: first.region,
+                    duplicate_region: duplicate.region,
+                }));
+
+            return Err(());
+        }
+    }
+
     let mut can_vars: Vec<Loc<AliasVar>> = Vec::with_capacity(vars.len());
     let mut is_phantom = false;
 
+    // Kept around to name unbound variables, should the alias have any
+    let introduced_variables = can_ann.introduced_variables.clone();
+
     let IntroducedVariables {
         named,
         able,
@@ -508,17 +530,44 @@ fn canonicalize_alias<'a>(
         no_problems = false;
     }
 
-    if no_problems {
-        Ok(create_alias(
-            symbol,
-            name.region,
-            can_vars.clone(),
-            infer_ext_in_output,
-            can_ann.typ,
-            kind,
-        ))
-    } else {
-        Err(())
+    if !no_problems {
+        return Err(());
+    }
+
+    match create_alias(
+        name.region,
+        can_vars.clone(),
+        infer_ext_in_output,
+        can_ann.typ,
+        kind,
+        &introduced_variables,
+    ) {
+        Ok(alias) => Ok(alias),
+        Err(AliasError::UnboundTypeVariables { names }) => {
+            env.problems.push(Problem::RuntimeError(
+                RuntimeError::UnboundTypeVariablesInAlias {
+                    alias: symbol,
+                    region: name.region,
+                    unbound: names,
+                },
+            ));
+
+            Err(())
+        }
+        Err(AliasError::DuplicateTypeVariable {
+            name,
+            first_region,
+            duplicate_region,
+        }) => {
+            env.problems
+                .push(Problem::RuntimeError(RuntimeError::DuplicateTypeVariable {
+                    name,
+                    first_region,
+                    duplicate_region,
+                }));
+
+            Err(())
+        }
     }
 }
 
@@ -1112,6 +1161,129 @@ pub(crate) fn canonicalize_defs<'a>(
     )
 }
 
+/// A top-level def that was canonicalized on its own, see [recanonicalize_top_level_def]
+#[derive(Debug)]
+pub struct RecanonicalizedDef {
+    pub def: Def,
+    pub output: Output,
+    /// Aliases used in the annotation of the def
+    pub aliases: VecMap<Symbol, Alias>,
+    /// Symbols the def brought into scope, because they were not bound by the previous version
+    pub symbols_introduced: MutMap<Symbol, Region>,
+}
+
+/// Canonicalize a single top-level def again (e.g. after its body was edited), reusing the
+/// canonicalization of the rest of the module.
+///
+/// `scope` must be the scope the module was canonicalized in, and `snapshot` must be taken once
+/// all top-level defs are in scope. Anything added to the scope after the snapshot (for instance
+/// by an earlier call) is discarded first. If the def still has the name of `previous`, it keeps
+/// its symbol, so that the other defs referring to it remain valid.
+///
+/// Only `name = ...` defs, with or without an annotation, can be canonicalized on their own. For
+/// other defs `None` is returned, and the whole module should be canonicalized again.
+pub fn recanonicalize_top_level_def<'a>(
+    env: &mut Env<'a>,
+    var_store: &mut VarStore,
+    scope: &mut Scope,
+    snapshot: ScopeSnapshot,
+    previous: &Def,
+    value_def: &'a ast::ValueDef<'a>,
+) -> Option<RecanonicalizedDef> {
+    let (loc_pattern, opt_loc_ann, loc_expr) = match value_def {
+        ast::ValueDef::Body(loc_pattern, loc_expr) => (*loc_pattern, None, *loc_expr),
+        ast::ValueDef::AnnotatedBody {
+            ann_pattern,
+            ann_type,
+            lines_between: _,
+            body_pattern,
+            body_expr,
+        } if ann_pattern.value.equivalent(&body_pattern.value) => {
+            (*body_pattern, Some(*ann_type), *body_expr)
+        }
+        _ => return None,
+    };
+
+    let ident = match loc_pattern.value.extract_spaces().item {
+        ast::Pattern::Identifier { ident } => ident,
+        _ => return None,
+    };
+
+    scope.rollback(snapshot);
+
+    let mut output = Output::default();
+    let mut symbols_introduced = MutMap::default();
+
+    let previous_symbol = match &previous.loc_pattern.value {
+        Pattern::Identifier(symbol)
+        | Pattern::AbilityMemberSpecialization { ident: symbol, .. } => Some(*symbol),
+        _ => None,
+    };
+
+    let loc_can_pattern = match previous_symbol {
+        Some(symbol)
+            if matches!(
+                scope.resolve_str(ident, loc_pattern.region),
+                Ok(lookup) if lookup.symbol == symbol
+            ) =>
+        {
+            output.references.insert_bound(symbol);
+
+            Loc::at(loc_pattern.region, previous.loc_pattern.value.clone())
+        }
+        _ => {
+            // the def was renamed, so its new name must be introduced
+            let loc_can_pattern = canonicalize_def_header_pattern(
+                env,
+                var_store,
+                scope,
+                &PendingAbilitiesInScope::default(),
+                &mut output,
+                PatternType::TopLevelDef,
+                &loc_pattern.value,
+                loc_pattern.region,
+            );
+
+            for (symbol, region) in BindingsFromPattern::new(&loc_can_pattern) {
+                env.top_level_symbols.insert(symbol);
+                symbols_introduced.insert(symbol, region);
+            }
+
+            loc_can_pattern
+        }
+    };
+
+    let pending_def = match opt_loc_ann {
+        None => PendingValueDef::Body(loc_can_pattern, loc_expr),
+        Some(loc_ann) => {
+            PendingValueDef::TypedBody(loc_pattern, loc_can_pattern, loc_ann, loc_expr)
+        }
+    };
+
+    let mut aliases = VecMap::default();
+
+    let DefOutput {
+        output,
+        def,
+        references: _,
+    } = canonicalize_pending_value_def(
+        env,
+        pending_def,
+        output,
+        scope,
+        var_store,
+        PatternType::TopLevelDef,
+        &mut aliases,
+    );
+
+    Some(RecanonicalizedDef {
+        def,
+        output,
+        aliases,
+        symbols_introduced,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn canonicalize_value_defs<'a>(
     env: &mut Env<'a>,
@@ -1429,14 +1601,16 @@ fn canonicalize_type_defs<'a>(
     let aliases = correct_mutual_recursive_type_alias(env, aliases, var_store);
 
     for (symbol, alias) in aliases.iter() {
-        scope.add_alias(
+        if let Err(error) = scope.add_alias(
             *symbol,
             alias.region,
             alias.type_variables.clone(),
             alias.infer_ext_in_output_variables.clone(),
             alias.typ.clone(),
             alias.kind,
-        );
+        ) {
+            env.problem(Problem::RuntimeError(error));
+        }
     }
 
     // Resolve all pending abilities, to add them to scope.
@@ -1972,9 +2146,28 @@ pub(crate) fn sort_can_defs(
     // recursive relations between any 2 definitions.
     let sccs = def_ordering.references.strongly_connected_components_all();
 
+    // Defs that are looked up outside of the defs themselves keep everything they reference
+    // alive; a recursive group that none of them reach is only used in recursion.
+    let roots: Vec<usize> = defs
+        .iter()
+        .enumerate()
+        .filter(|(_, def)| {
+            def.as_ref().map_or(false, |def| {
+                def.pattern_vars
+                    .keys()
+                    .any(|sym| output.references.has_value_lookup(*sym))
+            })
+        })
+        .map(|(index, _)| index)
+        .collect();
+
+    let reachable = ven_graph::reachable_from(&roots, |&index| {
+        def_ordering.references.references_for(index)
+    });
+
     let mut declarations = Vec::with_capacity(defs.len());
 
-    for (group, is_initial) in sccs.groups() {
+    for (group, _) in sccs.groups() {
         if group.count_ones() == 1 {
             // a group with a single Def, nice and simple
             let index = group.iter_ones().next().unwrap();
@@ -1988,12 +2181,7 @@ pub(crate) fn sort_can_defs(
             let declaration = if def_ordering.references.get_row_col(index, index) {
                 debug_assert!(!is_specialization, "Self-recursive specializations can only be determined during solving - but it was determined for {def:?} now, that's a bug!");
 
-                if is_initial
-                    && !def
-                        .pattern_vars
-                        .keys()
-                        .any(|sym| output.references.has_value_lookup(*sym))
-                {
+                if !reachable.contains(&index) {
                     // This defs is only used in recursion with itself.
                     env.problem(Problem::DefsOnlyUsedInRecursion(1, def.region()));
                 }
@@ -2043,18 +2231,14 @@ pub(crate) fn sort_can_defs(
 
                 Declaration::InvalidCycle(entries)
             } else {
+                let is_reachable = group.iter_ones().any(|index| reachable.contains(&index));
+
                 let rec_defs: Vec<Def> = group
                     .iter_ones()
                     .map(|index| mark_def_recursive(take_def!(index)))
                     .collect();
 
-                if is_initial
-                    && !rec_defs.iter().any(|def| {
-                        def.pattern_vars
-                            .keys()
-                            .any(|sym| output.references.has_value_lookup(*sym))
-                    })
-                {
+                if !is_reachable {
                     // These defs are only used in mutual recursion with themselves.
                     let region = Region::span_across(
                         &rec_defs.first().unwrap().region(),
@@ -2149,10 +2333,18 @@ fn pattern_to_vars_by_symbol(
             }
         }
 
-        RecordDestructure { destructs, .. } => {
+        RecordDestructure {
+            destructs,
+            opt_rest,
+            ..
+        } => {
             for destruct in destructs {
                 vars_by_symbol.insert(destruct.value.symbol, destruct.value.var);
             }
+
+            if let Some((rest_var, rest_sym)) = opt_rest {
+                vars_by_symbol.insert(rest_sym.value, *rest_var);
+            }
         }
 
         List {
@@ -2721,7 +2913,7 @@ pub fn can_defs_with_return<'a>(
         if !output.references.has_type_or_value_lookup(symbol)
             && !scope.abilities_store.is_specialization_name(symbol)
         {
-            env.problem(Problem::UnusedDef(symbol, region));
+            env.warn(Warning::UnusedDef(symbol, region));
         }
     }
 
@@ -2748,7 +2940,7 @@ pub fn report_unused_imports(
                 if !references.has_unqualified_type_or_value_lookup(*symbol)
                     && !scope.abilities_store.is_specialization_name(*symbol)
                 {
-                    env.problem(Problem::UnusedImport(*symbol, *region));
+                    env.warn(Warning::UnusedImport(*symbol, *region));
                 }
             }
         } else {
@@ -3216,20 +3408,26 @@ fn to_pending_value_def<'a>(
                         let symbol = Symbol::new(module_id, ident_id);
                         exposed_symbols.push((symbol, loc_name.region));
 
-                        if let Err((_shadowed_symbol, existing_symbol_region)) =
-                            scope.import_symbol(ident, symbol, loc_name.region)
-                        {
-                            if symbol.is_automatically_imported() {
-                                env.problem(Problem::ExplicitBuiltinTypeImport(
-                                    symbol,
-                                    loc_name.region,
-                                ));
-                            } else {
-                                env.problem(Problem::ImportShadowsSymbol {
-                                    region: loc_name.region,
-                                    new_symbol: symbol,
-                                    existing_symbol_region,
-                                })
+                        match scope.import_symbol(ident, symbol, loc_name.region) {
+                            Ok(None) => {}
+                            Ok(Some(builtin_symbol)) => env.warn(Warning::ShadowsBuiltin {
+                                region: loc_name.region,
+                                new_symbol: symbol,
+                                builtin_symbol,
+                            }),
+                            Err((_shadowed_symbol, existing_symbol_region)) => {
+                                if symbol.is_automatically_imported() {
+                                    env.problem(Problem::ExplicitBuiltinTypeImport(
+                                        symbol,
+                                        loc_name.region,
+                                    ));
+                                } else {
+                                    env.problem(Problem::ImportShadowsSymbol {
+                                        region: loc_name.region,
+                                        new_symbol: symbol,
+                                        existing_symbol_region,
+                                    })
+                                }
                             }
                         }
                     }
@@ -3441,11 +3639,15 @@ fn correct_mutual_recursive_type_alias(
             let is_mutually_recursive = cycle.count_ones() > 1;
 
             if is_self_recursive || is_mutually_recursive {
+                // If the cycle turns out to be illegal, the report shows how `rec` reaches itself
+                // through the other aliases of its group.
+                let others = alias_cycle_chain(&matrix, cycle, index, &symbols_introduced);
+
                 let _made_recursive = make_tag_union_of_alias_recursive(
                     env,
                     rec,
                     alias,
-                    vec![],
+                    others,
                     var_store,
                     &mut can_still_report_error,
                 );
@@ -3467,10 +3669,9 @@ fn correct_mutual_recursive_type_alias(
 
         if all_are_narrow {
             // This cycle is illegal!
-            let mut indices = cycle.iter_ones();
-            let first_index = indices.next().unwrap();
+            let first_index = cycle.first_one().unwrap();
 
-            let rest: Vec<Symbol> = indices.map(|i| symbols_introduced[i]).collect();
+            let rest = alias_cycle_chain(&matrix, cycle, first_index, &symbols_introduced);
 
             let alias_name = symbols_introduced[first_index];
             let alias = aliases.get_mut(first_index).unwrap();
@@ -3494,6 +3695,19 @@ fn correct_mutual_recursive_type_alias(
     unsafe { VecMap::zip(symbols_introduced, aliases) }
 }
 
+/// The aliases that the alias at `index` goes through to reference itself; see [cycle_chain].
+fn alias_cycle_chain(
+    matrix: &ReferenceMatrix,
+    cycle: &bitvec::slice::BitSlice,
+    index: usize,
+    symbols_introduced: &[Symbol],
+) -> Vec<Symbol> {
+    cycle_chain(matrix, cycle, index)
+        .into_iter()
+        .map(|index| symbols_introduced[index])
+        .collect()
+}
+
 fn make_tag_union_of_alias_recursive(
     env: &mut Env,
     alias_name: Symbol,
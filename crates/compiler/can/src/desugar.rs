@@ -6,7 +6,7 @@ use crate::suffixed::{apply_try_function, unwrap_suffixed_expression, EUnwrapped
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
 use roc_error_macros::internal_error;
-use roc_module::called_via::BinOp::{DoubleQuestion, Pizza};
+use roc_module::called_via::BinOp::{DoubleDot, DoubleDotEquals, DoubleQuestion, Pizza};
 use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::ModuleName;
 use roc_parse::ast::Expr::{self, *};
@@ -209,6 +209,99 @@ fn new_op_call_expr<'a>(
 
             When(left, branches.into_bump_slice())
         }
+        DoubleDot | DoubleDotEquals => {
+            let arena = env.arena;
+            let left = desugar_expr(env, scope, left);
+            let right = desugar_expr(env, scope, right);
+
+            // Rewrite `a..b` into
+            // (
+            //     tmpVar : List (Int _)
+            //     tmpVar = List.range { start: At a, end: Before b }
+            //     tmpVar
+            // )
+            // and `a..=b` likewise, with `end: At b`. List.range counts down by itself when
+            // the end comes before the start. The annotation restricts the endpoints to
+            // integers, which List.range on its own does not.
+            let end_tag = if loc_op.value == DoubleDot {
+                "Before"
+            } else {
+                "At"
+            };
+
+            let fields =
+                [("start", "At", left), ("end", end_tag, right)].map(|(name, tag, bound)| {
+                    let loc_tag = arena.alloc(Loc::at(bound.region, Tag(tag)));
+                    let loc_value = arena.alloc(Loc::at(
+                        bound.region,
+                        Apply(loc_tag, arena.alloc([bound]), CalledVia::Space),
+                    ));
+
+                    Loc::at(
+                        bound.region,
+                        AssignedField::RequiredValue(Loc::at(bound.region, name), &[], loc_value),
+                    )
+                });
+
+            let loc_record = arena.alloc(Loc::at(
+                region,
+                Record(Collection::with_items(arena.alloc(fields))),
+            ));
+
+            let loc_fn = arena.alloc(Loc {
+                value: Expr::Var {
+                    module_name: ModuleName::LIST,
+                    ident: "range",
+                },
+                region: loc_op.region,
+            });
+
+            let loc_range = arena.alloc(Loc::at(
+                region,
+                Apply(
+                    loc_fn,
+                    arena.alloc([&*loc_record]),
+                    CalledVia::BinOp(loc_op.value),
+                ),
+            ));
+
+            let ident = arena.alloc(scope.gen_unique_symbol_name().to_string());
+            let loc_pattern = arena.alloc(Loc::at(region, Pattern::Identifier { ident }));
+
+            // Qualify the builtins so a user-defined `Int` or `List` in scope can't capture them.
+            let int_ann = Loc::at(
+                region,
+                TypeAnnotation::Apply(
+                    ModuleName::NUM,
+                    "Int",
+                    arena.alloc([Loc::at(region, TypeAnnotation::Inferred)]),
+                ),
+            );
+
+            let value_def = ValueDef::AnnotatedBody {
+                ann_pattern: loc_pattern,
+                ann_type: arena.alloc(Loc::at(
+                    region,
+                    TypeAnnotation::Apply(ModuleName::LIST, "List", arena.alloc([int_ann])),
+                )),
+                lines_between: &[],
+                body_pattern: loc_pattern,
+                body_expr: loc_range,
+            };
+
+            let defs = arena.alloc(Defs::default());
+            defs.push_value_def(value_def, region, &[], &[]);
+
+            let tmp_var = arena.alloc(Loc::at(
+                region,
+                Var {
+                    module_name: "",
+                    ident,
+                },
+            ));
+
+            Defs(defs, tmp_var)
+        }
         binop => {
             let left = desugar_expr(env, scope, left);
             let right = desugar_expr(env, scope, right);
@@ -1453,6 +1546,7 @@ fn desugar_pattern<'a>(env: &mut Env<'a>, scope: &mut Scope, pattern: Pattern<'a
         | Underscore(_)
         | SingleQuote(_)
         | ListRest(_)
+        | RecordRest(_)
         | Malformed(_)
         | MalformedIdent(_, _)
         | QualifiedIdentifier { .. } => pattern,
@@ -1657,6 +1751,8 @@ fn binop_to_function(binop: BinOp) -> (&'static str, &'static str) {
         Or => (ModuleName::BOOL, "or"),
         Pizza => unreachable!("Cannot desugar the |> operator"),
         DoubleQuestion => unreachable!("Cannot desugar the ?? operator"),
+        DoubleDot => unreachable!("Cannot desugar the .. operator"),
+        DoubleDotEquals => unreachable!("Cannot desugar the ..= operator"),
     }
 }
 
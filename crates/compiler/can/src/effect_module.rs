@@ -253,5 +253,6 @@ fn empty_record_pattern(var_store: &mut VarStore) -> Pattern {
         whole_var: var_store.fresh(),
         ext_var: var_store.fresh(),
         destructs: vec![],
+        opt_rest: None,
     }
 }
@@ -1,12 +1,12 @@
 use std::path::Path;
 
 use crate::procedure::References;
-use crate::scope::{ModuleLookup, Scope, SymbolLookup};
+use crate::scope::{closest_idents, ModuleLookup, Scope, ShadowPolicy, SymbolLookup};
 use bumpalo::Bump;
 use roc_collections::{MutMap, VecSet};
 use roc_module::ident::{Ident, ModuleName};
 use roc_module::symbol::{IdentIdsByModule, ModuleId, PQModuleName, PackageModuleIds, Symbol};
-use roc_problem::can::{Problem, RuntimeError};
+use roc_problem::can::{Problem, RuntimeError, Warning};
 use roc_region::all::{LineInfo, Loc, Region};
 use roc_types::subs::Variable;
 
@@ -25,6 +25,17 @@ pub struct Env<'a> {
     /// Problems we've encountered along the way, which will be reported to the user at the end.
     pub problems: Vec<Problem>,
 
+    /// Non-fatal issues we've encountered along the way. Unused defs and imports are also
+    /// reported as problems.
+    pub warnings: Vec<Warning>,
+
+    /// Whether value bindings may shadow bindings that are already in scope
+    pub shadow_policy: ShadowPolicy,
+
+    /// The names bound so far by the outermost pattern being canonicalized, if any. Binding one
+    /// of these again is a duplicate binding rather than a shadow.
+    pub pattern_bindings: Option<Vec<Ident>>,
+
     /// Closures
     pub closures: MutMap<Symbol, References>,
 
@@ -72,6 +83,9 @@ impl<'a> Env<'a> {
             dep_idents,
             qualified_module_ids,
             problems: Vec::new(),
+            warnings: Vec::new(),
+            shadow_policy: ShadowPolicy::Disallow,
+            pattern_bindings: None,
             closures: MutMap::default(),
             qualified_value_lookups: VecSet::default(),
             tailcallable_symbol: None,
@@ -166,6 +180,14 @@ impl<'a> Env<'a> {
                             .ident_strs()
                             .map(|(_, string)| string.into())
                             .collect(),
+                        closest_suggestions: closest_idents(
+                            ident,
+                            scope
+                                .locals
+                                .ident_ids
+                                .ident_strs()
+                                .map(|(_, string)| string.into()),
+                        ),
                         underscored_suggestion_region: None,
                     };
                     Err(error)
@@ -227,6 +249,10 @@ impl<'a> Env<'a> {
         self.problems.push(problem)
     }
 
+    pub fn warn(&mut self, warning: Warning) {
+        self.warnings.push(warning)
+    }
+
     pub fn line_info(&mut self) -> &LineInfo {
         if self.line_info.is_none() {
             *self.line_info = Some(LineInfo::new(self.src));
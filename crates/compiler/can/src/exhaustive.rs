@@ -240,6 +240,9 @@ fn index_var(
                     };
                     return Ok(std::iter::repeat(Variable::NULL).take(num_fields).collect());
                 }
+                FlatType::EmptyTuple => {
+                    internal_error!("empty tuples are not indexable")
+                }
                 FlatType::EmptyTagUnion => {
                     internal_error!("empty tag unions are not indexable")
                 }
@@ -1104,6 +1104,14 @@ pub fn canonicalize_expr<'a>(
         ast::Expr::Underscore(name) => {
             // we parse underscores, but they are not valid expression syntax
 
+            if let Some(declaration_region) = scope.lookup_ignored_local(name) {
+                env.warn(roc_problem::can::Warning::UsedUnderscoreBinding {
+                    name: (*name).into(),
+                    region,
+                    declaration_region,
+                });
+            }
+
             let problem = roc_problem::can::RuntimeError::MalformedIdentifier(
                 (*name).into(),
                 if name.is_empty() {
@@ -2129,7 +2137,7 @@ fn canonicalize_var_lookup(
     } else {
         // Since module_name was nonempty, this is a qualified var.
         // Look it up in the env!
-        match env.qualified_lookup(scope, module_name, ident, region) {
+        match scope.lookup_qualified_str(env, module_name, ident, region) {
             Ok(lookup) => {
                 output
                     .references
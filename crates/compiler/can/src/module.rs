@@ -7,10 +7,11 @@ use crate::desugar::desugar_record_destructures;
 use crate::env::{Env, FxMode};
 use crate::expr::{ClosureData, Declarations, ExpectLookup, Expr, Output, PendingDerives};
 use crate::pattern::{
-    canonicalize_record_destructs, BindingsFromPattern, Pattern, PermitShadows, RecordDestruct,
+    canonicalize_record_destructs, BindingsFromPattern, CanRecordDestructs, Pattern, PermitShadows,
+    RecordDestruct,
 };
 use crate::procedure::References;
-use crate::scope::Scope;
+use crate::scope::{Scope, ShadowPolicy};
 use bumpalo::Bump;
 use roc_collections::{MutMap, SendMap, VecMap, VecSet};
 use roc_error_macros::internal_error;
@@ -20,7 +21,7 @@ use roc_module::symbol::{IdentId, IdentIds, IdentIdsByModule, ModuleId, PackageM
 use roc_parse::ast::{Defs, TypeAnnotation};
 use roc_parse::header::HeaderType;
 use roc_parse::pattern::PatternType;
-use roc_problem::can::{Problem, RuntimeError};
+use roc_problem::can::{Problem, RuntimeError, Warning};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{ExposedTypesStorageSubs, Subs, VarStore, Variable};
 use roc_types::types::{AbilitySet, Alias, Type};
@@ -157,6 +158,7 @@ impl ModuleParams {
             whole_var: self.record_var,
             ext_var: self.record_ext_var,
             destructs: self.destructs.clone(),
+            opt_rest: None,
         };
         let loc_record_pattern = Loc::at(self.region, record_pattern);
 
@@ -181,6 +183,7 @@ pub struct ModuleOutput {
     pub exposed_imports: MutMap<Symbol, Region>,
     pub exposed_symbols: VecSet<Symbol>,
     pub problems: Vec<Problem>,
+    pub warnings: Vec<Warning>,
     pub referenced_values: VecSet<Symbol>,
     pub symbols_from_requires: Vec<(Loc<Symbol>, Loc<Type>)>,
     pub pending_derives: PendingDerives,
@@ -225,6 +228,7 @@ pub fn canonicalize_module_defs<'a>(
     var_store: &mut VarStore,
     opt_shorthand: Option<&'a str>,
     fx_mode: FxMode,
+    shadow_policy: ShadowPolicy,
 ) -> ModuleOutput {
     let mut can_exposed_imports = MutMap::default();
 
@@ -248,16 +252,19 @@ pub fn canonicalize_module_defs<'a>(
         opt_shorthand,
         fx_mode,
     );
+    env.shadow_policy = shadow_policy;
 
     for (name, alias) in aliases.into_iter() {
-        scope.add_alias(
+        if let Err(error) = scope.add_alias(
             name,
             alias.region,
             alias.type_variables,
             alias.infer_ext_in_output_variables,
             alias.typ,
             alias.kind,
-        );
+        ) {
+            env.problem(Problem::RuntimeError(error));
+        }
     }
 
     // Desugar operators (convert them to Apply calls, taking into account
@@ -283,7 +290,7 @@ pub fn canonicalize_module_defs<'a>(
 
         if first_char.is_lowercase() {
             match scope.import_symbol(ident, symbol, region) {
-                Ok(()) => {
+                Ok(_) => {
                     // Add an entry to exposed_imports using the current module's name
                     // as the key; e.g. if this is the Foo module and we have
                     // Bar exposes [baz] then insert Foo.baz as the key, so when
@@ -306,7 +313,7 @@ pub fn canonicalize_module_defs<'a>(
             // but now we know this symbol by a different identifier, so we still need to add it to
             // the scope
             match scope.import_symbol(ident, symbol, region) {
-                Ok(()) => {
+                Ok(_) => {
                     // here we do nothing special
                 }
                 Err((shadowed_symbol, _region)) => {
@@ -330,7 +337,7 @@ pub fn canonicalize_module_defs<'a>(
             let desugared_patterns =
                 desugar_record_destructures(&mut env, &mut scope, pattern.value);
 
-            let (destructs, _) = canonicalize_record_destructs(
+            let CanRecordDestructs { destructs, .. } = canonicalize_record_destructs(
                 &mut env,
                 var_store,
                 &mut scope,
@@ -389,7 +396,7 @@ pub fn canonicalize_module_defs<'a>(
             && !scope.abilities_store.is_specialization_name(symbol)
             && !symbol.is_exposed_for_builtin_derivers()
         {
-            env.problem(Problem::UnusedDef(symbol, region));
+            env.warn(Warning::UnusedDef(symbol, region));
         }
     }
 
@@ -485,6 +492,12 @@ pub fn canonicalize_module_defs<'a>(
 
     report_unused_imports(imports_introduced, &output.references, &mut env, &mut scope);
 
+    for (symbol, region) in scope.unused_aliases() {
+        if symbol.module_id() == home && !exposed_symbols.contains(&symbol) {
+            env.warn(Warning::UnusedAlias(symbol, region));
+        }
+    }
+
     for index in 0..declarations.len() {
         use crate::expr::DeclarationTag::*;
 
@@ -759,6 +772,7 @@ pub fn canonicalize_module_defs<'a>(
         referenced_values,
         exposed_imports: can_exposed_imports,
         problems: env.problems,
+        warnings: env.warnings,
         symbols_from_requires,
         pending_derives,
         loc_expects: collected.expects,
@@ -5,13 +5,14 @@ use crate::num::{
     finish_parsing_base, finish_parsing_float, finish_parsing_num, FloatBound, IntBound, NumBound,
     ParsedNumResult,
 };
-use crate::scope::{PendingAbilitiesInScope, Scope};
+use crate::scope::{PendingAbilitiesInScope, Scope, ShadowPolicy, ShadowWarning};
+use roc_collections::VecMap;
 use roc_exhaustive::ListArity;
 use roc_module::ident::{Ident, Lowercase, TagName};
 use roc_module::symbol::Symbol;
 use roc_parse::ast::{self, ExtractSpaces, StrLiteral, StrSegment};
 use roc_parse::pattern::PatternType;
-use roc_problem::can::{MalformedPatternProblem, Problem, RuntimeError, ShadowKind};
+use roc_problem::can::{MalformedPatternProblem, Problem, RuntimeError, ShadowKind, Warning};
 use roc_region::all::{Loc, Region};
 use roc_types::num::SingleQuoteBound;
 use roc_types::subs::{VarStore, Variable};
@@ -57,6 +58,9 @@ pub enum Pattern {
         whole_var: Variable,
         ext_var: Variable,
         destructs: Vec<Loc<RecordDestruct>>,
+        /// The `..rest` of e.g. `{ x, ..rest }`, which binds the fields that
+        /// are not explicitly destructured. The variable is the type of `rest`.
+        opt_rest: Option<(Variable, Loc<Symbol>)>,
     },
     TupleDestructure {
         whole_var: Variable,
@@ -178,7 +182,11 @@ impl Pattern {
 
             AppliedTag { tag_name, .. } => C::Ctor(tag_name.clone()),
             UnwrappedOpaque { opaque, .. } => C::Opaque(*opaque),
-            RecordDestructure { destructs, .. } if destructs.is_empty() => C::EmptyRecord,
+            RecordDestructure {
+                destructs,
+                opt_rest: None,
+                ..
+            } if destructs.is_empty() => C::EmptyRecord,
             RecordDestructure { .. } => C::Record,
             TupleDestructure { .. } => C::Tuple,
             List { .. } => C::List,
@@ -338,8 +346,23 @@ fn canonicalize_pattern_symbol(
     permit_shadows: PermitShadows,
     name: &str,
 ) -> Result<Symbol, Pattern> {
-    match scope.introduce_str(name, region) {
-        Ok(symbol) => {
+    // A name bound twice in one pattern is an error under any policy, so it must not shadow
+    let policy = if permit_shadows.0 || binds_twice(env, name) {
+        ShadowPolicy::Disallow
+    } else {
+        env.shadow_policy
+    };
+
+    match scope.introduce_with_shadow_policy(Ident::from(name), region, policy) {
+        Ok((symbol, opt_warning)) => {
+            if let Some(ShadowWarning { original, shadow }) = opt_warning {
+                env.warn(Warning::Shadowing {
+                    original_region: original.region,
+                    shadow,
+                });
+            }
+
+            record_binding(env, name);
             output.references.insert_bound(symbol);
 
             Ok(symbol)
@@ -350,11 +373,8 @@ fn canonicalize_pattern_symbol(
 
                 Ok(shadowed_symbol.value)
             } else {
-                env.problem(Problem::RuntimeError(RuntimeError::Shadowing {
-                    original_region: shadowed_symbol.region,
-                    shadow: shadow.clone(),
-                    kind: ShadowKind::Variable,
-                }));
+                let problem = shadowing_problem(env, shadowed_symbol.region, shadow.clone());
+                env.problem(problem);
                 output.references.insert_bound(new_symbol);
 
                 Err(Pattern::Shadowed(
@@ -367,6 +387,37 @@ fn canonicalize_pattern_symbol(
     }
 }
 
+/// Whether the pattern currently being canonicalized already binds `name`, so that binding it
+/// again means the pattern binds it twice.
+fn binds_twice(env: &Env, name: &str) -> bool {
+    env.pattern_bindings
+        .as_ref()
+        .is_some_and(|bindings| bindings.iter().any(|bound| bound.as_str() == name))
+}
+
+/// Remember that the pattern currently being canonicalized binds `name`.
+fn record_binding(env: &mut Env, name: &str) {
+    if let Some(bindings) = env.pattern_bindings.as_mut() {
+        bindings.push(Ident::from(name));
+    }
+}
+
+fn shadowing_problem(env: &Env, original_region: Region, shadow: Loc<Ident>) -> Problem {
+    if binds_twice(env, shadow.value.as_str()) {
+        Problem::RuntimeError(RuntimeError::DuplicatePatternBinding {
+            name: shadow.value,
+            first_region: original_region,
+            second_region: shadow.region,
+        })
+    } else {
+        Problem::RuntimeError(RuntimeError::Shadowing {
+            original_region,
+            shadow,
+            kind: ShadowKind::Variable,
+        })
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn canonicalize_pattern<'a>(
     env: &mut Env<'a>,
@@ -377,6 +428,43 @@ pub fn canonicalize_pattern<'a>(
     pattern: &ast::Pattern<'a>,
     region: Region,
     permit_shadows: PermitShadows,
+) -> Loc<Pattern> {
+    // Nested patterns are canonicalized through this function too; only the outermost one
+    // determines which names count as bound by "this pattern".
+    let is_outermost = env.pattern_bindings.is_none();
+
+    if is_outermost {
+        env.pattern_bindings = Some(Vec::new());
+    }
+
+    let can_pattern = canonicalize_pattern_help(
+        env,
+        var_store,
+        scope,
+        output,
+        pattern_type,
+        pattern,
+        region,
+        permit_shadows,
+    );
+
+    if is_outermost {
+        env.pattern_bindings = None;
+    }
+
+    can_pattern
+}
+
+#[allow(clippy::too_many_arguments)]
+fn canonicalize_pattern_help<'a>(
+    env: &mut Env<'a>,
+    var_store: &mut VarStore,
+    scope: &mut Scope,
+    output: &mut Output,
+    pattern_type: PatternType,
+    pattern: &ast::Pattern<'a>,
+    region: Region,
+    permit_shadows: PermitShadows,
 ) -> Loc<Pattern> {
     use roc_parse::ast::Pattern::*;
     use PatternType::*;
@@ -643,7 +731,11 @@ pub fn canonicalize_pattern<'a>(
             let ext_var = var_store.fresh();
             let whole_var = var_store.fresh();
 
-            let (destructs, opt_erroneous) = canonicalize_record_destructs(
+            let CanRecordDestructs {
+                destructs,
+                opt_rest,
+                opt_erroneous,
+            } = canonicalize_record_destructs(
                 env,
                 var_store,
                 scope,
@@ -660,6 +752,7 @@ pub fn canonicalize_pattern<'a>(
                 whole_var,
                 ext_var,
                 destructs,
+                opt_rest,
             })
         }
 
@@ -669,6 +762,9 @@ pub fn canonicalize_pattern<'a>(
         OptionalField(_name, _loc_pattern) => {
             unreachable!("should have been handled in RecordDestructure");
         }
+        RecordRest(_opt_name) => {
+            unreachable!("should have been handled in RecordDestructure");
+        }
 
         List(patterns) => {
             // We want to admit the following cases:
@@ -807,6 +903,15 @@ pub fn canonicalize_pattern<'a>(
     }
 }
 
+/// The canonicalized fields of a record destructure, see [canonicalize_record_destructs]
+pub struct CanRecordDestructs {
+    pub destructs: Vec<Loc<RecordDestruct>>,
+    /// The `..rest` that binds the remaining fields, if any
+    pub opt_rest: Option<(Variable, Loc<Symbol>)>,
+    /// Set when one of the fields is erroneous, e.g. because it shadows
+    pub opt_erroneous: Option<Pattern>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn canonicalize_record_destructs<'a>(
     env: &mut Env<'a>,
@@ -817,17 +922,79 @@ pub fn canonicalize_record_destructs<'a>(
     patterns: &ast::Collection<Loc<ast::Pattern<'a>>>,
     region: Region,
     permit_shadows: PermitShadows,
-) -> (Vec<Loc<RecordDestruct>>, Option<Pattern>) {
+) -> CanRecordDestructs {
     use ast::Pattern::*;
 
     let mut destructs = Vec::with_capacity(patterns.len());
+    let mut opt_rest = None;
     let mut opt_erroneous = None;
+    let mut seen = VecMap::with_capacity(patterns.len());
+    let mut seen_rest = false;
+
+    for (index, loc_pattern) in patterns.iter().enumerate() {
+        let pattern = loc_pattern.value.extract_spaces().item;
+
+        // A rest pattern takes whatever fields are left, so it must come once, and last
+        if let RecordRest(_) = pattern {
+            if seen_rest {
+                env.problem(Problem::MultipleRecordRestPattern {
+                    region: loc_pattern.region,
+                });
+
+                opt_erroneous = Some(Pattern::MalformedPattern(
+                    MalformedPatternProblem::DuplicateRecordRestPattern,
+                    loc_pattern.region,
+                ));
+
+                continue;
+            }
+
+            seen_rest = true;
+
+            if index + 1 < patterns.len() {
+                env.problem(Problem::RecordRestPatternNotLast {
+                    region: loc_pattern.region,
+                });
+
+                opt_erroneous = Some(Pattern::MalformedPattern(
+                    MalformedPatternProblem::RecordRestPatternNotLast,
+                    loc_pattern.region,
+                ));
+
+                continue;
+            }
+        }
 
-    for loc_pattern in patterns.iter() {
-        match loc_pattern.value.extract_spaces().item {
+        // A field that is destructured twice also binds its name twice; report it only once
+        let mut is_duplicate_field = false;
+
+        if let Identifier { ident: label } | RequiredField(label, _) | OptionalField(label, _) =
+            pattern
+        {
+            let field_name = Lowercase::from(label);
+
+            if let Some(replaced_region) = seen.insert(field_name.clone(), loc_pattern.region) {
+                is_duplicate_field = true;
+
+                env.problem(Problem::DuplicateRecordFieldPattern {
+                    field_name,
+                    record_region: region,
+                    field_region: loc_pattern.region,
+                    replaced_region,
+                });
+
+                opt_erroneous = Some(Pattern::MalformedPattern(
+                    MalformedPatternProblem::DuplicateRecordField,
+                    loc_pattern.region,
+                ));
+            }
+        }
+
+        match pattern {
             Identifier { ident: label } => {
                 match scope.introduce(label.into(), region) {
                     Ok(symbol) => {
+                        record_binding(env, label);
                         output.references.insert_bound(symbol);
 
                         destructs.push(Loc {
@@ -841,11 +1008,11 @@ pub fn canonicalize_record_destructs<'a>(
                         });
                     }
                     Err((shadowed_symbol, shadow, new_symbol)) => {
-                        env.problem(Problem::RuntimeError(RuntimeError::Shadowing {
-                            original_region: shadowed_symbol.region,
-                            shadow: shadow.clone(),
-                            kind: ShadowKind::Variable,
-                        }));
+                        if !is_duplicate_field {
+                            let problem =
+                                shadowing_problem(env, shadowed_symbol.region, shadow.clone());
+                            env.problem(problem);
+                        }
 
                         // No matter what the other patterns
                         // are, we're definitely shadowed and will
@@ -888,6 +1055,8 @@ pub fn canonicalize_record_destructs<'a>(
                 // an optional DOES introduce the label into scope!
                 match scope.introduce(label.into(), region) {
                     Ok(symbol) => {
+                        // Patterns inside the default value are unrelated to this one.
+                        let pattern_bindings = env.pattern_bindings.take();
                         let (can_default, expr_output) = canonicalize_expr(
                             env,
                             var_store,
@@ -895,6 +1064,8 @@ pub fn canonicalize_record_destructs<'a>(
                             loc_default.region,
                             &loc_default.value,
                         );
+                        env.pattern_bindings = pattern_bindings;
+                        record_binding(env, label);
 
                         // an optional field binds the symbol!
                         output.references.insert_bound(symbol);
@@ -912,11 +1083,11 @@ pub fn canonicalize_record_destructs<'a>(
                         });
                     }
                     Err((shadowed_symbol, shadow, new_symbol)) => {
-                        env.problem(Problem::RuntimeError(RuntimeError::Shadowing {
-                            original_region: shadowed_symbol.region,
-                            shadow: shadow.clone(),
-                            kind: ShadowKind::Variable,
-                        }));
+                        if !is_duplicate_field {
+                            let problem =
+                                shadowing_problem(env, shadowed_symbol.region, shadow.clone());
+                            env.problem(problem);
+                        }
 
                         // No matter what the other patterns
                         // are, we're definitely shadowed and will
@@ -930,6 +1101,33 @@ pub fn canonicalize_record_destructs<'a>(
                     }
                 };
             }
+            RecordRest(_) if pattern_type == PatternType::ModuleParams => {
+                opt_erroneous = Some(unsupported_pattern(env, pattern_type, loc_pattern.region));
+            }
+            RecordRest(None) => {
+                // An anonymous `..` binds nothing; record patterns are open anyway.
+            }
+            RecordRest(Some(name)) => {
+                match scope.introduce(name.into(), loc_pattern.region) {
+                    Ok(symbol) => {
+                        record_binding(env, name);
+                        output.references.insert_bound(symbol);
+
+                        opt_rest = Some((var_store.fresh(), Loc::at(loc_pattern.region, symbol)));
+                    }
+                    Err((shadowed_symbol, shadow, new_symbol)) => {
+                        let problem =
+                            shadowing_problem(env, shadowed_symbol.region, shadow.clone());
+                        env.problem(problem);
+
+                        opt_erroneous = Some(Pattern::Shadowed(
+                            shadowed_symbol.region,
+                            shadow,
+                            new_symbol,
+                        ));
+                    }
+                };
+            }
             _ => unreachable!(
                 "Any other pattern should have given a parse error: {:?}",
                 loc_pattern.value
@@ -937,7 +1135,11 @@ pub fn canonicalize_record_destructs<'a>(
         }
     }
 
-    (destructs, opt_erroneous)
+    CanRecordDestructs {
+        destructs,
+        opt_rest,
+        opt_erroneous,
+    }
 }
 
 /// When we detect an unsupported pattern type (e.g. 5 = 1 + 2 is unsupported because you can't
@@ -1028,9 +1230,17 @@ impl<'a> BindingsFromPattern<'a> {
                             let it = destructs.iter().rev().map(TupleDestruct);
                             stack.extend(it);
                         }
-                        RecordDestructure { destructs, .. } => {
+                        RecordDestructure {
+                            destructs,
+                            opt_rest,
+                            ..
+                        } => {
                             let it = destructs.iter().rev().map(RecordDestruct);
                             stack.extend(it);
+
+                            if let Some((_, rest_sym)) = opt_rest {
+                                return Some((rest_sym.value, rest_sym.region));
+                            }
                         }
                         NumLiteral(..)
                         | IntLiteral(..)
@@ -1,13 +1,14 @@
-use roc_collections::{VecMap, VecSet};
-use roc_error_macros::internal_error;
-use roc_module::ident::{Ident, ModuleName};
+use roc_collections::{ReferenceMatrix, VecMap, VecMapEntry, VecSet};
+use roc_module::ident::{Ident, Lowercase, ModuleName};
 use roc_module::symbol::{IdentId, IdentIds, ModuleId, ModuleIds, Symbol};
 use roc_problem::can::{RuntimeError, ScopeModuleSource};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::Variable;
-use roc_types::types::{Alias, AliasKind, AliasVar, EarlyReturnKind, Type};
+use roc_types::types::{Alias, AliasKind, AliasVar, EarlyReturnKind, MemberImpl, Type};
 
-use crate::abilities::PendingAbilitiesStore;
+use crate::abilities::{ImplKey, PendingAbilitiesStore};
+use crate::annotation::IntroducedVariables;
+use crate::env::Env;
 
 use bitvec::vec::BitVec;
 
@@ -19,6 +20,9 @@ pub struct Scope {
     /// The type aliases currently in scope
     pub aliases: VecMap<Symbol, Alias>,
 
+    /// Aliases that were resolved while canonicalizing a type
+    referenced_aliases: VecSet<Symbol>,
+
     /// The abilities currently in scope, and their implementors.
     pub abilities_store: PendingAbilitiesStore,
 
@@ -35,6 +39,9 @@ pub struct Scope {
     /// Identifiers that are imported
     imported_symbols: Vec<(Ident, Symbol, Region)>,
 
+    /// Whether the import at the same index in `imported_symbols` was ever resolved by a lookup
+    imported_symbols_used: BitVec,
+
     /// Shadows of an ability member, for example a local specialization of `eq` for the ability
     /// member `Eq implements eq : a, a -> Bool where a implements Eq` gets a shadow symbol it can use for its
     /// implementation.
@@ -49,6 +56,10 @@ pub struct Scope {
     /// We won't intern them because they're only used during canonicalization for error reporting.
     ignored_locals: VecMap<String, Region>,
 
+    /// Locals that were hidden because a new binding was allowed to shadow them.
+    /// They come back into scope when the scope that shadowed them is exited.
+    shadowed_locals: Vec<IdentId>,
+
     pub early_returns: Vec<(Variable, Region, EarlyReturnKind)>,
 }
 
@@ -60,26 +71,32 @@ impl Scope {
         starting_abilities_store: PendingAbilitiesStore,
     ) -> Scope {
         // Add all `Apply` types.
-        let default_imports = Symbol::apply_types_in_scope()
+        let default_imports: Vec<_> = Symbol::apply_types_in_scope()
             .into_iter()
             .map(|(a, (b, c))| (a, b, c))
             .collect();
 
+        // default imports are never reported as unused
+        let imported_symbols_used = BitVec::repeat(true, default_imports.len());
+
         Scope {
             home,
             exposed_ident_count: initial_ident_ids.len(),
             locals: ScopedIdentIds::from_ident_ids(home, initial_ident_ids),
             aliases: VecMap::default(),
+            referenced_aliases: VecSet::default(),
             abilities_store: starting_abilities_store,
             shadows: VecMap::default(),
             modules: ScopeModules::new(home, module_name),
             imported_symbols: default_imports,
+            imported_symbols_used,
             ignored_locals: VecMap::default(),
+            shadowed_locals: Vec::default(),
             early_returns: Vec::default(),
         }
     }
 
-    pub fn lookup(&self, ident: &Ident, region: Region) -> Result<SymbolLookup, RuntimeError> {
+    pub fn lookup(&mut self, ident: &Ident, region: Region) -> Result<SymbolLookup, RuntimeError> {
         self.lookup_str(ident.as_str(), region)
     }
 
@@ -92,9 +109,25 @@ impl Scope {
             .push(("Dict".into(), Symbol::DICT_DICT, Region::zero()));
         self.imported_symbols
             .push(("Set".into(), Symbol::SET_SET, Region::zero()));
+        self.imported_symbols_used.push(true);
+        self.imported_symbols_used.push(true);
+    }
+
+    pub fn lookup_str(
+        &mut self,
+        ident: &str,
+        region: Region,
+    ) -> Result<SymbolLookup, RuntimeError> {
+        let lookup = self.resolve_str(ident, region)?;
+
+        self.mark_import_used(ident, lookup.symbol);
+
+        Ok(lookup)
     }
 
-    pub fn lookup_str(&self, ident: &str, region: Region) -> Result<SymbolLookup, RuntimeError> {
+    /// Like [Self::lookup_str], but does not count as a use of the ident. This is meant for tools
+    /// that inspect the scope after canonicalization, e.g. to resolve links in docs.
+    pub fn resolve_str(&self, ident: &str, region: Region) -> Result<SymbolLookup, RuntimeError> {
         use ContainsIdent::*;
 
         match self.scope_contains_ident(ident) {
@@ -108,6 +141,10 @@ impl Scope {
                         value: Ident::from(ident),
                     },
                     suggestion_options: self.idents_in_scope().map(|v| v.as_ref().into()).collect(),
+                    closest_suggestions: closest_idents(
+                        ident,
+                        self.idents_in_scope().map(|v| v.as_str().into()),
+                    ),
                     // Check if the user just forgot to remove an underscore from an ignored identifier
                     underscored_suggestion_region: self.lookup_ignored_local(ident),
                 };
@@ -117,6 +154,109 @@ impl Scope {
         }
     }
 
+    /// Look up a module-qualified ident like `Json.decode` or, after `import Json as J`,
+    /// `J.decode`. The lookup itself is [Env::qualified_lookup]; on success, an unqualified
+    /// import of the same ident counts as used.
+    ///
+    /// This needs the [Env] because the idents other modules expose live in its `dep_idents`,
+    /// and because a successful value lookup is recorded in its `qualified_value_lookups`.
+    /// Marking the import as used is what needs `&mut self`. The result is a [SymbolLookup]
+    /// rather than a bare [Symbol], so that the params of the imported module are not lost.
+    pub fn lookup_qualified(
+        &mut self,
+        env: &mut Env,
+        module_name: &ModuleName,
+        ident: &Ident,
+        region: Region,
+    ) -> Result<SymbolLookup, RuntimeError> {
+        self.lookup_qualified_str(env, module_name.as_str(), ident.as_str(), region)
+    }
+
+    pub fn lookup_qualified_str(
+        &mut self,
+        env: &mut Env,
+        module_name: &str,
+        ident: &str,
+        region: Region,
+    ) -> Result<SymbolLookup, RuntimeError> {
+        let lookup = env.qualified_lookup(self, module_name, ident, region)?;
+
+        self.mark_import_used(ident, lookup.symbol);
+
+        Ok(lookup)
+    }
+
+    /// Remember that the import `ident` resolved to `symbol`, so it is not reported as unused.
+    fn mark_import_used(&mut self, ident: &str, symbol: Symbol) {
+        let position = self
+            .imported_symbols
+            .iter()
+            .position(|(import, import_symbol, _)| {
+                *import_symbol == symbol && import.as_str() == ident
+            });
+
+        if let Some(index) = position {
+            self.imported_symbols_used.set(index, true);
+        }
+    }
+
+    /// The imports that were never resolved by a lookup. Default imports (like `Str` or `List`)
+    /// are never included.
+    pub fn unused_imports(&self) -> Vec<(Ident, Region)> {
+        self.imported_symbols
+            .iter()
+            .zip(self.imported_symbols_used.iter())
+            .filter(|(_, used)| !**used)
+            .map(|((ident, _, region), _)| (ident.clone(), *region))
+            .collect()
+    }
+
+    /// Every name that an unqualified lookup can currently resolve, with the symbol it resolves
+    /// to. Locals come first, then imports; each symbol is listed once.
+    pub fn completions(&self) -> Vec<Completion> {
+        let locals = self
+            .locals
+            .symbols_in_scope()
+            .map(|(ident, symbol, region)| (ident, symbol, region, CompletionKind::Local));
+
+        // the latest import of a name wins, like in `has_imported_symbol`
+        let imports = self
+            .imported_symbols
+            .iter()
+            .rev()
+            .map(|(ident, symbol, region)| {
+                (ident.clone(), *symbol, *region, CompletionKind::Import)
+            });
+
+        let mut seen_idents = VecSet::default();
+        let mut seen_symbols = VecSet::default();
+        let mut completions = Vec::new();
+
+        for (ident, symbol, region, kind) in locals.chain(imports) {
+            // a name that is already taken (e.g. a local shadowing an import) can't resolve here
+            if seen_idents.insert(ident.clone()) || seen_symbols.insert(symbol) {
+                continue;
+            }
+
+            let kind = if self.aliases.contains_key(&symbol) {
+                CompletionKind::Alias
+            } else if self.abilities_store.is_ability_member_name(symbol) {
+                CompletionKind::AbilityMember
+            } else {
+                kind
+            };
+
+            completions.push(Completion {
+                ident,
+                symbol,
+                kind,
+                region,
+            });
+        }
+
+        completions
+    }
+
     fn idents_in_scope(&self) -> impl Iterator<Item = Ident> + '_ {
         let it1 = self.locals.idents_in_scope();
         let it2 = self.imported_symbols.iter().map(|t| t.0.clone());
@@ -128,7 +268,7 @@ impl Scope {
     /// current scope. E.g. `@Age` must reference an opaque `Age` declared in this module, not any
     /// other!
     pub fn lookup_opaque_ref(
-        &self,
+        &mut self,
         opaque_ref: &str,
         lookup_region: Region,
     ) -> Result<(Symbol, &Alias), RuntimeError> {
@@ -138,22 +278,45 @@ impl Scope {
 
         match self.locals.has_in_scope(&opaque) {
             Some((symbol, _)) => match self.lookup_opaque_alias(symbol) {
-                Ok(alias) => Ok((symbol, alias)),
+                Ok(_) => {
+                    self.referenced_aliases.insert(symbol);
+
+                    Ok((symbol, self.aliases.get(&symbol).unwrap()))
+                }
                 Err(opt_alias_def_region) => {
                     Err(self.opaque_not_defined_error(opaque, lookup_region, opt_alias_def_region))
                 }
             },
             None => {
                 // opaque types can only be wrapped/unwrapped in the scope they are defined in (and below)
-                let error = if let Some((_, decl_region)) = self.has_imported_symbol(opaque_str) {
-                    // specific error for when the opaque is imported, which definitely does not work
-                    RuntimeError::OpaqueOutsideScope {
+                let imports: Vec<_> = self
+                    .imported_symbols
+                    .iter()
+                    .filter(|(import, _, _)| import.as_str() == opaque_str)
+                    .collect();
+
+                // a user import may replace a default builtin import of the same name, which is
+                // then no longer a candidate
+                let is_replaced = |symbol: Symbol| {
+                    symbol.is_automatically_imported()
+                        && imports
+                            .iter()
+                            .any(|(_, other, _)| !other.is_automatically_imported())
+                };
+
+                let imported_region = imports
+                    .iter()
+                    .find(|(_, symbol, _)| !is_replaced(*symbol))
+                    .map(|(_, _, decl_region)| *decl_region);
+
+                // specific error for when the opaque is imported, which definitely does not work
+                let error = match imported_region {
+                    Some(decl_region) => RuntimeError::OpaqueOutsideScope {
                         opaque,
                         referenced_region: lookup_region,
                         imported_region: decl_region,
-                    }
-                } else {
-                    self.opaque_not_defined_error(opaque, lookup_region, None)
+                    },
+                    None => self.opaque_not_defined_error(opaque, lookup_region, None),
                 };
 
                 Err(error)
@@ -161,6 +324,29 @@ impl Scope {
         }
     }
 
+    /// Like [Scope::lookup_opaque_ref], but also checks that the opaque type is applied to as
+    /// many type arguments as it declares. For example, `@Age U32` is an error when `Age` takes
+    /// no type parameters.
+    pub fn lookup_opaque_ref_applied(
+        &mut self,
+        opaque_ref: &str,
+        num_type_arguments: usize,
+        lookup_region: Region,
+    ) -> Result<(Symbol, &Alias), RuntimeError> {
+        let (symbol, alias) = self.lookup_opaque_ref(opaque_ref, lookup_region)?;
+
+        if alias.type_variables.len() == num_type_arguments {
+            Ok((symbol, alias))
+        } else {
+            Err(RuntimeError::OpaqueArityMismatch {
+                opaque: symbol,
+                expected: alias.type_variables.len(),
+                got: num_type_arguments,
+                region: lookup_region,
+            })
+        }
+    }
+
     fn lookup_opaque_alias(&self, symbol: Symbol) -> Result<&Alias, Option<Region>> {
         match self.aliases.get(&symbol) {
             None => Err(None),
@@ -209,8 +395,11 @@ impl Scope {
     }
 
     fn has_imported_symbol(&self, ident: &str) -> Option<(SymbolLookup, Region)> {
+        // the latest import wins: an import can only reuse a name if it replaces a builtin type
+        // that is in scope by default, see `import_symbol`
         self.imported_symbols
             .iter()
+            .rev()
             .find_map(|(import, symbol, original_region)| {
                 if ident == import.as_str() {
                     match self.modules.lookup_by_id(&symbol.module_id()) {
@@ -225,10 +414,14 @@ impl Scope {
 
     /// Is an identifier in scope, either in the locals or imports
     fn scope_contains_ident(&self, ident: &str) -> ContainsIdent {
-        // exposed imports are likely to be small
-        match self.has_imported_symbol(ident) {
-            Some((symbol, region)) => ContainsIdent::InScope(symbol, region),
-            None => self.locals.contains_ident(ident),
+        // a local can only be in scope alongside an import of the same name if it was allowed to
+        // shadow that import, in which case the local wins
+        match self.locals.contains_ident(ident) {
+            ContainsIdent::InScope(symbol, region) => ContainsIdent::InScope(symbol, region),
+            not_in_scope => match self.has_imported_symbol(ident) {
+                Some((symbol, region)) => ContainsIdent::InScope(symbol, region),
+                None => not_in_scope,
+            },
         }
     }
 
@@ -301,6 +494,37 @@ impl Scope {
         }
     }
 
+    /// Like [Self::introduce], but the `policy` decides whether shadowing an ident that is
+    /// already in scope is an error.
+    ///
+    /// When shadowing is allowed, the new symbol is brought into scope and hides the original
+    /// until the current scope is exited. With [ShadowPolicy::WarnAndAllow], the shadowing is
+    /// returned alongside the symbol so the caller can report it.
+    #[allow(clippy::type_complexity)]
+    pub fn introduce_with_shadow_policy(
+        &mut self,
+        ident: Ident,
+        region: Region,
+        policy: ShadowPolicy,
+    ) -> Result<(Symbol, Option<ShadowWarning>), (Loc<Symbol>, Loc<Ident>, Symbol)> {
+        match self.introduce_str(ident.as_str(), region) {
+            Ok(symbol) => Ok((symbol, None)),
+            Err(error) if policy == ShadowPolicy::Disallow => Err(error),
+            Err((original, shadow, symbol)) => {
+                if let Some(hidden) = self.locals.shadow(original.value, symbol) {
+                    self.shadowed_locals.push(hidden);
+                }
+
+                let warning = match policy {
+                    ShadowPolicy::WarnAndAllow => Some(ShadowWarning { original, shadow }),
+                    ShadowPolicy::SilentlyAllow | ShadowPolicy::Disallow => None,
+                };
+
+                Ok((symbol, warning))
+            }
+        }
+    }
+
     /// Like [Self::introduce], but does not introduce a new symbol for the shadowing symbol.
     pub fn introduce_without_shadow_symbol(
         &mut self,
@@ -373,6 +597,40 @@ impl Scope {
         }
     }
 
+    /// Declares `impl_symbol` as the specialization of the ability member `member` for the type
+    /// `for_type`.
+    ///
+    /// Fails when `for_type` already has a specialization for `member`; the existing one is kept.
+    pub fn register_ability_specialization(
+        &mut self,
+        ability: Symbol,
+        member: Symbol,
+        for_type: Symbol,
+        impl_symbol: Symbol,
+    ) -> Result<(), RuntimeError> {
+        let impl_key = ImplKey {
+            opaque: for_type,
+            ability_member: member,
+        };
+
+        if self
+            .abilities_store
+            .declared_implementation(impl_key)
+            .is_some()
+        {
+            return Err(RuntimeError::OverlappingSpecialization {
+                ability,
+                member,
+                for_type,
+            });
+        }
+
+        self.abilities_store
+            .register_declared_implementations(for_type, [(member, MemberImpl::Impl(impl_symbol))]);
+
+        Ok(())
+    }
+
     pub fn get_member_shadow(&self, ability_member: Symbol) -> Option<&Loc<Symbol>> {
         self.shadows.get(&ability_member)
     }
@@ -390,25 +648,93 @@ impl Scope {
     ///
     /// Returns Err if this would shadow an existing ident, including the
     /// Symbol and Region of the ident we already had in scope under that name.
+    ///
+    /// The exception are the builtin types that are in scope by default (like `Dict`): a
+    /// non-builtin import replaces those, and returns the builtin symbol it shadows.
     pub fn import_symbol(
         &mut self,
         ident: Ident,
         symbol: Symbol,
         region: Region,
-    ) -> Result<(), (Symbol, Region)> {
-        match self.scope_contains_ident(ident.as_str()) {
+    ) -> Result<Option<Symbol>, (Symbol, Region)> {
+        let shadowed_builtin = match self.scope_contains_ident(ident.as_str()) {
             ContainsIdent::InScope(
                 SymbolLookup {
-                    symbol,
+                    symbol: existing,
                     module_params: _,
                 },
-                region,
-            ) => Err((symbol, region)),
-            ContainsIdent::NotPresent | ContainsIdent::NotInScope(_) => {
-                self.imported_symbols.push((ident, symbol, region));
-                Ok(())
+                existing_region,
+            ) => {
+                if existing.is_automatically_imported() && !symbol.is_automatically_imported() {
+                    Some(existing)
+                } else {
+                    return Err((existing, existing_region));
+                }
+            }
+            ContainsIdent::NotPresent | ContainsIdent::NotInScope(_) => None,
+        };
+
+        self.imported_symbols.push((ident, symbol, region));
+        self.imported_symbols_used.push(false);
+
+        Ok(shadowed_builtin)
+    }
+
+    /// Import a Symbol under a local name of our choosing, e.g. `import Json exposing [decode as dec]`.
+    ///
+    /// The Symbol keeps its original name; only unqualified lookups of `local_name` find it.
+    /// Conflicts are reported exactly like [Scope::import_symbol].
+    pub fn import_as(
+        &mut self,
+        local_name: Ident,
+        symbol: Symbol,
+        region: Region,
+    ) -> Result<Option<Symbol>, (Symbol, Region)> {
+        self.import_symbol(local_name, symbol, region)
+    }
+
+    /// Bring a module into scope under an alias, e.g. `import Json as J`, so that
+    /// qualified lookups like `J.decode` resolve to that module.
+    ///
+    /// Returns Err with the source of the existing module if the alias is already taken
+    /// by a different module.
+    pub fn import_module_as(
+        &mut self,
+        alias: ModuleName,
+        module_id: ModuleId,
+        region: Region,
+    ) -> Result<(), ScopeModuleSource> {
+        self.modules.insert(alias, module_id, None, region)
+    }
+
+    /// Import a batch of Symbols exposed by `module`, e.g. all the names in a module header.
+    ///
+    /// Like [Scope::import_symbol], but rather than stopping at the first ident that would
+    /// shadow an existing one, this imports all the others and returns every conflict, each
+    /// with the Symbol and Region of the ident we already had in scope.
+    pub fn import_all(
+        &mut self,
+        module: ModuleId,
+        exposed: &[(Ident, Symbol)],
+        region: Region,
+    ) -> Result<(), Vec<(Ident, Symbol, Region)>> {
+        let mut conflicts = Vec::new();
+
+        for (ident, symbol) in exposed {
+            debug_assert_eq!(symbol.module_id(), module);
+
+            if let Err((existing, existing_region)) =
+                self.import_symbol(ident.clone(), *symbol, region)
+            {
+                conflicts.push((ident.clone(), existing, existing_region));
             }
         }
+
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(conflicts)
+        }
     }
 
     pub fn add_alias(
@@ -419,51 +745,219 @@ impl Scope {
         infer_ext_in_output_variables: Vec<Variable>,
         typ: Type,
         kind: AliasKind,
-    ) {
-        let alias = create_alias(name, region, vars, infer_ext_in_output_variables, typ, kind);
-        self.aliases.insert(name, alias);
+    ) -> Result<(), RuntimeError> {
+        self.add_alias_with_names(
+            name,
+            region,
+            vars,
+            infer_ext_in_output_variables,
+            typ,
+            kind,
+            &IntroducedVariables::default(),
+        )
+    }
+
+    /// Like [Self::add_alias], but uses the variables `introduced` by the alias annotation to
+    /// name any unbound type variables in the error.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_alias_with_names(
+        &mut self,
+        name: Symbol,
+        region: Region,
+        vars: Vec<Loc<AliasVar>>,
+        infer_ext_in_output_variables: Vec<Variable>,
+        typ: Type,
+        kind: AliasKind,
+        introduced: &IntroducedVariables,
+    ) -> Result<(), RuntimeError> {
+        match self.aliases.entry(name) {
+            VecMapEntry::Occupied(entry) => Err(RuntimeError::DuplicateAlias {
+                alias: name,
+                original_region: entry.get().region,
+                duplicate_region: region,
+            }),
+            VecMapEntry::Vacant(_) if references_itself(name, &vars, &typ, kind) => {
+                Err(RuntimeError::RecursiveAliasWithoutRecursionVar {
+                    symbol: name,
+                    region,
+                })
+            }
+            VecMapEntry::Vacant(entry) => {
+                match create_alias(
+                    region,
+                    vars,
+                    infer_ext_in_output_variables,
+                    typ,
+                    kind,
+                    introduced,
+                ) {
+                    Ok(alias) => {
+                        entry.insert(alias);
+
+                        Ok(())
+                    }
+                    Err(AliasError::UnboundTypeVariables { names }) => {
+                        Err(RuntimeError::UnboundTypeVariablesInAlias {
+                            alias: name,
+                            region,
+                            unbound: names,
+                        })
+                    }
+                    Err(AliasError::DuplicateTypeVariable {
+                        name,
+                        first_region,
+                        duplicate_region,
+                    }) => Err(RuntimeError::DuplicateTypeVariable {
+                        name,
+                        first_region,
+                        duplicate_region,
+                    }),
+                }
+            }
+        }
     }
 
     pub fn lookup_alias(&self, symbol: Symbol) -> Option<&Alias> {
         self.aliases.get(&symbol)
     }
 
+    /// Like [Self::lookup_alias], but also records that the alias is referenced, so it is not
+    /// reported by [Self::unused_aliases]. The reference is recorded even if the alias is not in
+    /// scope yet, e.g. when it is used in the body of another alias that is introduced first.
+    pub fn reference_alias(&mut self, symbol: Symbol) -> Option<&Alias> {
+        self.referenced_aliases.insert(symbol);
+
+        self.aliases.get(&symbol)
+    }
+
+    /// The aliases in scope that were never referenced by a canonicalized type. Opaque types
+    /// exposed by the module are part of its API, and are never included.
+    pub fn unused_aliases(&self) -> Vec<(Symbol, Region)> {
+        self.aliases
+            .iter()
+            .filter(|(symbol, alias)| {
+                let exposed_opaque = alias.kind == AliasKind::Opaque
+                    && symbol.module_id() == self.home
+                    && symbol.ident_id().index() < self.exposed_ident_count;
+
+                !exposed_opaque && !self.referenced_aliases.contains(symbol)
+            })
+            .map(|(symbol, alias)| (*symbol, alias.region))
+            .collect()
+    }
+
     pub fn contains_alias(&mut self, name: Symbol) -> bool {
         self.aliases.contains_key(&name)
     }
 
+    /// Find groups of aliases in this scope that refer to each other in a loop that can never be
+    /// expanded, like `A : B` and `B : A`, or a structural alias that refers to itself.
+    ///
+    /// Opaque types and tag unions may be recursive, so a group that contains one of those, or
+    /// an alias that already has a recursion variable, is not reported.
+    pub fn check_alias_cycles(&self) -> Vec<AliasCycle> {
+        let symbols: Vec<Symbol> = self.aliases.keys().copied().collect();
+        let aliases: Vec<&Alias> = self.aliases.values().collect();
+
+        let mut matrix = ReferenceMatrix::new(symbols.len());
+
+        for (index, alias) in aliases.iter().enumerate() {
+            for referenced in alias.typ.symbols() {
+                if let Some(ref_index) = symbols.iter().position(|s| *s == referenced) {
+                    matrix.set_row_col(index, ref_index, true);
+                }
+            }
+        }
+
+        let mut cycles = Vec::new();
+
+        for (group, _) in matrix.strongly_connected_components_all().groups() {
+            let first = group.first_one().unwrap();
+
+            let is_cycle = group.count_ones() > 1 || matrix.get_row_col(first, first);
+
+            let can_be_recursive = group.iter_ones().any(|index| {
+                let alias = aliases[index];
+
+                alias.kind == AliasKind::Opaque
+                    || !alias.recursion_variables.is_empty()
+                    || alias.typ.is_tag_union_like()
+            });
+
+            if is_cycle && !can_be_recursive {
+                let chain = std::iter::once(first)
+                    .chain(cycle_chain(&matrix, group, first))
+                    .map(|index| Loc::at(aliases[index].region, symbols[index]))
+                    .collect();
+
+                cycles.push(AliasCycle { chain });
+            }
+        }
+
+        cycles
+    }
+
     /// Enter an inner scope within a definition, e.g. a def or when block.
     pub fn inner_def_scope<F, T>(&mut self, f: F) -> T
     where
         F: FnOnce(&mut Scope) -> T,
     {
-        // store enough information to roll back to the original outer scope
-        //
+        let snapshot = self.snapshot();
+
+        let result = f(self);
+
+        self.rollback(snapshot);
+
+        result
+    }
+
+    /// Store enough information to roll back to the current scope later, see [Self::rollback].
+    pub fn snapshot(&self) -> ScopeSnapshot {
         // - abilities_store: ability definitions not allowed in inner scopes
-        // - locals: everything introduced in the inner scope is marked as not in scope in the rollback
-        // - imports: everything that was imported in the inner scope is removed in the rollback
-        // - aliases: stored in a VecMap, we just discard anything added in an inner scope
+        // - locals: everything introduced after the snapshot is marked as not in scope in the rollback
+        // - imports: everything that was imported after the snapshot is removed in the rollback
+        // - aliases: stored in a VecMap, we just discard anything added after the snapshot
         // - exposed_ident_count: unchanged
         // - home: unchanged
-        let aliases_count = self.aliases.len();
-        let ignored_locals_count = self.ignored_locals.len();
-        let locals_snapshot = self.locals.in_scope.len();
-        let imported_symbols_snapshot = self.imported_symbols.len();
-        let imported_modules_snapshot = self.modules.len();
-
-        let result = f(self);
+        ScopeSnapshot {
+            aliases: self.aliases.len(),
+            ignored_locals: self.ignored_locals.len(),
+            locals: self.locals.in_scope.len(),
+            shadowed_locals: self.shadowed_locals.len(),
+            imported_symbols: self.imported_symbols.len(),
+            imported_modules: self.modules.len(),
+        }
+    }
 
-        self.aliases.truncate(aliases_count);
-        self.ignored_locals.truncate(ignored_locals_count);
-        self.imported_symbols.truncate(imported_symbols_snapshot);
-        self.modules.truncate(imported_modules_snapshot);
+    /// Discard everything that was added to the scope since the `snapshot` was taken.
+    ///
+    /// Symbols created in the meantime stay valid (they are never reused), but are no longer in
+    /// scope. Imports that were used in the meantime are still considered used.
+    pub fn rollback(&mut self, snapshot: ScopeSnapshot) {
+        let ScopeSnapshot {
+            aliases,
+            ignored_locals,
+            locals,
+            shadowed_locals,
+            imported_symbols,
+            imported_modules,
+        } = snapshot;
+
+        self.aliases.truncate(aliases);
+        self.ignored_locals.truncate(ignored_locals);
+        self.imported_symbols.truncate(imported_symbols);
+        self.imported_symbols_used.truncate(imported_symbols);
+        self.modules.truncate(imported_modules);
+
+        // locals that were shadowed after the snapshot are visible again
+        for hidden in self.shadowed_locals.drain(shadowed_locals..) {
+            self.locals.in_scope.set(hidden.index(), true);
+        }
 
-        // anything added in the inner scope is no longer in scope now
-        for i in locals_snapshot..self.locals.in_scope.len() {
+        // anything added after the snapshot is no longer in scope now
+        for i in locals..self.locals.in_scope.len() {
             self.locals.in_scope.set(i, false);
         }
-
-        result
     }
 
     /// Enter an inner scope within a child function, e.g. a closure body.
@@ -513,50 +1007,142 @@ impl Scope {
     }
 }
 
+/// A loop of aliases that refer to each other, as found by [Scope::check_alias_cycles]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AliasCycle {
+    /// The aliases in the loop, in reference order: each alias refers to the next one, and the
+    /// last one refers back to the first.
+    pub chain: Vec<Loc<Symbol>>,
+}
+
+/// The members of `cycle` that `start` goes through to reference itself, in the order in which
+/// they reference each other. This is the shortest such chain within `cycle`, so members of the
+/// cycle that are not on it are left out. Empty if `start` references itself directly.
+pub(crate) fn cycle_chain(
+    matrix: &ReferenceMatrix,
+    cycle: &bitvec::slice::BitSlice,
+    start: usize,
+) -> Vec<usize> {
+    // breadth-first, remembering which member each member was first reached from
+    let mut reached_from: Vec<Option<usize>> = vec![None; cycle.len()];
+    let mut queue = std::collections::VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        for next in matrix.references_for(current) {
+            if next == start {
+                let mut chain = Vec::new();
+                let mut at = current;
+
+                while at != start {
+                    chain.push(at);
+                    at = reached_from[at].unwrap();
+                }
+
+                chain.reverse();
+
+                return chain;
+            }
+
+            if cycle[next] && reached_from[next].is_none() {
+                reached_from[next] = Some(current);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Vec::new()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AliasError {
+    /// The alias body mentions type variables that are neither declared as alias arguments
+    /// nor bound as recursion or inferred extension variables, e.g. `MyAlias a : b`.
+    ///
+    /// Variables without a name in the annotation are reported as `*` or `_`.
+    UnboundTypeVariables { names: Vec<Lowercase> },
+    /// The alias declares a type variable name twice, e.g. `Pair a a : [Pair a a]`
+    DuplicateTypeVariable {
+        name: Lowercase,
+        first_region: Region,
+        duplicate_region: Region,
+    },
+}
+
+/// Does the structural alias `name` refer to itself in `typ`? Valid recursive tag unions
+/// refer to themselves through a recursion variable by the time they are added to the scope.
+/// Recursive uses with different type arguments (nested datatypes) are reported separately.
+fn references_itself(name: Symbol, vars: &[Loc<AliasVar>], typ: &Type, kind: AliasKind) -> bool {
+    if kind != AliasKind::Structural || !typ.contains_symbol(name) {
+        return false;
+    }
+
+    let args: Vec<Type> = vars.iter().map(|v| Type::Variable(v.value.var)).collect();
+
+    typ.clone()
+        .substitute_alias(name, &args, &Type::EmptyRec)
+        .is_ok()
+}
+
 pub fn create_alias(
-    name: Symbol,
     region: Region,
     vars: Vec<Loc<AliasVar>>,
     infer_ext_in_output_variables: Vec<Variable>,
     typ: Type,
     kind: AliasKind,
-) -> Alias {
+    introduced: &IntroducedVariables,
+) -> Result<Alias, AliasError> {
+    for (index, duplicate) in vars.iter().enumerate() {
+        if let Some(first) = vars[..index]
+            .iter()
+            .find(|first| first.value.name == duplicate.value.name)
+        {
+            return Err(AliasError::DuplicateTypeVariable {
+                name: duplicate.value.name.clone(),
+                first_region: first.region,
+                duplicate_region: duplicate.region,
+            });
+        }
+    }
+
     let roc_types::types::VariableDetail {
         type_variables,
         lambda_set_variables,
         recursion_variables,
     } = typ.variables_detail();
 
-    debug_assert!({
-        let mut hidden = type_variables;
+    let mut hidden = type_variables;
 
-        for var in (vars.iter().map(|lv| lv.value.var))
-            .chain(recursion_variables.iter().copied())
-            .chain(infer_ext_in_output_variables.iter().copied())
-        {
-            hidden.remove(&var);
-        }
+    for var in (vars.iter().map(|lv| lv.value.var))
+        .chain(recursion_variables.iter().copied())
+        .chain(infer_ext_in_output_variables.iter().copied())
+    {
+        hidden.remove(&var);
+    }
 
-        if !hidden.is_empty() {
-            internal_error!(
-                "Found unbound type variables {:?} \n in type alias {:?} {:?} {:?} : {:?}",
-                hidden,
-                name,
-                &vars,
-                &infer_ext_in_output_variables,
-                &typ
+    if !hidden.is_empty() {
+        let mut variables: Vec<Variable> = hidden.into_iter().collect();
+        variables.sort();
+
+        let names = variables
+            .into_iter()
+            .map(
+                |var| match introduced.iter_named().find(|v| v.variable() == var) {
+                    Some(named) => named.name().clone(),
+                    None if introduced.inferred.iter().any(|v| v.value == var) => "_".into(),
+                    None => "*".into(),
+                },
             )
-        }
+            .collect();
 
-        true
-    });
+        return Err(AliasError::UnboundTypeVariables { names });
+    }
 
     let lambda_set_variables: Vec<_> = lambda_set_variables
         .into_iter()
         .map(|v| roc_types::types::LambdaSet(Type::Variable(v)))
         .collect();
 
-    Alias {
+    Ok(Alias {
         region,
         type_variables: vars,
         lambda_set_variables,
@@ -564,7 +1150,112 @@ pub fn create_alias(
         recursion_variables,
         typ,
         kind,
+    })
+}
+
+/// The maximum number of "did you mean?" candidates reported for an unknown identifier
+const MAX_CLOSEST_SUGGESTIONS: usize = 3;
+
+/// The largest edit distance at which `candidate` is still considered a plausible typo of an
+/// identifier with `typo_len` characters. Short names get a tighter bound, otherwise something
+/// like `x` would suggest every other one-letter name in scope.
+fn suggestion_threshold(typo_len: usize) -> usize {
+    match typo_len {
+        0..=4 => 1,
+        _ => 2,
+    }
+}
+
+/// Casing and underscores are common sources of typos (`myValue` vs. `my_value`), so candidates
+/// are also compared to the typo with both of those removed.
+fn normalize_ident(ident: &str) -> String {
+    ident
+        .chars()
+        .filter(|c| *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Rank the `candidates` by their edit distance to `typo`, and return (at most) the top three
+/// that are close enough to plausibly be what the user meant. The distance is the smaller of the
+/// distance between the original spellings and the distance between their normalized forms.
+/// Ties keep the candidate order.
+pub(crate) fn closest_idents(
+    typo: &str,
+    candidates: impl Iterator<Item = Box<str>>,
+) -> Vec<Box<str>> {
+    let threshold = suggestion_threshold(typo.chars().count());
+    let normalized_typo = normalize_ident(typo);
+
+    let mut ranked: Vec<(usize, Box<str>)> = Vec::new();
+
+    for candidate in candidates {
+        if candidate.as_ref() == typo || ranked.iter().any(|(_, c)| *c == candidate) {
+            continue;
+        }
+
+        let distance = Ord::min(
+            distance::damerau_levenshtein(typo, &candidate),
+            distance::damerau_levenshtein(&normalized_typo, &normalize_ident(&candidate)),
+        );
+
+        if distance <= threshold {
+            ranked.push((distance, candidate));
+        }
     }
+
+    ranked.sort_by_key(|(distance, _)| *distance);
+    ranked.truncate(MAX_CLOSEST_SUGGESTIONS);
+
+    ranked.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+/// The state of a [Scope] at some point, which it can be rolled back to with [Scope::rollback].
+#[derive(Clone, Copy, Debug)]
+pub struct ScopeSnapshot {
+    aliases: usize,
+    ignored_locals: usize,
+    locals: usize,
+    shadowed_locals: usize,
+    imported_symbols: usize,
+    imported_modules: usize,
+}
+
+/// What to do when an introduced ident is already in scope.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShadowPolicy {
+    /// Shadowing is an error (this is what [Scope::introduce] does)
+    Disallow,
+    /// Shadowing is allowed, but reported back to the caller
+    WarnAndAllow,
+    /// Shadowing is allowed without further notice
+    SilentlyAllow,
+}
+
+/// An ident that was allowed to shadow an ident that was already in scope
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShadowWarning {
+    pub original: Loc<Symbol>,
+    pub shadow: Loc<Ident>,
+}
+
+/// What a [Completion] refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionKind {
+    Local,
+    Import,
+    Alias,
+    AbilityMember,
+}
+
+/// A name that resolves to `symbol` in the current scope, see [Scope::completions]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Completion {
+    pub ident: Ident,
+    pub symbol: Symbol,
+    pub kind: CompletionKind,
+    /// Where the local was defined, or where the import was written
+    pub region: Region,
 }
 
 #[derive(Debug)]
@@ -634,6 +1325,21 @@ impl ScopedIdentIds {
             })
     }
 
+    fn symbols_in_scope(&self) -> impl Iterator<Item = (Ident, Symbol, Region)> + '_ {
+        self.ident_ids
+            .ident_strs()
+            .zip(self.in_scope.iter())
+            .filter_map(|((ident_id, string), keep)| {
+                if *keep {
+                    let symbol = Symbol::new(self.home, ident_id);
+
+                    Some((Ident::from(string), symbol, self.regions[ident_id.index()]))
+                } else {
+                    None
+                }
+            })
+    }
+
     fn introduce_into_scope(&mut self, ident_name: &str, region: Region) -> IdentId {
         let id = self.ident_ids.add_str(ident_name);
 
@@ -671,16 +1377,35 @@ impl ScopedIdentIds {
         Symbol::new(self.home, id)
     }
 
-    fn gen_unique(&mut self) -> IdentId {
-        let id = self.ident_ids.gen_unique();
+    /// Bring the (scopeless) `shadow` symbol into scope. If `original` is an in-scope local, it is
+    /// taken out of scope so that lookups resolve to `shadow`; its IdentId is returned so that it
+    /// can be brought back into scope later.
+    fn shadow(&mut self, original: Symbol, shadow: Symbol) -> Option<IdentId> {
+        debug_assert_eq!(shadow.module_id(), self.home);
 
-        debug_assert_eq!(id.index(), self.in_scope.len());
-        debug_assert_eq!(id.index(), self.regions.len());
+        self.in_scope.set(shadow.ident_id().index(), true);
 
-        self.in_scope.push(false);
-        self.regions.push(Region::zero());
+        let original_id = original.ident_id();
 
-        id
+        if original.module_id() == self.home && self.in_scope[original_id.index()] {
+            self.in_scope.set(original_id.index(), false);
+
+            Some(original_id)
+        } else {
+            None
+        }
+    }
+
+    fn gen_unique(&mut self) -> IdentId {
+        let id = self.ident_ids.gen_unique();
+
+        debug_assert_eq!(id.index(), self.in_scope.len());
+        debug_assert_eq!(id.index(), self.regions.len());
+
+        self.in_scope.push(false);
+        self.regions.push(Region::zero());
+
+        id
     }
 }
 
@@ -830,8 +1555,13 @@ impl ModuleLookup {
 #[cfg(test)]
 mod test {
     use super::*;
-    use roc_module::symbol::ModuleIds;
+    use crate::env::FxMode;
+    use bumpalo::Bump;
+    use roc_module::ident::TagName;
+    use roc_module::symbol::{ModuleIds, PQModuleName, PackageModuleIds};
     use roc_region::all::Position;
+    use roc_types::types::TypeExtension;
+    use std::path::Path;
 
     use pretty_assertions::{assert_eq, assert_ne};
 
@@ -908,25 +1638,30 @@ mod test {
     }
 
     #[test]
-    fn default_idents_in_scope() {
+    fn shadow_policy_disallow_is_an_error() {
         let _register_module_debug_names = ModuleIds::default();
-        let scope = Scope::new(
+        let mut scope = Scope::new(
             ModuleId::ATTR,
             "#Attr".into(),
             IdentIds::default(),
             PendingAbilitiesStore::default(),
         );
 
-        let idents: Vec<_> = scope.idents_in_scope().collect();
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+        let ident = Ident::from("mezolit");
 
-        assert_eq!(
-            &idents,
-            &[Ident::from("Str"), Ident::from("List"), Ident::from("Box"),]
-        );
+        let first = scope.introduce(ident.clone(), region1).unwrap();
+        let (original, _ident, _shadow_symbol) = scope
+            .introduce_with_shadow_policy(ident.clone(), region2, ShadowPolicy::Disallow)
+            .unwrap_err();
+
+        assert_eq!(original.region, region1);
+        assert_eq!(first, scope.lookup(&ident, Region::zero()).unwrap().symbol);
     }
 
     #[test]
-    fn idents_with_inner_def_scope() {
+    fn shadow_policy_warn_and_allow_in_same_scope() {
         let _register_module_debug_names = ModuleIds::default();
         let mut scope = Scope::new(
             ModuleId::ATTR,
@@ -935,60 +1670,83 @@ mod test {
             PendingAbilitiesStore::default(),
         );
 
-        let idents: Vec<_> = scope.idents_in_scope().collect();
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+        let ident = Ident::from("mezolit");
 
-        assert_eq!(
-            &idents,
-            &[Ident::from("Str"), Ident::from("List"), Ident::from("Box"),]
+        let first = scope.introduce(ident.clone(), region1).unwrap();
+        let (shadow, warning) = scope
+            .introduce_with_shadow_policy(ident.clone(), region2, ShadowPolicy::WarnAndAllow)
+            .unwrap();
+
+        assert_ne!(first, shadow);
+
+        let warning = warning.unwrap();
+        assert_eq!(warning.original, Loc::at(region1, first));
+        assert_eq!(warning.shadow, Loc::at(region2, ident.clone()));
+
+        let lookup = scope.lookup(&ident, Region::zero()).unwrap();
+        assert_eq!(shadow, lookup.symbol);
+    }
+
+    #[test]
+    fn shadow_policy_allow_restores_outer_binding() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
         );
 
-        let builtin_count = idents.len();
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+        let ident = Ident::from("Sirály");
 
-        let region = Region::zero();
+        let outer = scope.introduce(ident.clone(), region1).unwrap();
 
-        let ident1 = Ident::from("uránia");
-        let ident2 = Ident::from("malmok");
-        let ident3 = Ident::from("Járnak");
+        scope.inner_def_scope(|inner| {
+            let (shadow, warning) = inner
+                .introduce_with_shadow_policy(ident.clone(), region2, ShadowPolicy::SilentlyAllow)
+                .unwrap();
 
-        scope.introduce(ident1.clone(), region).unwrap();
-        scope.introduce(ident2.clone(), region).unwrap();
-        scope.introduce(ident3.clone(), region).unwrap();
+            assert!(warning.is_none());
+            assert_eq!(shadow, inner.lookup(&ident, Region::zero()).unwrap().symbol);
+        });
 
-        let idents: Vec<_> = scope.idents_in_scope().collect();
+        let lookup = scope.lookup(&ident, Region::zero()).unwrap();
+        assert_eq!(outer, lookup.symbol);
+    }
 
-        assert_eq!(
-            &idents[builtin_count..],
-            &[ident1.clone(), ident2.clone(), ident3.clone(),]
+    #[test]
+    fn shadow_policy_allow_shadows_import() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
         );
 
-        scope.inner_def_scope(|inner| {
-            let ident4 = Ident::from("Ångström");
-            let ident5 = Ident::from("Sirály");
+        let ident = Ident::from("product");
+        let symbol = Symbol::LIST_PRODUCT;
+        let region = Region::zero();
 
-            inner.introduce(ident4.clone(), region).unwrap();
-            inner.introduce(ident5.clone(), region).unwrap();
+        scope.import_symbol(ident.clone(), symbol, region).unwrap();
 
-            let idents: Vec<_> = inner.idents_in_scope().collect();
+        scope.inner_def_scope(|inner| {
+            let (shadow, _) = inner
+                .introduce_with_shadow_policy(ident.clone(), region, ShadowPolicy::WarnAndAllow)
+                .unwrap();
 
-            assert_eq!(
-                &idents[builtin_count..],
-                &[
-                    ident1.clone(),
-                    ident2.clone(),
-                    ident3.clone(),
-                    ident4,
-                    ident5
-                ]
-            );
+            assert_eq!(shadow, inner.lookup(&ident, region).unwrap().symbol);
         });
 
-        let idents: Vec<_> = scope.idents_in_scope().collect();
-
-        assert_eq!(&idents[builtin_count..], &[ident1, ident2, ident3,]);
+        assert_eq!(symbol, scope.lookup(&ident, region).unwrap().symbol);
     }
 
     #[test]
-    fn import_is_in_scope() {
+    fn rollback_discards_speculative_changes() {
         let _register_module_debug_names = ModuleIds::default();
         let mut scope = Scope::new(
             ModuleId::ATTR,
@@ -997,21 +1755,38 @@ mod test {
             PendingAbilitiesStore::default(),
         );
 
-        let ident = Ident::from("product");
-        let symbol = Symbol::LIST_PRODUCT;
         let region = Region::zero();
+        let kept = Ident::from("malmok");
+        let discarded = Ident::from("Járnak");
+        let import = Ident::from("product");
 
-        assert!(scope.lookup(&ident, region).is_err());
+        scope.introduce(kept.clone(), region).unwrap();
 
-        assert!(scope.import_symbol(ident.clone(), symbol, region).is_ok());
+        let snapshot = scope.snapshot();
 
-        assert!(scope.lookup(&ident, region).is_ok());
+        scope.introduce(discarded.clone(), region).unwrap();
+        scope
+            .import_symbol(import.clone(), Symbol::LIST_PRODUCT, region)
+            .unwrap();
 
-        assert!(scope.idents_in_scope().any(|x| x == ident));
+        assert!(scope.lookup(&discarded, region).is_ok());
+        assert!(scope.lookup(&import, region).is_ok());
+
+        scope.rollback(snapshot);
+
+        assert!(scope.lookup(&kept, region).is_ok());
+        assert!(scope.lookup(&discarded, region).is_err());
+        assert!(scope.lookup(&import, region).is_err());
+
+        // after the rollback, the same names can be introduced again without shadowing
+        assert!(scope.introduce(discarded, region).is_ok());
+        assert!(scope
+            .import_symbol(import, Symbol::LIST_PRODUCT, region)
+            .is_ok());
     }
 
     #[test]
-    fn shadow_of_import() {
+    fn duplicate_alias_is_an_error() {
         let _register_module_debug_names = ModuleIds::default();
         let mut scope = Scope::new(
             ModuleId::ATTR,
@@ -1020,24 +1795,1256 @@ mod test {
             PendingAbilitiesStore::default(),
         );
 
-        let ident = Ident::from("product");
-        let symbol = Symbol::LIST_PRODUCT;
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+
+        let symbol = scope.introduce("Age".into(), region1).unwrap();
+
+        scope
+            .add_alias(
+                symbol,
+                region1,
+                vec![],
+                vec![],
+                Type::EmptyRec,
+                AliasKind::Structural,
+            )
+            .unwrap();
+
+        let error = scope
+            .add_alias(
+                symbol,
+                region2,
+                vec![],
+                vec![],
+                Type::EmptyTagUnion,
+                AliasKind::Structural,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            RuntimeError::DuplicateAlias {
+                alias: symbol,
+                original_region: region1,
+                duplicate_region: region2,
+            }
+        );
+
+        // the original alias is kept
+        assert_eq!(scope.lookup_alias(symbol).unwrap().typ, Type::EmptyRec);
+    }
+
+    #[test]
+    fn unreferenced_aliases_are_reported() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut exposed_ident_ids = IdentIds::default();
+        let exposed_id = exposed_ident_ids.add_str("Kardos");
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            exposed_ident_ids,
+            PendingAbilitiesStore::default(),
+        );
 
         let region1 = Region::from_pos(Position { offset: 10 });
         let region2 = Region::from_pos(Position { offset: 20 });
+        let region3 = Region::from_pos(Position { offset: 30 });
+
+        let referenced = scope.introduce("Bögre".into(), region1).unwrap();
+        let unreferenced = scope.introduce("Csupor".into(), region2).unwrap();
+        let exposed_opaque = scope.introduce("Kardos".into(), region3).unwrap();
+        assert_eq!(exposed_opaque, Symbol::new(ModuleId::ATTR, exposed_id));
+
+        for (symbol, region, kind) in [
+            (referenced, region1, AliasKind::Structural),
+            (unreferenced, region2, AliasKind::Structural),
+            (exposed_opaque, region3, AliasKind::Opaque),
+        ] {
+            scope
+                .add_alias(symbol, region, vec![], vec![], Type::EmptyRec, kind)
+                .unwrap();
+        }
 
-        scope.import_symbol(ident.clone(), symbol, region1).unwrap();
+        // a plain lookup does not count as a reference
+        assert!(scope.lookup_alias(referenced).is_some());
+        assert_eq!(
+            scope.unused_aliases(),
+            vec![(referenced, region1), (unreferenced, region2)]
+        );
 
-        let (original, _ident, shadow_symbol) =
-            scope.introduce(ident.clone(), region2).unwrap_err();
+        assert!(scope.reference_alias(referenced).is_some());
 
-        scope.register_debug_idents();
+        assert_eq!(scope.unused_aliases(), vec![(unreferenced, region2)]);
+    }
 
-        assert_ne!(symbol, shadow_symbol);
-        assert_eq!(original.region, region1);
+    #[test]
+    fn unbound_type_variables_in_alias_are_an_error() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+        let mut var_store = roc_types::subs::VarStore::default();
 
-        let lookup = scope.lookup(&ident, Region::zero()).unwrap();
+        let region = Region::from_pos(Position { offset: 10 });
 
-        assert_eq!(symbol, lookup.symbol);
+        let symbol = scope.introduce("Járnak".into(), region).unwrap();
+
+        // Járnak a : b
+        let declared = Loc::at(
+            region,
+            AliasVar {
+                name: "a".into(),
+                var: var_store.fresh(),
+                opt_bound_abilities: None,
+            },
+        );
+        let hidden = var_store.fresh();
+
+        let mut introduced = IntroducedVariables::default();
+        introduced.insert_named("a".into(), Loc::at(region, declared.value.var));
+        introduced.insert_named("b".into(), Loc::at(region, hidden));
+
+        let error = scope
+            .add_alias_with_names(
+                symbol,
+                region,
+                vec![declared],
+                vec![],
+                Type::Variable(hidden),
+                AliasKind::Structural,
+                &introduced,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            RuntimeError::UnboundTypeVariablesInAlias {
+                alias: symbol,
+                region,
+                unbound: vec!["b".into()],
+            }
+        );
+
+        assert!(scope.lookup_alias(symbol).is_none());
+    }
+
+    #[test]
+    fn duplicate_type_variables_in_alias_are_an_error() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+        let mut var_store = roc_types::subs::VarStore::default();
+
+        let region = Region::from_pos(Position { offset: 10 });
+        let region_a = Region::from_pos(Position { offset: 15 });
+        let region_b = Region::from_pos(Position { offset: 17 });
+        let region_a2 = Region::from_pos(Position { offset: 19 });
+
+        let mut alias_var = |name: &str, region| {
+            let var = var_store.fresh();
+            let loc = Loc::at(
+                region,
+                AliasVar {
+                    name: name.into(),
+                    var,
+                    opt_bound_abilities: None,
+                },
+            );
+
+            (loc, Type::Variable(var))
+        };
+
+        // Pair a b : [Pair a b]
+        let (a, a_type) = alias_var("a", region_a);
+        let (b, b_type) = alias_var("b", region_b);
+        let pair = scope.introduce("Pair".into(), region).unwrap();
+        let pair_tag =
+            |args| Type::TagUnion(vec![(TagName("Pair".into()), args)], TypeExtension::Closed);
+
+        assert!(scope
+            .add_alias(
+                pair,
+                region,
+                vec![a, b],
+                vec![],
+                pair_tag(vec![a_type, b_type]),
+                AliasKind::Structural,
+            )
+            .is_ok());
+
+        // Twin a b a : [Pair a b]
+        let (a, a_type) = alias_var("a", region_a);
+        let (b, b_type) = alias_var("b", region_b);
+        let (a2, _) = alias_var("a", region_a2);
+        let twin = scope.introduce("Twin".into(), region).unwrap();
+
+        let error = scope
+            .add_alias(
+                twin,
+                region,
+                vec![a, b, a2],
+                vec![],
+                pair_tag(vec![a_type, b_type]),
+                AliasKind::Structural,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            RuntimeError::DuplicateTypeVariable {
+                name: "a".into(),
+                first_region: region_a,
+                duplicate_region: region_a2,
+            }
+        );
+
+        assert!(scope.lookup_alias(twin).is_none());
+    }
+
+    #[test]
+    fn recursive_alias_without_recursion_var_is_an_error() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+        let mut var_store = roc_types::subs::VarStore::default();
+
+        let region = Region::from_pos(Position { offset: 10 });
+
+        // Tree : [Node Tree]
+        let tree = scope.introduce("Tree".into(), region).unwrap();
+        let node = Type::TagUnion(
+            vec![(
+                TagName("Node".into()),
+                vec![Type::Apply(tree, vec![], region)],
+            )],
+            TypeExtension::Closed,
+        );
+
+        let error = scope
+            .add_alias(tree, region, vec![], vec![], node, AliasKind::Structural)
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            RuntimeError::RecursiveAliasWithoutRecursionVar {
+                symbol: tree,
+                region
+            }
+        );
+        assert!(scope.lookup_alias(tree).is_none());
+
+        // ConsList : [Cons ConsList, Nil], where the recursion goes through a recursion variable
+        let cons_list = scope.introduce("ConsList".into(), region).unwrap();
+        let rec_var = var_store.fresh();
+        let cons = Type::RecursiveTagUnion(
+            rec_var,
+            vec![
+                (TagName("Cons".into()), vec![Type::Variable(rec_var)]),
+                (TagName("Nil".into()), vec![]),
+            ],
+            TypeExtension::Closed,
+        );
+
+        assert!(scope
+            .add_alias(
+                cons_list,
+                region,
+                vec![],
+                vec![],
+                cons,
+                AliasKind::Structural
+            )
+            .is_ok());
+        assert!(scope.lookup_alias(cons_list).is_some());
+    }
+
+    #[test]
+    fn opaque_ref_arity_mismatch() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::from_pos(Position { offset: 10 });
+        let lookup_region = Region::from_pos(Position { offset: 20 });
+
+        let symbol = scope.introduce("Age".into(), region).unwrap();
+
+        scope
+            .add_alias(
+                symbol,
+                region,
+                vec![],
+                vec![],
+                Type::EmptyRec,
+                AliasKind::Opaque,
+            )
+            .unwrap();
+
+        assert!(scope
+            .lookup_opaque_ref_applied("@Age", 0, lookup_region)
+            .is_ok());
+
+        assert_eq!(
+            scope
+                .lookup_opaque_ref_applied("@Age", 1, lookup_region)
+                .unwrap_err(),
+            RuntimeError::OpaqueArityMismatch {
+                opaque: symbol,
+                expected: 0,
+                got: 1,
+                region: lookup_region,
+            }
+        );
+    }
+
+    fn structural_alias(scope: &mut Scope, symbol: Symbol, region: Region, typ: Type) {
+        scope
+            .add_alias(symbol, region, vec![], vec![], typ, AliasKind::Structural)
+            .unwrap();
+    }
+
+    #[test]
+    fn self_referencing_alias_is_a_cycle() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::from_pos(Position { offset: 10 });
+        let symbol = scope.introduce("Sirály".into(), region).unwrap();
+
+        // Sirály : Sirály
+        let typ = Type::Apply(symbol, vec![], Region::zero());
+        structural_alias(&mut scope, symbol, region, typ);
+
+        assert_eq!(
+            scope.check_alias_cycles(),
+            vec![AliasCycle {
+                chain: vec![Loc::at(region, symbol)],
+            }]
+        );
+    }
+
+    #[test]
+    fn three_aliases_in_a_cycle() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region_a = Region::from_pos(Position { offset: 10 });
+        let region_b = Region::from_pos(Position { offset: 20 });
+        let region_c = Region::from_pos(Position { offset: 30 });
+        let region_d = Region::from_pos(Position { offset: 40 });
+
+        let a = scope.introduce("Sirály".into(), region_a).unwrap();
+        let b = scope.introduce("Ångström".into(), region_b).unwrap();
+        let c = scope.introduce("Járnak".into(), region_c).unwrap();
+        let d = scope.introduce("Malmok".into(), region_d).unwrap();
+
+        let apply = |symbol| Type::Apply(symbol, vec![], Region::zero());
+
+        // Sirály : Ångström
+        // Ångström : Járnak
+        // Járnak : Sirály
+        // Malmok : Sirály
+        structural_alias(&mut scope, a, region_a, apply(b));
+        structural_alias(&mut scope, b, region_b, apply(c));
+        structural_alias(&mut scope, c, region_c, apply(a));
+        structural_alias(&mut scope, d, region_d, apply(a));
+
+        assert_eq!(
+            scope.check_alias_cycles(),
+            vec![AliasCycle {
+                chain: vec![
+                    Loc::at(region_a, a),
+                    Loc::at(region_b, b),
+                    Loc::at(region_c, c)
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn recursive_tag_unions_and_opaques_are_not_cycles() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region_a = Region::from_pos(Position { offset: 10 });
+        let region_b = Region::from_pos(Position { offset: 20 });
+
+        let list = scope.introduce("Malmok".into(), region_a).unwrap();
+        let opaque = scope.introduce("Mezolit".into(), region_b).unwrap();
+
+        // Malmok : [Cons Malmok, Nil]
+        let tag_union = Type::TagUnion(
+            vec![
+                (
+                    TagName("Cons".into()),
+                    vec![Type::Apply(list, vec![], Region::zero())],
+                ),
+                (TagName("Nil".into()), vec![]),
+            ],
+            TypeExtension::Closed,
+        );
+        structural_alias(&mut scope, list, region_a, tag_union);
+
+        // Mezolit := Mezolit
+        scope
+            .add_alias(
+                opaque,
+                region_b,
+                vec![],
+                vec![],
+                Type::Apply(opaque, vec![], Region::zero()),
+                AliasKind::Opaque,
+            )
+            .unwrap();
+
+        assert_eq!(scope.check_alias_cycles(), vec![]);
+    }
+
+    #[test]
+    fn default_idents_in_scope() {
+        let _register_module_debug_names = ModuleIds::default();
+        let scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let idents: Vec<_> = scope.idents_in_scope().collect();
+
+        assert_eq!(
+            &idents,
+            &[Ident::from("Str"), Ident::from("List"), Ident::from("Box"),]
+        );
+    }
+
+    #[test]
+    fn completions_classify_symbols() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+        let region3 = Region::from_pos(Position { offset: 30 });
+        let region4 = Region::from_pos(Position { offset: 40 });
+
+        let local = scope.introduce("kicsi".into(), region1).unwrap();
+        let alias = scope.introduce("Kardos".into(), region2).unwrap();
+        scope
+            .add_alias(
+                alias,
+                region2,
+                vec![],
+                vec![],
+                Type::EmptyRec,
+                AliasKind::Structural,
+            )
+            .unwrap();
+        scope
+            .import_symbol(Ident::from("product"), Symbol::LIST_PRODUCT, region3)
+            .unwrap();
+
+        // a local that shadows an import hides the import
+        scope
+            .import_symbol(Ident::from("sum"), Symbol::LIST_SUM, region3)
+            .unwrap();
+        let (shadow, _) = scope
+            .introduce_with_shadow_policy("sum".into(), region4, ShadowPolicy::SilentlyAllow)
+            .unwrap();
+
+        let completions = scope.completions();
+        let find = |name: &str| {
+            let matching: Vec<_> = completions
+                .iter()
+                .filter(|completion| completion.ident.as_str() == name)
+                .collect();
+
+            assert_eq!(matching.len(), 1, "{name} should be listed exactly once");
+
+            (matching[0].symbol, matching[0].kind, matching[0].region)
+        };
+
+        assert_eq!(find("kicsi"), (local, CompletionKind::Local, region1));
+        assert_eq!(find("Kardos"), (alias, CompletionKind::Alias, region2));
+        assert_eq!(
+            find("product"),
+            (Symbol::LIST_PRODUCT, CompletionKind::Import, region3)
+        );
+        assert_eq!(
+            find("Str"),
+            (Symbol::STR_STR, CompletionKind::Import, Region::zero())
+        );
+        assert_eq!(find("sum"), (shadow, CompletionKind::Local, region4));
+        assert!(completions
+            .iter()
+            .all(|completion| completion.symbol != Symbol::LIST_SUM));
+    }
+
+    #[test]
+    fn idents_with_inner_def_scope() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let idents: Vec<_> = scope.idents_in_scope().collect();
+
+        assert_eq!(
+            &idents,
+            &[Ident::from("Str"), Ident::from("List"), Ident::from("Box"),]
+        );
+
+        let builtin_count = idents.len();
+
+        let region = Region::zero();
+
+        let ident1 = Ident::from("uránia");
+        let ident2 = Ident::from("malmok");
+        let ident3 = Ident::from("Járnak");
+
+        scope.introduce(ident1.clone(), region).unwrap();
+        scope.introduce(ident2.clone(), region).unwrap();
+        scope.introduce(ident3.clone(), region).unwrap();
+
+        let idents: Vec<_> = scope.idents_in_scope().collect();
+
+        assert_eq!(
+            &idents[builtin_count..],
+            &[ident1.clone(), ident2.clone(), ident3.clone(),]
+        );
+
+        scope.inner_def_scope(|inner| {
+            let ident4 = Ident::from("Ångström");
+            let ident5 = Ident::from("Sirály");
+
+            inner.introduce(ident4.clone(), region).unwrap();
+            inner.introduce(ident5.clone(), region).unwrap();
+
+            let idents: Vec<_> = inner.idents_in_scope().collect();
+
+            assert_eq!(
+                &idents[builtin_count..],
+                &[
+                    ident1.clone(),
+                    ident2.clone(),
+                    ident3.clone(),
+                    ident4,
+                    ident5
+                ]
+            );
+        });
+
+        let idents: Vec<_> = scope.idents_in_scope().collect();
+
+        assert_eq!(&idents[builtin_count..], &[ident1, ident2, ident3,]);
+    }
+
+    #[test]
+    fn lookup_suggests_closest_ident() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        for name in ["length", "width", "lengths", "breadth"] {
+            scope.introduce(Ident::from(name), region).unwrap();
+        }
+
+        match scope.lookup(&Ident::from("lenght"), region) {
+            Err(RuntimeError::LookupNotInScope {
+                closest_suggestions,
+                ..
+            }) => {
+                assert_eq!(
+                    closest_suggestions,
+                    vec![Box::<str>::from("length"), Box::<str>::from("lengths")]
+                );
+            }
+            other => panic!("expected LookupNotInScope, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn lookup_suggests_closest_unicode_ident() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        scope.introduce(Ident::from("uránia"), region).unwrap();
+        scope.introduce(Ident::from("Ångström"), region).unwrap();
+
+        match scope.lookup(&Ident::from("urania"), region) {
+            Err(RuntimeError::LookupNotInScope {
+                closest_suggestions,
+                ..
+            }) => {
+                assert_eq!(closest_suggestions, vec![Box::<str>::from("uránia")]);
+            }
+            other => panic!("expected LookupNotInScope, got {other:?}"),
+        }
+
+        match scope.lookup(&Ident::from("Angstrom"), region) {
+            Err(RuntimeError::LookupNotInScope {
+                closest_suggestions,
+                ..
+            }) => {
+                assert_eq!(closest_suggestions, vec![Box::<str>::from("Ångström")]);
+            }
+            other => panic!("expected LookupNotInScope, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn lookup_suggests_ident_that_differs_in_casing() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        for name in ["parseHttpUrl", "parseHtml"] {
+            scope.introduce(Ident::from(name), region).unwrap();
+        }
+
+        match scope.lookup(&Ident::from("parseHTTPURL"), region) {
+            Err(RuntimeError::LookupNotInScope {
+                closest_suggestions,
+                ..
+            }) => {
+                assert_eq!(closest_suggestions, vec![Box::<str>::from("parseHttpUrl")]);
+            }
+            other => panic!("expected LookupNotInScope, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn lookup_suggests_ident_that_differs_in_underscores() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        for name in ["read_file_to_str", "write_file"] {
+            scope.introduce(Ident::from(name), region).unwrap();
+        }
+
+        match scope.lookup(&Ident::from("readfiletostr"), region) {
+            Err(RuntimeError::LookupNotInScope {
+                closest_suggestions,
+                ..
+            }) => {
+                assert_eq!(
+                    closest_suggestions,
+                    vec![Box::<str>::from("read_file_to_str")]
+                );
+            }
+            other => panic!("expected LookupNotInScope, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn short_idents_do_not_over_suggest() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        scope.introduce(Ident::from("ab"), region).unwrap();
+
+        match scope.lookup(&Ident::from("x"), region) {
+            Err(RuntimeError::LookupNotInScope {
+                closest_suggestions,
+                ..
+            }) => {
+                assert!(closest_suggestions.is_empty());
+            }
+            other => panic!("expected LookupNotInScope, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn import_is_in_scope() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let ident = Ident::from("product");
+        let symbol = Symbol::LIST_PRODUCT;
+        let region = Region::zero();
+
+        assert!(scope.lookup(&ident, region).is_err());
+
+        assert!(scope.import_symbol(ident.clone(), symbol, region).is_ok());
+
+        assert!(scope.lookup(&ident, region).is_ok());
+
+        assert!(scope.idents_in_scope().any(|x| x == ident));
+    }
+
+    fn lookup_qualified(
+        scope: &mut Scope,
+        qualified_module_ids: &PackageModuleIds,
+        module_name: &str,
+        ident: &str,
+    ) -> Result<SymbolLookup, RuntimeError> {
+        let arena = Bump::new();
+        let dep_idents = IdentIds::exposed_builtins(0);
+        let mut env = Env::new(
+            &arena,
+            "",
+            ModuleId::ATTR,
+            Path::new("Test.roc"),
+            &dep_idents,
+            qualified_module_ids,
+            None,
+            FxMode::PurityInference,
+        );
+
+        scope.lookup_qualified(
+            &mut env,
+            &ModuleName::from(module_name),
+            &Ident::from(ident),
+            Region::zero(),
+        )
+    }
+
+    #[test]
+    fn qualified_lookup_of_exposed_value() {
+        let qualified_module_ids = PackageModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        // `product` was never imported unqualified, `List` exposes it
+        let lookup = lookup_qualified(
+            &mut scope,
+            &qualified_module_ids,
+            ModuleName::LIST,
+            "product",
+        )
+        .unwrap();
+
+        assert_eq!(lookup.symbol, Symbol::LIST_PRODUCT);
+    }
+
+    #[test]
+    fn qualified_lookup_marks_unqualified_import_used() {
+        let qualified_module_ids = PackageModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        scope
+            .import_symbol(Ident::from("product"), Symbol::LIST_PRODUCT, region)
+            .unwrap();
+
+        lookup_qualified(
+            &mut scope,
+            &qualified_module_ids,
+            ModuleName::LIST,
+            "product",
+        )
+        .unwrap();
+
+        assert!(scope.unused_imports().is_empty());
+    }
+
+    #[test]
+    fn lookup_value_imported_as_alias() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        scope
+            .import_as(Ident::from("prod"), Symbol::LIST_PRODUCT, region)
+            .unwrap();
+
+        let lookup = scope.lookup(&Ident::from("prod"), region).unwrap();
+        assert_eq!(lookup.symbol, Symbol::LIST_PRODUCT);
+
+        // the original name is not brought into scope
+        assert!(scope.lookup(&Ident::from("product"), region).is_err());
+    }
+
+    #[test]
+    fn qualified_lookup_through_module_alias() {
+        let qualified_module_ids = PackageModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+
+        let alias = ModuleName::from("L");
+
+        assert!(matches!(
+            lookup_qualified(&mut scope, &qualified_module_ids, "L", "product"),
+            Err(RuntimeError::ModuleNotImported {
+                module_exists: false,
+                ..
+            })
+        ));
+
+        scope
+            .import_module_as(alias.clone(), ModuleId::LIST, region)
+            .unwrap();
+
+        let lookup = lookup_qualified(&mut scope, &qualified_module_ids, "L", "product").unwrap();
+
+        assert_eq!(lookup.symbol, Symbol::LIST_PRODUCT);
+
+        // registering the same alias again for another module is a conflict
+        assert!(matches!(
+            scope.import_module_as(alias, ModuleId::DICT, region),
+            Err(ScopeModuleSource::Import(_))
+        ));
+    }
+
+    #[test]
+    fn qualified_lookup_not_exposed() {
+        let qualified_module_ids = PackageModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        match lookup_qualified(
+            &mut scope,
+            &qualified_module_ids,
+            ModuleName::LIST,
+            "frobnicate",
+        ) {
+            Err(RuntimeError::ValueNotExposed {
+                ident,
+                exposed_values,
+                ..
+            }) => {
+                assert_eq!(ident, Ident::from("frobnicate"));
+                assert!(exposed_values.contains(&Lowercase::from("product")));
+                assert!(exposed_values.contains(&Lowercase::from("map")));
+            }
+            other => panic!("expected ValueNotExposed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn qualified_lookup_module_not_imported() {
+        let mut qualified_module_ids = PackageModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let json = ModuleName::from("Json");
+
+        match lookup_qualified(&mut scope, &qualified_module_ids, "Json", "decode") {
+            Err(RuntimeError::ModuleNotImported {
+                module_name,
+                module_exists,
+                ..
+            }) => {
+                assert_eq!(module_name, json);
+                assert!(!module_exists);
+            }
+            other => panic!("expected ModuleNotImported, got {other:?}"),
+        }
+
+        // the module is known to the build, just not imported here
+        qualified_module_ids.get_or_insert(&PQModuleName::Unqualified(json));
+
+        match lookup_qualified(&mut scope, &qualified_module_ids, "Json", "decode") {
+            Err(RuntimeError::ModuleNotImported { module_exists, .. }) => {
+                assert!(module_exists);
+            }
+            other => panic!("expected ModuleNotImported, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unused_imports_are_reported() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+
+        let used = Ident::from("product");
+        let unused = Ident::from("sum");
+
+        scope
+            .import_symbol(used.clone(), Symbol::LIST_PRODUCT, region1)
+            .unwrap();
+        scope
+            .import_symbol(unused.clone(), Symbol::LIST_SUM, region2)
+            .unwrap();
+
+        // the default imports are not reported, even though they are never used
+        assert_eq!(
+            scope.unused_imports(),
+            vec![(used.clone(), region1), (unused.clone(), region2)]
+        );
+
+        scope.lookup(&used, Region::zero()).unwrap();
+
+        assert_eq!(scope.unused_imports(), vec![(unused, region2)]);
+    }
+
+    #[test]
+    fn opaque_ref_to_import() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let import_region = Region::from_pos(Position { offset: 10 });
+        let lookup_region = Region::from_pos(Position { offset: 20 });
+
+        scope
+            .import_symbol(Ident::from("Age"), Symbol::NUM_U32, import_region)
+            .unwrap();
+
+        match scope.lookup_opaque_ref("@Age", lookup_region) {
+            Err(RuntimeError::OpaqueOutsideScope {
+                imported_region, ..
+            }) => {
+                assert_eq!(imported_region, import_region);
+            }
+            other => panic!("expected OpaqueOutsideScope, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn opaque_ref_to_import_replacing_builtin() {
+        let mut module_ids = ModuleIds::default();
+        let my_strs = module_ids.get_or_insert(&"MyStrs".into());
+
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let ident = Ident::from("Str");
+        let mut ident_ids = IdentIds::default();
+        let my_str = Symbol::new(my_strs, ident_ids.add_str("Str"));
+
+        let import_region = Region::from_pos(Position { offset: 10 });
+        let lookup_region = Region::from_pos(Position { offset: 20 });
+
+        // `Str` is imported by default, and a user import replaces it
+        assert_eq!(
+            scope.import_symbol(ident, my_str, import_region),
+            Ok(Some(Symbol::STR_STR))
+        );
+
+        // only the user's `Str` is a candidate, the builtin it replaced is not
+        match scope.lookup_opaque_ref("@Str", lookup_region) {
+            Err(RuntimeError::OpaqueOutsideScope {
+                opaque,
+                referenced_region,
+                imported_region,
+            }) => {
+                assert_eq!(opaque, Ident::from("Str"));
+                assert_eq!(referenced_region, lookup_region);
+                assert_eq!(imported_region, import_region);
+            }
+            other => panic!("expected OpaqueOutsideScope, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn overlapping_ability_specialization_is_an_error() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::zero();
+        let hash_u32 = scope.introduce("hashU32".into(), region).unwrap();
+        let hash_u64 = scope.introduce("hashU64".into(), region).unwrap();
+        let other_hash_u32 = scope.introduce("otherHashU32".into(), region).unwrap();
+
+        let ability = Symbol::HASH_HASH_ABILITY;
+        let member = Symbol::HASH_HASH;
+
+        assert!(scope
+            .register_ability_specialization(ability, member, Symbol::NUM_U32, hash_u32)
+            .is_ok());
+        assert!(scope
+            .register_ability_specialization(ability, member, Symbol::NUM_U64, hash_u64)
+            .is_ok());
+
+        assert_eq!(
+            scope.register_ability_specialization(ability, member, Symbol::NUM_U32, other_hash_u32),
+            Err(RuntimeError::OverlappingSpecialization {
+                ability,
+                member,
+                for_type: Symbol::NUM_U32,
+            })
+        );
+
+        // The first specialization wins
+        let impl_key = ImplKey {
+            opaque: Symbol::NUM_U32,
+            ability_member: member,
+        };
+        assert_eq!(
+            scope.abilities_store.declared_implementation(impl_key),
+            Some(&MemberImpl::Impl(hash_u32))
+        );
+        assert_eq!(
+            scope.abilities_store.impl_key(hash_u64).map(|k| k.opaque),
+            Some(Symbol::NUM_U64)
+        );
+    }
+
+    #[test]
+    fn import_all_reports_every_conflict() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+
+        scope
+            .import_symbol(Ident::from("sum"), Symbol::LIST_SUM, region1)
+            .unwrap();
+        scope
+            .import_symbol(Ident::from("product"), Symbol::LIST_PRODUCT, region1)
+            .unwrap();
+
+        let batch = [
+            (Ident::from("sum"), Symbol::LIST_SUM),
+            (Ident::from("len"), Symbol::LIST_LEN_U64),
+            (Ident::from("product"), Symbol::LIST_PRODUCT),
+        ];
+
+        let conflicts = scope
+            .import_all(ModuleId::LIST, &batch, region2)
+            .unwrap_err();
+
+        assert_eq!(
+            conflicts,
+            vec![
+                (Ident::from("sum"), Symbol::LIST_SUM, region1),
+                (Ident::from("product"), Symbol::LIST_PRODUCT, region1),
+            ]
+        );
+
+        // the names that didn't conflict are still imported
+        let lookup = scope.lookup(&Ident::from("len"), Region::zero()).unwrap();
+        assert_eq!(lookup.symbol, Symbol::LIST_LEN_U64);
+    }
+
+    #[test]
+    fn shadow_of_import() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let ident = Ident::from("product");
+        let symbol = Symbol::LIST_PRODUCT;
+
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+
+        scope.import_symbol(ident.clone(), symbol, region1).unwrap();
+
+        let (original, _ident, shadow_symbol) =
+            scope.introduce(ident.clone(), region2).unwrap_err();
+
+        scope.register_debug_idents();
+
+        assert_ne!(symbol, shadow_symbol);
+        assert_eq!(original.region, region1);
+
+        let lookup = scope.lookup(&ident, Region::zero()).unwrap();
+
+        assert_eq!(symbol, lookup.symbol);
+    }
+
+    #[test]
+    fn import_may_shadow_builtin_type() {
+        let mut module_ids = ModuleIds::default();
+        let my_dicts = module_ids.get_or_insert(&"MyDicts".into());
+        let other_dicts = module_ids.get_or_insert(&"OtherDicts".into());
+
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let ident = Ident::from("Dict");
+        let mut ident_ids = IdentIds::default();
+        let dict_id = ident_ids.add_str("Dict");
+        let my_dict = Symbol::new(my_dicts, dict_id);
+        let other_dict = Symbol::new(other_dicts, dict_id);
+
+        let region1 = Region::from_pos(Position { offset: 10 });
+        let region2 = Region::from_pos(Position { offset: 20 });
+
+        // builtin types like `Dict` are imported as part of the module's initial scope
+        scope
+            .import_symbol(ident.clone(), Symbol::DICT_DICT, Region::zero())
+            .unwrap();
+        assert_eq!(
+            scope.lookup(&ident, Region::zero()).unwrap().symbol,
+            Symbol::DICT_DICT
+        );
+
+        // a user import replaces the builtin `Dict`
+        assert_eq!(
+            scope.import_symbol(ident.clone(), my_dict, region1),
+            Ok(Some(Symbol::DICT_DICT))
+        );
+        assert_eq!(
+            scope.lookup(&ident, Region::zero()).unwrap().symbol,
+            my_dict
+        );
+
+        // but a second user import of `Dict` is still an error
+        assert_eq!(
+            scope.import_symbol(ident.clone(), other_dict, region2),
+            Err((my_dict, region1))
+        );
+        assert_eq!(
+            scope.lookup(&ident, Region::zero()).unwrap().symbol,
+            my_dict
+        );
+    }
+
+    #[test]
+    fn explicit_import_of_builtin_type_is_not_a_shadow() {
+        let _register_module_debug_names = ModuleIds::default();
+        let mut scope = Scope::new(
+            ModuleId::ATTR,
+            "#Attr".into(),
+            IdentIds::default(),
+            PendingAbilitiesStore::default(),
+        );
+
+        let region = Region::from_pos(Position { offset: 10 });
+
+        scope
+            .import_symbol(Ident::from("Dict"), Symbol::DICT_DICT, Region::zero())
+            .unwrap();
+
+        // importing the builtin itself is reported as an explicit builtin import instead
+        assert_eq!(
+            scope.import_symbol(Ident::from("Dict"), Symbol::DICT_DICT, region),
+            Err((Symbol::DICT_DICT, Region::zero()))
+        );
     }
 }
@@ -1,8 +1,15 @@
 //! Traversals over the can ast.
 
-use roc_module::{ident::Lowercase, symbol::Symbol};
+use roc_module::{
+    ident::Lowercase,
+    symbol::{Interns, ModuleId, Symbol},
+};
 use roc_region::all::{Loc, Position, Region};
-use roc_types::{subs::Variable, types::MemberImpl};
+use roc_types::{
+    pretty_print::{name_and_print_var, DebugPrint},
+    subs::{Subs, Variable},
+    types::MemberImpl,
+};
 
 use crate::{
     abilities::AbilitiesStore,
@@ -600,9 +607,23 @@ pub fn walk_pattern<V: Visitor>(visitor: &mut V, pattern: &Pattern) {
             let (v, lp) = &**argument;
             visitor.visit_pattern(&lp.value, lp.region, Some(*v));
         }
-        RecordDestructure { destructs, .. } => destructs
-            .iter()
-            .for_each(|d| visitor.visit_record_destruct(&d.value, d.region)),
+        RecordDestructure {
+            destructs,
+            opt_rest,
+            ..
+        } => {
+            destructs
+                .iter()
+                .for_each(|d| visitor.visit_record_destruct(&d.value, d.region));
+
+            if let Some((rest_var, rest_sym)) = opt_rest {
+                visitor.visit_pattern(
+                    &Identifier(rest_sym.value),
+                    rest_sym.region,
+                    Some(*rest_var),
+                );
+            }
+        }
         TupleDestructure { destructs, .. } => destructs
             .iter()
             .for_each(|d| visitor.visit_tuple_destruct(&d.value, d.region)),
@@ -736,6 +757,70 @@ pub fn find_closest_type_at(
     visitor.region_typ
 }
 
+struct TypedRegionsVisitor {
+    typed_regions: Vec<(Region, Variable)>,
+}
+
+impl Visitor for TypedRegionsVisitor {
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        self.typed_regions.push((region, var));
+
+        walk_expr(self, expr, var);
+    }
+
+    fn visit_pattern(&mut self, pat: &Pattern, region: Region, opt_var: Option<Variable>) {
+        if let Some(var) = opt_var {
+            self.typed_regions.push((region, var));
+        }
+
+        walk_pattern(self, pat);
+    }
+}
+
+/// The region and type variable of every expression and typed pattern in [decls], outer nodes
+/// before the nodes they contain. These are the variables `constrain` generates constraints
+/// for, so once solved they hold the types of their regions.
+pub fn typed_regions(decls: &Declarations) -> Vec<(Region, Variable)> {
+    let mut visitor = TypedRegionsVisitor {
+        typed_regions: Vec::new(),
+    };
+    visitor.visit_decls(decls);
+    visitor.typed_regions
+}
+
+/// The smallest region in [typed_regions] that covers the byte at [offset], along with its
+/// variable. Of several equally small regions, the innermost one wins.
+pub fn innermost_typed_region(
+    typed_regions: &[(Region, Variable)],
+    offset: u32,
+) -> Option<(Region, Variable)> {
+    typed_regions
+        .iter()
+        .rev()
+        .filter(|(region, _)| (region.start().offset..region.end().offset).contains(&offset))
+        .min_by_key(|(region, _)| region.len())
+        .copied()
+}
+
+/// The pretty-printed type of the innermost node covering the byte at [offset], for hovers.
+/// [subs] must be the solved subs of the module the regions came from; naming the type's
+/// variables is rolled back afterwards.
+pub fn type_at_offset(
+    subs: &mut Subs,
+    home: ModuleId,
+    interns: &Interns,
+    typed_regions: &[(Region, Variable)],
+    offset: u32,
+) -> Option<(Region, String)> {
+    let (region, var) = innermost_typed_region(typed_regions, offset)?;
+
+    let snapshot = subs.snapshot();
+    let type_str = name_and_print_var(var, subs, home, interns, DebugPrint::NOTHING);
+    subs.rollback_to(snapshot);
+
+    Some((region, type_str))
+}
+
 /// Given an ability Foo has foo : ..., returns (T, foo1) if the symbol at the given region is a
 /// symbol foo1 that specializes foo for T. Otherwise if the symbol is foo but the specialization
 /// is unknown, (Foo, foo) is returned. Otherwise [None] is returned.
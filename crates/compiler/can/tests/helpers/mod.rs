@@ -4,10 +4,10 @@ use self::bumpalo::Bump;
 use roc_can::desugar;
 use roc_can::env::Env;
 use roc_can::expr::{canonicalize_expr, Expr};
-use roc_can::scope::Scope;
+use roc_can::scope::{Scope, ShadowPolicy};
 use roc_collections::all::MutMap;
 use roc_module::symbol::{IdentIds, Interns, ModuleId, ModuleIds, PackageModuleIds, Symbol};
-use roc_problem::can::Problem;
+use roc_problem::can::{Problem, Warning};
 use roc_region::all::{Loc, Region};
 use roc_types::subs::{VarStore, Variable};
 use roc_types::types::{AliasVar, Type};
@@ -26,11 +26,22 @@ pub fn can_expr(expr_str: &str) -> CanExprOut {
 pub struct CanExprOut {
     pub loc_expr: Loc<Expr>,
     pub problems: Vec<Problem>,
+    pub warnings: Vec<Warning>,
     pub interns: Interns,
 }
 
 #[allow(dead_code)]
 pub fn can_expr_with(arena: &Bump, home: ModuleId, expr_str: &str) -> CanExprOut {
+    can_expr_with_shadow_policy(arena, home, expr_str, ShadowPolicy::Disallow)
+}
+
+#[allow(dead_code)]
+pub fn can_expr_with_shadow_policy(
+    arena: &Bump,
+    home: ModuleId,
+    expr_str: &str,
+    shadow_policy: ShadowPolicy,
+) -> CanExprOut {
     let loc_expr = roc_parse::test_helpers::parse_loc_with(arena, expr_str).unwrap_or_else(|e| {
         panic!(
             "can_expr_with() got a parse error when attempting to canonicalize:\n\n{expr_str:?} {e:?}"
@@ -58,6 +69,7 @@ pub fn can_expr_with(arena: &Bump, home: ModuleId, expr_str: &str) -> CanExprOut
         None,
         roc_can::env::FxMode::PurityInference,
     );
+    env.shadow_policy = shadow_policy;
 
     // Desugar operators (convert them to Apply calls, taking into account
     // operator precedence and associativity rules), before doing other canonicalization.
@@ -68,17 +80,19 @@ pub fn can_expr_with(arena: &Bump, home: ModuleId, expr_str: &str) -> CanExprOut
     // rules multiple times unnecessarily.
     let loc_expr = desugar::desugar_expr(&mut env, &mut scope, &loc_expr);
 
-    scope.add_alias(
-        Symbol::NUM_INT,
-        Region::zero(),
-        vec![Loc::at_zero(AliasVar::unbound(
-            "a".into(),
-            Variable::EMPTY_RECORD,
-        ))],
-        vec![],
-        Type::EmptyRec,
-        roc_types::types::AliasKind::Structural,
-    );
+    scope
+        .add_alias(
+            Symbol::NUM_INT,
+            Region::zero(),
+            vec![Loc::at_zero(AliasVar::unbound(
+                "a".into(),
+                Variable::EMPTY_RECORD,
+            ))],
+            vec![],
+            Type::EmptyRec,
+            roc_types::types::AliasKind::Structural,
+        )
+        .unwrap();
 
     let (loc_expr, _) = canonicalize_expr(
         &mut env,
@@ -99,6 +113,7 @@ pub fn can_expr_with(arena: &Bump, home: ModuleId, expr_str: &str) -> CanExprOut
     CanExprOut {
         loc_expr,
         problems: env.problems,
+        warnings: env.warnings,
         interns,
     }
 }
@@ -12,16 +12,25 @@ mod helpers;
 
 #[cfg(test)]
 mod test_can {
-    use crate::helpers::{can_expr_with, test_home, CanExprOut};
+    use crate::helpers::{can_expr_with, can_expr_with_shadow_policy, test_home, CanExprOut};
     use bumpalo::Bump;
     use core::panic;
+    use roc_can::def::{recanonicalize_top_level_def, Def, DefKind};
+    use roc_can::env::{Env, FxMode};
     use roc_can::expr::Expr::{self, *};
-    use roc_can::expr::{ClosureData, IntValue, Recursive, WhenBranch};
+    use roc_can::expr::{ClosureData, ExpectLookup, IntValue, Recursive, WhenBranch};
     use roc_can::pattern::Pattern;
-    use roc_module::called_via::CalledVia;
-    use roc_problem::can::{CycleEntry, FloatErrorKind, IntErrorKind, Problem, RuntimeError};
+    use roc_can::scope::{Scope, ShadowPolicy};
+    use roc_collections::SendMap;
+    use roc_module::called_via::{BinOp, CalledVia};
+    use roc_module::ident::Lowercase;
+    use roc_module::symbol::{IdentIds, PackageModuleIds, Symbol};
+    use roc_problem::can::{
+        CycleEntry, FloatErrorKind, IntErrorKind, PrecedenceProblem, Problem, RuntimeError, Warning,
+    };
     use roc_region::all::{Loc, Position, Region};
-    use roc_types::subs::Variable;
+    use roc_types::subs::{VarStore, Variable};
+    use std::path::Path;
     use std::{f64, i64};
 
     fn assert_can_runtime_error(input: &str, expected: RuntimeError) {
@@ -423,6 +432,379 @@ mod test_can {
         )));
     }
 
+    #[test]
+    fn self_referencing_as_alias_is_an_error() {
+        let src = indoc!(
+            r"
+                f : { x : Foo } as Foo
+
+                f
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems.iter().any(|problem| matches!(
+            problem,
+            Problem::RuntimeError(RuntimeError::RecursiveAliasWithoutRecursionVar { .. })
+        )));
+    }
+
+    #[test]
+    fn as_alias_with_undeclared_argument_is_an_error() {
+        let src = indoc!(
+            r"
+                f : [Cons a (ConsList a), Nil] as ConsList
+
+                f
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems.len(), 2);
+        assert!(problems
+            .iter()
+            .any(|problem| matches!(problem, Problem::NestedDatatype { .. })));
+        assert!(problems.iter().any(|problem| matches!(
+            problem,
+            Problem::RuntimeError(RuntimeError::UnboundTypeVariablesInAlias { unbound, .. })
+                if unbound.len() == 1 && unbound[0].as_str() == "a"
+        )));
+    }
+
+    #[test]
+    fn duplicate_alias_type_variable() {
+        let src = indoc!(
+            r"
+                Pair a a : [Pair a a]
+
+                x = 1
+
+                x
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        let duplicates: Vec<_> = problems
+            .iter()
+            .filter(|problem| {
+                matches!(
+                    problem,
+                    Problem::RuntimeError(RuntimeError::DuplicateTypeVariable { .. })
+                )
+            })
+            .collect();
+
+        assert_eq!(duplicates.len(), 1);
+        assert!(matches!(
+            duplicates[0],
+            Problem::RuntimeError(RuntimeError::DuplicateTypeVariable { name, .. })
+                if name.as_str() == "a"
+        ));
+        assert!(!problems
+            .iter()
+            .any(|problem| matches!(problem, Problem::PhantomTypeArgument { .. })));
+    }
+
+    #[test]
+    fn cyclic_alias_reported_in_reference_order() {
+        let src = indoc!(
+            r"
+                A : { x : C }
+                B : { x : A }
+                C : { x : B }
+
+                x = 1
+
+                x
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            problems, interns, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        let cycles: Vec<_> = problems
+            .iter()
+            .filter_map(|problem| match problem {
+                Problem::CyclicAlias(symbol, _, others, _) => Some((symbol, others)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(cycles.len(), 1);
+
+        let (symbol, others) = cycles[0];
+        let others: Vec<_> = others.iter().map(|other| other.as_str(&interns)).collect();
+
+        // A references C, which references B, which references A
+        assert_eq!(symbol.as_str(&interns), "A");
+        assert_eq!(others, ["C", "B"]);
+    }
+
+    #[test]
+    fn record_rest_in_def() {
+        let src = indoc!(
+            r"
+                { x, ..rest } = { x: 1, y: 2 }
+
+                x + rest.y
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems, Vec::new());
+    }
+
+    #[test]
+    fn record_rest_must_be_last() {
+        let src = indoc!(
+            r"
+                when { x: 1, y: 2 } is
+                    { ..rest, x } -> x
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems
+            .iter()
+            .all(|problem| matches!(problem, Problem::RecordRestPatternNotLast { .. })));
+    }
+
+    #[test]
+    fn multiple_record_rest_patterns() {
+        let src = indoc!(
+            r"
+                when { x: 1, y: 2 } is
+                    { x, .., ..rest } -> x
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert!(problems
+            .iter()
+            .any(|problem| matches!(problem, Problem::MultipleRecordRestPattern { .. })));
+    }
+
+    /// The field names reported as duplicated in a record literal, type or pattern of `src`
+    fn duplicate_record_fields(src: &str) -> Vec<Lowercase> {
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        problems
+            .into_iter()
+            .filter_map(|problem| match problem {
+                Problem::DuplicateRecordFieldValue { field_name, .. }
+                | Problem::DuplicateRecordFieldType { field_name, .. }
+                | Problem::DuplicateRecordFieldPattern { field_name, .. } => Some(field_name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn duplicate_field_in_record_literal() {
+        let src = indoc!(
+            r"
+                r = { x: 1, y: 2, x: 3 }
+
+                r
+            "
+        );
+
+        assert_eq!(duplicate_record_fields(src), [Lowercase::from("x")]);
+    }
+
+    #[test]
+    fn duplicate_field_in_record_type() {
+        let src = indoc!(
+            r"
+                f : { x : a, x : a } -> {}
+                f = \_ -> {}
+
+                f
+            "
+        );
+
+        assert_eq!(duplicate_record_fields(src), [Lowercase::from("x")]);
+    }
+
+    #[test]
+    fn duplicate_field_in_record_pattern() {
+        let src = indoc!(
+            r"
+                f = \{ x: a, x: b } -> a
+
+                f
+            "
+        );
+
+        assert_eq!(duplicate_record_fields(src), [Lowercase::from("x")]);
+    }
+
+    #[test]
+    fn distinct_record_fields_are_not_duplicates() {
+        let src = indoc!(
+            r"
+                r = { x: 1, y: 2 }
+
+                f : { x : a, y : a } -> a
+                f = \{ x, y: _ } -> x
+
+                f r
+            "
+        );
+
+        assert_eq!(duplicate_record_fields(src), Vec::<Lowercase>::new());
+    }
+
+    /// The names reported as bound twice within a single pattern of `src`
+    fn duplicate_pattern_bindings(src: &str) -> Vec<String> {
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert!(
+            !problems.iter().any(|problem| matches!(
+                problem,
+                Problem::RuntimeError(RuntimeError::Shadowing { .. })
+            )),
+            "{problems:?}"
+        );
+
+        problems
+            .into_iter()
+            .filter_map(|problem| match problem {
+                Problem::RuntimeError(RuntimeError::DuplicatePatternBinding { name, .. }) => {
+                    Some(name.as_str().to_string())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tuple_pattern_binds_name_twice() {
+        let names = duplicate_pattern_bindings(indoc!(
+            r"
+            when (1, 2) is
+                (x, x) -> x
+            "
+        ));
+
+        assert_eq!(names, ["x"]);
+    }
+
+    #[test]
+    fn record_pattern_binds_name_twice() {
+        let names = duplicate_pattern_bindings(indoc!(
+            r"
+            when { a: 1, b: 2 } is
+                { a: x, b: x } -> x
+            "
+        ));
+
+        assert_eq!(names, ["x"]);
+    }
+
+    #[test]
+    fn duplicate_record_field_is_not_also_a_duplicate_binding() {
+        let src = indoc!(
+            r"
+            when { x: 1 } is
+                { x, x } -> x
+            "
+        );
+
+        assert_eq!(duplicate_record_fields(src), [Lowercase::from("x")]);
+        assert_eq!(duplicate_pattern_bindings(src), Vec::<String>::new());
+    }
+
+    /// The condition and lookups of the first `expect` in `expr`, looking into defs and closures.
+    fn find_expect(expr: &Expr) -> Option<(&Loc<Expr>, &[ExpectLookup])> {
+        match expr {
+            Expect {
+                loc_condition,
+                lookups_in_cond,
+                ..
+            } => Some((loc_condition, lookups_in_cond)),
+            LetNonRec(def, continuation) => {
+                find_expect(&def.loc_expr.value).or_else(|| find_expect(&continuation.value))
+            }
+            LetRec(defs, continuation, _) => defs
+                .iter()
+                .find_map(|def| find_expect(&def.loc_expr.value))
+                .or_else(|| find_expect(&continuation.value)),
+            Closure(closure) => find_expect(&closure.loc_body.value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expect_condition_region() {
+        let src = indoc!(
+            r"
+                expect 1 + 1 == 2
+
+                {}
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr, problems, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert!(problems.is_empty(), "{problems:?}");
+
+        let (loc_condition, _) = find_expect(&loc_expr.value).expect("no expect");
+
+        // the region covers `1 + 1 == 2`
+        assert_eq!(
+            loc_condition.region,
+            Region::new(Position::new(7), Position::new(17))
+        );
+    }
+
+    #[test]
+    fn nested_expect_looks_up_free_variables() {
+        let src = indoc!(
+            r"
+                f = \x ->
+                    expect x > 0
+
+                    x
+
+                f 1
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr,
+            problems,
+            interns,
+            ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert!(problems.is_empty(), "{problems:?}");
+
+        let (_, lookups) = find_expect(&loc_expr.value).expect("no expect");
+        let looked_up: Vec<_> = lookups
+            .iter()
+            .map(|lookup| lookup.symbol.as_str(&interns))
+            .collect();
+
+        assert_eq!(looked_up, ["x"]);
+        assert!(lookups
+            .iter()
+            .all(|lookup| lookup.symbol.module_id() == test_home()));
+    }
+
     #[test]
     fn correct_nested_unannotated_body() {
         let src = indoc!(
@@ -517,55 +899,214 @@ mod test_can {
                     g + 1
 
 
-                f
+                f
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems, Vec::new());
+    }
+
+    #[test]
+    fn annotation_followed_with_unrelated_affectation() {
+        let src = indoc!(
+            r"
+                F : Str
+
+                x = 1
+
+                x
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            problems, warnings, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems, Vec::new());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings
+            .iter()
+            .all(|warning| matches!(warning, Warning::UnusedDef(_, _))));
+    }
+
+    #[test]
+    fn two_annotations_followed_with_unrelated_affectation() {
+        let src = indoc!(
+            r"
+                G : Str
+
+                F : {}
+
+                x = 1
+
+                x
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            problems, warnings, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems, Vec::new());
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings
+            .iter()
+            .all(|warning| matches!(warning, Warning::UnusedDef(_, _))));
+    }
+
+    #[test]
+    fn unused_closure_argument() {
+        let src = indoc!(
+            r"
+                f = \x, y -> x
+
+                f 1 2
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            problems, interns, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems.iter().all(|problem| matches!(
+            problem,
+            Problem::UnusedArgument(_, _, argument, _) if argument.as_str(&interns) == "y"
+        )));
+    }
+
+    #[test]
+    fn underscore_argument_is_not_reported_as_unused() {
+        let src = indoc!(
+            r"
+                f = \x, _y -> x
+
+                f 1 2
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems, Vec::new());
+    }
+
+    #[test]
+    fn used_underscore_binding() {
+        let src = indoc!(
+            r"
+                \_x -> _x
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            problems, warnings, ..
+        } = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems.iter().all(|problem| matches!(
+            problem,
+            Problem::RuntimeError(RuntimeError::MalformedIdentifier(
+                _,
+                roc_parse::ident::BadIdent::UnderscoreAtStart {
+                    declaration_region: Some(declaration_region),
+                    ..
+                },
+                region,
+            )) if declaration_region.start().offset == 1 && region.start().offset == 7
+        )));
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings.iter().all(|warning| matches!(
+            warning,
+            Warning::UsedUnderscoreBinding {
+                name,
+                region,
+                declaration_region,
+            } if name.as_str() == "x"
+                && declaration_region.start().offset == 1
+                && region.start().offset == 7
+        )));
+    }
+
+    #[test]
+    fn unused_shadowing_argument() {
+        let src = indoc!(
+            r"
+                i = 1
+
+                s = \i -> 5
+
+                s i
             "
         );
         let arena = Bump::new();
         let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
 
-        assert_eq!(problems, Vec::new());
+        assert_eq!(problems.len(), 1);
+        assert!(problems.iter().all(|problem| matches!(
+            problem,
+            Problem::RuntimeError(RuntimeError::Shadowing { .. })
+        )));
     }
 
     #[test]
-    fn annotation_followed_with_unrelated_affectation() {
+    fn shadowing_is_a_warning_when_the_policy_allows_it() {
         let src = indoc!(
             r"
-                F : Str
-
                 x = 1
+                f = \x -> x
 
-                x
+                f x
             "
         );
         let arena = Bump::new();
-        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+        let CanExprOut {
+            problems, warnings, ..
+        } = can_expr_with_shadow_policy(&arena, test_home(), src, ShadowPolicy::WarnAndAllow);
 
-        assert_eq!(problems.len(), 1);
-        assert!(problems
-            .iter()
-            .all(|problem| matches!(problem, Problem::UnusedDef(_, _))));
+        assert_eq!(problems, Vec::new());
+        assert_eq!(
+            warnings,
+            vec![Warning::Shadowing {
+                original_region: Region::new(Position::new(0), Position::new(1)),
+                shadow: Loc::at(
+                    Region::new(Position::new(11), Position::new(12)),
+                    "x".into()
+                ),
+            }]
+        );
     }
 
     #[test]
-    fn two_annotations_followed_with_unrelated_affectation() {
+    fn binding_twice_is_an_error_when_the_policy_allows_shadowing() {
         let src = indoc!(
             r"
-                G : Str
-
-                F : {}
-
                 x = 1
+                f = \(x, x) -> x
 
-                x
+                f (1, 2)
             "
         );
         let arena = Bump::new();
-        let CanExprOut { problems, .. } = can_expr_with(&arena, test_home(), src);
+        let CanExprOut {
+            problems, warnings, ..
+        } = can_expr_with_shadow_policy(&arena, test_home(), src, ShadowPolicy::WarnAndAllow);
 
-        assert_eq!(problems.len(), 2);
-        assert!(problems
+        match problems.as_slice() {
+            [Problem::RuntimeError(RuntimeError::DuplicatePatternBinding { name, .. })] => {
+                assert_eq!(name.as_str(), "x");
+            }
+            problems => panic!("Expected a single duplicate binding, got {problems:?}"),
+        }
+
+        // only the first `x` shadows the outer one
+        let shadowing = warnings
             .iter()
-            .all(|problem| matches!(problem, Problem::UnusedDef(_, _))));
+            .filter(|warning| matches!(warning, Warning::Shadowing { .. }))
+            .count();
+        assert_eq!(shadowing, 1);
     }
     // LOCALS
 
@@ -1064,6 +1605,309 @@ mod test_can {
         assert!(&branches[0].guard.is_none());
     }
 
+    #[test]
+    fn pipe_is_left_associative() {
+        let src = indoc!(
+            r#"
+                1 |> Num.neg |> Num.abs |> Num.toStr
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        // Assert that we desugar to:
+        //
+        // Num.toStr (Num.abs (Num.neg 1))
+
+        let pizza = CalledVia::BinOp(BinOp::Pizza);
+
+        let to_str_args = assert_func_call(&out.loc_expr.value, "toStr", pizza, &out.interns);
+        assert_eq!(to_str_args.len(), 1);
+
+        let abs_args = assert_func_call(&to_str_args[0].1.value, "abs", pizza, &out.interns);
+        assert_eq!(abs_args.len(), 1);
+
+        let neg_args = assert_func_call(&abs_args[0].1.value, "neg", pizza, &out.interns);
+        assert_eq!(neg_args.len(), 1);
+
+        assert_num_value(&neg_args[0].1.value, 1);
+    }
+
+    #[test]
+    fn pipe_into_partially_applied_function() {
+        let src = indoc!(
+            r#"
+                1 |> Num.sub 2
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        // Assert that we desugar to:
+        //
+        // Num.sub 1 2
+
+        let pizza = CalledVia::BinOp(BinOp::Pizza);
+        let sub_args = assert_func_call(&out.loc_expr.value, "sub", pizza, &out.interns);
+
+        assert_eq!(sub_args.len(), 2);
+        assert_num_value(&sub_args[0].1.value, 1);
+        assert_num_value(&sub_args[1].1.value, 2);
+    }
+
+    #[test]
+    fn pipe_has_lower_precedence_than_arithmetic() {
+        let src = indoc!(
+            r#"
+                1 + 2 |> Num.neg
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_eq!(out.problems, Vec::new());
+
+        // Assert that we desugar to:
+        //
+        // Num.neg (Num.add 1 2)
+
+        let pizza = CalledVia::BinOp(BinOp::Pizza);
+        let neg_args = assert_func_call(&out.loc_expr.value, "neg", pizza, &out.interns);
+        assert_eq!(neg_args.len(), 1);
+
+        let plus = CalledVia::BinOp(BinOp::Plus);
+        let add_args = assert_func_call(&neg_args[0].1.value, "add", plus, &out.interns);
+
+        assert_eq!(add_args.len(), 2);
+        assert_num_value(&add_args[0].1.value, 1);
+        assert_num_value(&add_args[1].1.value, 2);
+    }
+
+    #[test]
+    fn same_precedence_operators_associate_left() {
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), "1 + 2 + 3");
+
+        assert_eq!(out.problems, Vec::new());
+
+        // Assert that we desugar to:
+        //
+        // Num.add (Num.add 1 2) 3
+
+        let plus = CalledVia::BinOp(BinOp::Plus);
+        let outer_args = assert_func_call(&out.loc_expr.value, "add", plus, &out.interns);
+        assert_eq!(outer_args.len(), 2);
+        assert_num_value(&outer_args[1].1.value, 3);
+
+        let inner_args = assert_func_call(&outer_args[0].1.value, "add", plus, &out.interns);
+        assert_num_value(&inner_args[0].1.value, 1);
+        assert_num_value(&inner_args[1].1.value, 2);
+    }
+
+    #[test]
+    fn higher_precedence_operator_binds_tighter() {
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), "1 + 2 * 3");
+
+        assert_eq!(out.problems, Vec::new());
+
+        // Assert that we desugar to:
+        //
+        // Num.add 1 (Num.mul 2 3)
+
+        let add_args = assert_func_call(
+            &out.loc_expr.value,
+            "add",
+            CalledVia::BinOp(BinOp::Plus),
+            &out.interns,
+        );
+        assert_eq!(add_args.len(), 2);
+        assert_num_value(&add_args[0].1.value, 1);
+
+        let mul_args = assert_func_call(
+            &add_args[1].1.value,
+            "mul",
+            CalledVia::BinOp(BinOp::Star),
+            &out.interns,
+        );
+        assert_num_value(&mul_args[0].1.value, 2);
+        assert_num_value(&mul_args[1].1.value, 3);
+    }
+
+    #[test]
+    fn comparison_chain_is_a_precedence_problem() {
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), "1 < 2 < 3");
+
+        let first = Loc::at(
+            Region::new(Position::new(2), Position::new(3)),
+            BinOp::LessThan,
+        );
+        let second = Loc::at(
+            Region::new(Position::new(6), Position::new(7)),
+            BinOp::LessThan,
+        );
+
+        match out.problems.as_slice() {
+            [Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(_, op1, op2))] => {
+                assert_eq!((op1, op2), (&first, &second));
+            }
+            problems => panic!("Expected a single precedence problem, got {problems:?}"),
+        }
+
+        assert!(matches!(
+            out.loc_expr.value,
+            Expr::RuntimeError(RuntimeError::InvalidPrecedence(..))
+        ));
+    }
+
+    #[test]
+    fn range_chain_is_a_precedence_problem() {
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), "a..b..c");
+
+        let first = Loc::at(
+            Region::new(Position::new(1), Position::new(3)),
+            BinOp::DoubleDot,
+        );
+        let second = Loc::at(
+            Region::new(Position::new(4), Position::new(6)),
+            BinOp::DoubleDot,
+        );
+
+        match out.problems.as_slice() {
+            [Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(_, op1, op2))] => {
+                assert_eq!((op1, op2), (&first, &second));
+            }
+            problems => panic!("Expected a single precedence problem, got {problems:?}"),
+        }
+
+        assert!(matches!(
+            out.loc_expr.value,
+            Expr::RuntimeError(RuntimeError::InvalidPrecedence(..))
+        ));
+    }
+
+    #[test]
+    fn backpassing_desugars_to_trailing_closure() {
+        let src = indoc!(
+            r#"
+                x <- List.map [1]
+
+                x
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_only_deprecated_backpassing(&out.problems);
+
+        // Assert that we desugar to:
+        //
+        // List.map [1] (\x -> x)
+
+        let map_args = assert_func_call(&out.loc_expr.value, "map", CalledVia::Space, &out.interns);
+        assert_eq!(map_args.len(), 2);
+
+        let closure = assert_closure(&map_args[1].1.value);
+        assert_eq!(closure.arguments.len(), 1);
+        assert_pattern_name(&closure.arguments[0].2.value, "x", &out.interns);
+        assert_var_usage(&closure.loc_body.value, "x", &out.interns);
+    }
+
+    #[test]
+    fn chained_backpassing_nests_closures() {
+        let src = indoc!(
+            r#"
+                x <- List.map [1]
+                y <- List.map [2]
+
+                x + y
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_only_deprecated_backpassing(&out.problems);
+
+        // Assert that we desugar to:
+        //
+        // List.map [1] (\x -> List.map [2] (\y -> x + y))
+
+        let outer_args =
+            assert_func_call(&out.loc_expr.value, "map", CalledVia::Space, &out.interns);
+        assert_eq!(outer_args.len(), 2);
+
+        let outer_closure = assert_closure(&outer_args[1].1.value);
+        assert_pattern_name(&outer_closure.arguments[0].2.value, "x", &out.interns);
+
+        let inner_args = assert_func_call(
+            &outer_closure.loc_body.value,
+            "map",
+            CalledVia::Space,
+            &out.interns,
+        );
+        assert_eq!(inner_args.len(), 2);
+
+        let inner_closure = assert_closure(&inner_args[1].1.value);
+        assert_pattern_name(&inner_closure.arguments[0].2.value, "y", &out.interns);
+
+        let add_args = assert_func_call(
+            &inner_closure.loc_body.value,
+            "add",
+            CalledVia::BinOp(BinOp::Plus),
+            &out.interns,
+        );
+        assert_var_usage(&add_args[0].1.value, "x", &out.interns);
+        assert_var_usage(&add_args[1].1.value, "y", &out.interns);
+    }
+
+    #[test]
+    fn backpassing_into_record_destructure() {
+        let src = indoc!(
+            r#"
+                { a, b } <- List.map [{ a: 1, b: 2 }]
+
+                a + b
+            "#
+        );
+        let arena = Bump::new();
+        let out = can_expr_with(&arena, test_home(), src);
+
+        assert_only_deprecated_backpassing(&out.problems);
+
+        // Assert that we desugar to:
+        //
+        // List.map [{ a: 1, b: 2 }] (\{ a, b } -> a + b)
+
+        let map_args = assert_func_call(&out.loc_expr.value, "map", CalledVia::Space, &out.interns);
+        assert_eq!(map_args.len(), 2);
+
+        let closure = assert_closure(&map_args[1].1.value);
+        assert_eq!(closure.arguments.len(), 1);
+
+        match &closure.arguments[0].2.value {
+            Pattern::RecordDestructure { destructs, .. } => {
+                let labels: Vec<_> = destructs.iter().map(|d| d.value.label.as_str()).collect();
+                assert_eq!(labels, ["a", "b"]);
+            }
+            other => panic!("Backpassed pattern was not a record destructure: {other:?}"),
+        }
+
+        let add_args = assert_func_call(
+            &closure.loc_body.value,
+            "add",
+            CalledVia::BinOp(BinOp::Plus),
+            &out.interns,
+        );
+        assert_var_usage(&add_args[0].1.value, "a", &out.interns);
+        assert_var_usage(&add_args[1].1.value, "b", &out.interns);
+    }
+
     fn assert_num_value(expr: &Expr, num: usize) {
         match expr {
             Expr::Num(_, num_str, _, _) => {
@@ -1114,6 +1958,22 @@ mod test_can {
         }
     }
 
+    fn assert_closure(expr: &Expr) -> &ClosureData {
+        match expr {
+            Expr::Closure(closure_data) => closure_data,
+            _ => panic!("Expr was not a closure: {:?}", expr),
+        }
+    }
+
+    fn assert_only_deprecated_backpassing(problems: &[Problem]) {
+        for problem in problems {
+            assert!(
+                matches!(problem, Problem::DeprecatedBackpassing(_)),
+                "Unexpected problem: {problem:?}"
+            );
+        }
+    }
+
     fn assert_tag_application(expr: &Expr, tag_name: &str) -> Vec<(Variable, Loc<Expr>)> {
         match expr {
             Expr::LetNonRec(_, loc_expr) => assert_tag_application(&loc_expr.value, tag_name),
@@ -1273,9 +2133,6 @@ mod test_can {
         } = can_expr_with(&arena, test_home(), src);
 
         assert_eq!(problems, Vec::new());
-        assert!(problems
-            .iter()
-            .all(|problem| matches!(problem, Problem::UnusedDef(_, _))));
 
         let actual = loc_expr.value;
 
@@ -1433,6 +2290,144 @@ mod test_can {
         assert_eq!(detected, Recursive::Recursive);
     }
 
+    #[test]
+    fn mutually_recursive_defs_are_grouped() {
+        let src = indoc!(
+            r"
+                isEven = \n ->
+                    when n is
+                        0 -> Yes
+                        _ -> isOdd n
+
+                isOdd = \n ->
+                    when n is
+                        0 -> No
+                        _ -> isEven n
+
+                answer = isEven 42
+
+                answer
+            "
+        );
+        let arena = Bump::new();
+        let CanExprOut {
+            loc_expr,
+            problems,
+            interns,
+            ..
+        } = can_expr_with(&arena, test_home(), src);
+        assert_eq!(problems, Vec::new());
+
+        // the defs come out in dependency order, as one group per `LetRec` or `LetNonRec`
+        let mut groups = Vec::new();
+        let mut expr = &loc_expr.value;
+
+        loop {
+            match expr {
+                LetRec(defs, body, _) => {
+                    let mut names: Vec<_> =
+                        defs.iter().map(|def| def_name(def, &interns)).collect();
+                    names.sort();
+
+                    groups.push(names);
+                    expr = &body.value;
+                }
+                LetNonRec(def, body) => {
+                    groups.push(vec![def_name(def, &interns)]);
+                    expr = &body.value;
+                }
+                _ => break,
+            }
+        }
+
+        assert_eq!(groups, vec![vec!["isEven", "isOdd"], vec!["answer"]]);
+    }
+
+    fn def_name(def: &Def, interns: &roc_module::symbol::Interns) -> String {
+        match def.loc_pattern.value {
+            Pattern::Identifier(symbol) => symbol.as_str(interns).to_string(),
+            ref other => panic!("expected an identifier pattern, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn recanonicalize_def_with_changed_body() {
+        // `b = a` is edited to `b = [a]`
+        let src = "b = [a]\n";
+        let arena = Bump::new();
+        let home = test_home();
+        let dep_idents = IdentIds::exposed_builtins(0);
+        let qualified_module_ids = PackageModuleIds::default();
+        let mut env = Env::new(
+            &arena,
+            src,
+            home,
+            Path::new("Test.roc"),
+            &dep_idents,
+            &qualified_module_ids,
+            None,
+            FxMode::PurityInference,
+        );
+        let mut var_store = VarStore::default();
+        let mut scope = Scope::new(
+            home,
+            "TestPath".into(),
+            IdentIds::default(),
+            Default::default(),
+        );
+
+        // the top-level defs `a`, `b` and `c` of the module are in scope
+        let symbols: Vec<Symbol> = ["a", "b", "c"]
+            .into_iter()
+            .map(|name| scope.introduce(name.into(), Region::zero()).unwrap())
+            .collect();
+        let snapshot = scope.snapshot();
+
+        let previous = Def {
+            loc_pattern: Loc::at_zero(Pattern::Identifier(symbols[1])),
+            loc_expr: Loc::at_zero(Var(symbols[0], var_store.fresh())),
+            expr_var: var_store.fresh(),
+            pattern_vars: SendMap::default(),
+            annotation: None,
+            kind: DefKind::Let,
+        };
+        let changed = roc_parse::test_helpers::parse_defs_with(&arena, src).unwrap();
+        let changed_def = arena.alloc(changed.value_defs[0]);
+
+        let recanonicalized = recanonicalize_top_level_def(
+            &mut env,
+            &mut var_store,
+            &mut scope,
+            snapshot,
+            &previous,
+            changed_def,
+        )
+        .unwrap();
+
+        assert!(env.problems.is_empty(), "{:?}", env.problems);
+        assert!(recanonicalized.symbols_introduced.is_empty());
+        assert!(matches!(
+            recanonicalized.def.loc_pattern.value,
+            Pattern::Identifier(symbol) if symbol == symbols[1]
+        ));
+
+        match &recanonicalized.def.loc_expr.value {
+            List { loc_elems, .. } => {
+                assert!(matches!(
+                    loc_elems.as_slice(),
+                    [Loc { value: Var(symbol, _), .. }] if *symbol == symbols[0]
+                ));
+            }
+            other => panic!("expected a list, got {other:?}"),
+        }
+
+        // the other defs are still in scope with their original symbols
+        for (name, symbol) in ["a", "b", "c"].into_iter().zip(symbols) {
+            let lookup = scope.resolve_str(name, Region::zero()).unwrap();
+            assert_eq!(lookup.symbol, symbol);
+        }
+    }
+
     #[test]
     fn valid_self_recursion() {
         let src = indoc!(
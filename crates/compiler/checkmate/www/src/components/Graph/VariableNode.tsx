@@ -251,6 +251,7 @@ function VariableNodeContent(
       return {};
     }
     case "EmptyRecord":
+    case "EmptyTuple":
     case "EmptyTagUnion":
     case "Error": {
       return {};
@@ -148,6 +148,10 @@ export type Content =
       type: "EmptyRecord";
       [k: string]: unknown;
     }
+  | {
+      type: "EmptyTuple";
+      [k: string]: unknown;
+    }
   | {
       type: "EmptyTagUnion";
       [k: string]: unknown;
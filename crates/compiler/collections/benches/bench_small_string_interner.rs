@@ -0,0 +1,98 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use roc_collections::SmallStringInterner;
+
+/// Field names that show up over and over in the records of a typical module
+const FIELD_NAMES: [&str; 8] = [
+    "id",
+    "name",
+    "email",
+    "createdAt",
+    "updatedAt",
+    "status",
+    "owner",
+    "tags",
+];
+
+/// Numbers of records in the module
+const SIZES: [usize; 4] = [10, 100, 1_000, 10_000];
+
+/// The identifiers met while canonicalizing `count` records that all use the same fields,
+/// plus a unique binding for every record
+fn module_idents(count: usize) -> Vec<String> {
+    let mut idents = Vec::with_capacity(count * (FIELD_NAMES.len() + 1));
+
+    for index in 0..count {
+        idents.push(format!("record{index}"));
+        idents.extend(FIELD_NAMES.iter().map(|name| name.to_string()));
+    }
+
+    idents
+}
+
+pub fn intern_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("intern the identifiers of a module");
+
+    for size in SIZES {
+        let idents = module_idents(size);
+
+        group.bench_with_input(BenchmarkId::new("intern", size), &idents, |b, idents| {
+            b.iter(|| {
+                let mut interner = SmallStringInterner::default();
+
+                for ident in idents {
+                    black_box(interner.intern(ident));
+                }
+
+                black_box(interner.len())
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("insert", size), &idents, |b, idents| {
+            b.iter(|| {
+                let mut interner = SmallStringInterner::default();
+
+                for ident in idents {
+                    black_box(interner.insert(ident));
+                }
+
+                black_box(interner.len())
+            })
+        });
+    }
+
+    group.finish();
+}
+
+pub fn resolve_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compare interned field names");
+
+    for size in SIZES {
+        let idents = module_idents(size);
+
+        let mut interner = SmallStringInterner::default();
+        let interned: Vec<usize> = idents.iter().map(|ident| interner.intern(ident)).collect();
+        let name = interner.intern("name");
+
+        group.bench_with_input(BenchmarkId::new("index", size), &interned, |b, interned| {
+            b.iter(|| interned.iter().filter(|index| **index == name).count())
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("resolve", size),
+            &interned,
+            |b, interned| {
+                b.iter(|| {
+                    interned
+                        .iter()
+                        .filter(|index| interner.resolve(**index) == "name")
+                        .count()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, intern_benchmark, resolve_benchmark);
+criterion_main!(benches);
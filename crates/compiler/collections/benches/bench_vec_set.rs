@@ -0,0 +1,83 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use roc_collections::{MutSet, VecSet};
+
+/// Sizes that are typical for the sets the compiler keeps per scope or per definition
+const SIZES: [usize; 6] = [1, 2, 4, 8, 16, 32];
+
+pub fn insert_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert then check every element");
+
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::new("VecSet", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut set = VecSet::default();
+
+                for value in 0..size as u64 {
+                    set.insert(value);
+                }
+
+                for value in 0..size as u64 {
+                    black_box(set.contains(&value));
+                }
+
+                black_box(set.len())
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("MutSet", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut set = MutSet::default();
+
+                for value in 0..size as u64 {
+                    set.insert(value);
+                }
+
+                for value in 0..size as u64 {
+                    black_box(set.contains(&value));
+                }
+
+                black_box(set.len())
+            })
+        });
+    }
+
+    group.finish();
+}
+
+pub fn remove_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("remove every other element");
+
+    for size in SIZES {
+        let vec_set: VecSet<u64> = (0..size as u64).collect();
+        let mut_set: MutSet<u64> = (0..size as u64).collect();
+
+        group.bench_with_input(BenchmarkId::new("VecSet", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut set = vec_set.clone();
+
+                for value in (0..size as u64).step_by(2) {
+                    black_box(set.remove(&value));
+                }
+
+                black_box(set.len())
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("MutSet", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut set = mut_set.clone();
+
+                for value in (0..size as u64).step_by(2) {
+                    black_box(set.remove(&value));
+                }
+
+                black_box(set.len())
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, insert_benchmark, remove_benchmark);
+criterion_main!(benches);
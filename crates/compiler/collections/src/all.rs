@@ -149,6 +149,16 @@ where
     map
 }
 
+/// Returns the value for `key`, first inserting the result of `f` if the key is missing.
+/// The key is hashed only once, which makes this a good fit for memoization caches.
+pub fn get_or_insert_with<K, V, F>(map: &mut MutMap<K, V>, key: K, f: F) -> &mut V
+where
+    K: Eq + Hash,
+    F: FnOnce() -> V,
+{
+    map.entry(key).or_insert_with(f)
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct HumanIndex(usize);
 
@@ -207,3 +217,25 @@ macro_rules! mut_map {
         }
     };
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_or_insert_with_only_computes_missing_values() {
+        let mut map: MutMap<&str, u32> = MutMap::default();
+        let mut calls = 0;
+
+        for _ in 0..3 {
+            let value = get_or_insert_with(&mut map, "key", || {
+                calls += 1;
+                41
+            });
+            *value += 1;
+        }
+
+        assert_eq!(calls, 1);
+        assert_eq!(map.get("key"), Some(&44));
+    }
+}
@@ -17,5 +17,5 @@ pub use push::Push;
 pub use reference_matrix::{ReferenceMatrix, Sccs, TopologicalSort};
 pub use small_string_interner::SmallStringInterner;
 pub use small_vec::SmallVec;
-pub use vec_map::VecMap;
+pub use vec_map::{Entry as VecMapEntry, VecMap};
 pub use vec_set::VecSet;
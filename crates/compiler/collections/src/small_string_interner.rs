@@ -130,6 +130,23 @@ impl SmallStringInterner {
         index
     }
 
+    /// Insert with deduplicating: if an equal string was interned before, its index is
+    /// returned and no new entry is created. Generated names (see `insert_index_str`)
+    /// are never found, so interning `"3"` does not alias a generated variable name.
+    pub fn intern(&mut self, string: &str) -> usize {
+        match self.find_index(string) {
+            Some(index) => index,
+            None => self.insert(string),
+        }
+    }
+
+    /// Get back the string of an index returned by `insert`, `intern` or `duplicate`.
+    ///
+    /// Panics when the index is out of bounds; use `try_get` if that can happen.
+    pub fn resolve(&self, index: usize) -> &str {
+        self.get(index)
+    }
+
     /// Create a new entry that uses the same string bytes as an existing entry
     pub fn duplicate(&mut self, existing: usize) -> usize {
         let offset = self.offsets[existing];
@@ -353,6 +370,51 @@ mod test {
         assert!(interner.find_and_update("c", "cd").is_some());
     }
 
+    #[test]
+    fn intern_deduplicates() {
+        let mut interner = SmallStringInterner::default();
+
+        let first = interner.intern("name");
+        let other = interner.intern("age");
+        let second = interner.intern("name");
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(interner.len(), 2);
+
+        // plain insert does not deduplicate, but intern finds the first entry
+        let inserted = interner.insert("age");
+        assert_ne!(inserted, other);
+        assert_eq!(interner.intern("age"), other);
+    }
+
+    #[test]
+    fn intern_resolve_round_trip() {
+        let mut interner = SmallStringInterner::default();
+
+        let strings = ["", "x", "Járnak", "bögre", "name", "x", "", "Járnak"];
+        let indices: Vec<_> = strings.iter().map(|s| interner.intern(s)).collect();
+
+        for (string, index) in strings.iter().zip(indices) {
+            assert_eq!(interner.resolve(index), *string);
+        }
+
+        assert_eq!(interner.len(), 5);
+    }
+
+    #[test]
+    fn intern_does_not_return_generated_names() {
+        let mut interner = SmallStringInterner::default();
+
+        interner.insert("a");
+        let generated = interner.insert_index_str();
+        assert_eq!(interner.resolve(generated), "1");
+
+        let interned = interner.intern("1");
+        assert_ne!(interned, generated);
+        assert_eq!(interner.resolve(interned), "1");
+    }
+
     #[cfg(all(
         target_arch = "x86_64",
         target_feature = "avx",
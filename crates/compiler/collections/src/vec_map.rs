@@ -105,6 +105,17 @@ impl<K: PartialEq, V> VecMap<K, V> {
         }
     }
 
+    /// Get the entry for `key`, to inspect or modify it in place, or to insert it if it is absent.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.keys.iter().position(|x| x == &key) {
+            Some(index) => Entry::Occupied(OccupiedEntry {
+                vec_map: self,
+                index,
+            }),
+            None => Entry::Vacant(VacantEntry { vec_map: self, key }),
+        }
+    }
+
     pub fn iter(&self) -> impl ExactSizeIterator<Item = (&K, &V)> {
         self.keys.iter().zip(self.values.iter())
     }
@@ -145,6 +156,28 @@ impl<K: PartialEq, V> VecMap<K, V> {
         }
     }
 
+    /// Keep only the key/value pairs for which `predicate` returns `true`. Unlike `remove`, this
+    /// preserves the order of the remaining pairs.
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut kept = 0;
+
+        for index in 0..self.keys.len() {
+            if predicate(&self.keys[index], &mut self.values[index]) {
+                // everything in kept..index was dropped, so this moves the pair into the first
+                // free slot without reordering the pairs we keep
+                self.keys.swap(kept, index);
+                self.values.swap(kept, index);
+
+                kept += 1;
+            }
+        }
+
+        self.truncate(kept);
+    }
+
     /// Removes all key/value pairs from the map, without affecting its allocated capacity.
     pub fn clear(&mut self) {
         self.keys.clear();
@@ -223,6 +256,103 @@ impl<K: PartialEq, V> std::iter::FromIterator<(K, V)> for VecMap<K, V> {
     }
 }
 
+/// A view into a single entry of a [VecMap], see [VecMap::entry].
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn and_modify(self, f: impl FnOnce(&mut V)) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+}
+
+/// An entry of a [VecMap] whose key is present. Knows the index of the key, so further
+/// operations don't need to scan the keys again.
+pub struct OccupiedEntry<'a, K, V> {
+    vec_map: &'a mut VecMap<K, V>,
+    index: usize,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn key(&self) -> &K {
+        &self.vec_map.keys[self.index]
+    }
+
+    pub fn get(&self) -> &V {
+        &self.vec_map.values[self.index]
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.vec_map.values[self.index]
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.vec_map.values[self.index]
+    }
+
+    /// Replace the value of this entry, returning the old value
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+}
+
+/// An entry of a [VecMap] whose key is absent.
+pub struct VacantEntry<'a, K, V> {
+    vec_map: &'a mut VecMap<K, V>,
+    key: K,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.vec_map.keys.push(self.key);
+        self.vec_map.values.push(value);
+
+        self.vec_map.values.last_mut().unwrap()
+    }
+}
+
 pub struct DrainFilter<'a, K, V, F>
 where
     F: Fn(&K, &V) -> bool,
@@ -314,3 +444,118 @@ mod test_drain_filter {
         assert_eq!(rest, vec![(1, 2), (3, 6), (5, 10)]);
     }
 }
+
+#[cfg(test)]
+mod test_retain {
+    use crate::VecMap;
+
+    #[test]
+    fn retain_preserves_order() {
+        let mut map = VecMap::default();
+        map.extend(vec![
+            (1, 'a'),
+            (2, 'b'),
+            (3, 'c'),
+            (4, 'd'),
+            (5, 'e'),
+            (6, 'f'),
+        ]);
+
+        map.retain(|k, _| k % 3 != 0);
+
+        let rest: Vec<_> = map.into_iter().collect();
+        assert_eq!(rest, vec![(1, 'a'), (2, 'b'), (4, 'd'), (5, 'e')]);
+    }
+
+    #[test]
+    fn retain_can_modify_values() {
+        let mut map = VecMap::default();
+        map.extend(vec![(1, 10), (2, 20), (3, 30)]);
+
+        map.retain(|k, v| {
+            *v += 1;
+            *k != 2
+        });
+
+        let rest: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(rest, vec![(1, 11), (3, 31)]);
+    }
+
+    #[test]
+    fn iter_mut_modifies_in_place() {
+        let mut map = VecMap::default();
+        map.extend(vec![(1, 10), (2, 20)]);
+
+        for (k, v) in map.iter_mut() {
+            *v += k;
+        }
+
+        assert_eq!(map.get(&1), Some(&11));
+        assert_eq!(map.get(&2), Some(&22));
+    }
+}
+
+#[cfg(test)]
+mod test_entry {
+    use crate::vec_map::Entry;
+    use crate::VecMap;
+
+    #[test]
+    fn or_insert_only_inserts_when_vacant() {
+        let mut map = VecMap::default();
+
+        *map.entry(1).or_insert(10) += 1;
+        *map.entry(1).or_insert(20) += 1;
+
+        assert_eq!(map.get(&1), Some(&12));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn or_insert_with_is_lazy() {
+        let mut map = VecMap::default();
+        map.insert(1, 10);
+
+        let value = map
+            .entry(1)
+            .or_insert_with(|| panic!("the key is already present"));
+
+        assert_eq!(*value, 10);
+    }
+
+    #[test]
+    fn and_modify_only_modifies_when_occupied() {
+        let mut map = VecMap::default();
+
+        map.entry(1).and_modify(|v| *v += 1).or_insert(10);
+        map.entry(1).and_modify(|v| *v += 1).or_insert(10);
+
+        assert_eq!(map.get(&1), Some(&11));
+    }
+
+    #[test]
+    fn occupied_entry_knows_its_index() {
+        let mut map = VecMap::default();
+        map.extend(vec![(1, 2), (2, 4), (3, 6)]);
+
+        match map.entry(2) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.index(), 1);
+                assert_eq!(entry.key(), &2);
+                assert_eq!(entry.insert(5), 4);
+            }
+            Entry::Vacant(_) => panic!("key 2 is present"),
+        }
+
+        match map.entry(4) {
+            Entry::Occupied(_) => panic!("key 4 is absent"),
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), &4);
+                entry.insert(8);
+            }
+        }
+
+        assert_eq!(map.get(&2), Some(&5));
+        assert_eq!(map.get(&4), Some(&8));
+    }
+}
@@ -48,6 +48,10 @@ impl<T: PartialEq> VecSet<T> {
         self.elements.swap_remove(index)
     }
 
+    /// Insert the value unless the set already contains it.
+    ///
+    /// Note that, unlike `HashSet::insert`, this returns `true` when the value *was already*
+    /// present, and `false` when it is new.
     pub fn insert(&mut self, value: T) -> bool {
         if self.elements.contains(&value) {
             true
@@ -86,7 +90,7 @@ impl<T: PartialEq> VecSet<T> {
         }
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
         self.elements.iter()
     }
 
@@ -163,3 +167,47 @@ impl<T> From<Vec<T>> for VecSet<T> {
         Self { elements }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::VecSet;
+
+    #[test]
+    fn insert_reports_existing_elements() {
+        let mut set = VecSet::default();
+
+        assert!(!set.insert(1));
+        assert!(!set.insert(2));
+        assert!(set.insert(1));
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&1));
+        assert!(!set.contains(&3));
+    }
+
+    #[test]
+    fn iteration_follows_insertion_order() {
+        let mut set = VecSet::default();
+
+        for value in [3, 1, 2, 1] {
+            set.insert(value);
+        }
+
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn remove_and_retain() {
+        let mut set = VecSet::default();
+        set.insert_all([1, 2, 3, 4, 5].into_iter());
+
+        assert!(set.remove(&2));
+        assert!(!set.remove(&2));
+
+        set.retain(|value| value % 2 == 1);
+
+        let mut rest = set.into_vec();
+        rest.sort_unstable();
+        assert_eq!(rest, vec![1, 3, 5]);
+    }
+}
@@ -91,7 +91,20 @@ fn headers_from_annotation_help(
         | SingleQuote(..)
         | StrLiteral(_) => true,
 
-        RecordDestructure { destructs, .. } => {
+        RecordDestructure {
+            destructs,
+            opt_rest,
+            ..
+        } => {
+            if let Some((rest_var, rest_sym)) = opt_rest {
+                // The rest gets its own variable, which constraining the
+                // pattern ties to the extension of the record.
+                headers.insert(
+                    rest_sym.value,
+                    Loc::at(annotation.region, constraints.push_variable(*rest_var)),
+                );
+            }
+
             let dealiased = types.shallow_dealias(annotation.value);
             match types[dealiased] {
                 TypeTag::Record(fields) => {
@@ -628,11 +641,42 @@ pub fn constrain_pattern_help(
             whole_var,
             ext_var,
             destructs,
+            opt_rest,
         } => {
             state.vars.push(*whole_var);
             state.vars.push(*ext_var);
             let ext_type = Type::Variable(*ext_var);
 
+            if let Some((rest_var, rest_sym)) = opt_rest {
+                // The rest of `{ x, ..rest }` is a record of exactly the fields
+                // that are not destructured, i.e. the extension of the whole record.
+                let rest_type = {
+                    let typ = types.from_old_type(&Type::Record(
+                        SendMap::default(),
+                        TypeExtension::from_non_annotation_type(ext_type.clone()),
+                    ));
+                    constraints.push_type(types, typ)
+                };
+                let rest_var_index = constraints.push_variable(*rest_var);
+                let expected_rest =
+                    constraints.push_expected_type(Expected::NoExpectation(rest_type));
+
+                state.constraints.push(constraints.equal_types(
+                    rest_var_index,
+                    expected_rest,
+                    Category::Storage(std::file!(), std::line!()),
+                    rest_sym.region,
+                ));
+
+                if !state.headers.contains_key(&rest_sym.value) {
+                    state
+                        .headers
+                        .insert(rest_sym.value, Loc::at(rest_sym.region, rest_var_index));
+                }
+
+                state.vars.push(*rest_var);
+            }
+
             let mut field_types: SendMap<Lowercase, RecordField<Type>> = SendMap::default();
 
             for Loc {
@@ -7,6 +7,7 @@ use crate::expr::{
     expr_lift_and_lower, expr_lift_spaces, expr_lift_spaces_after, expr_lift_spaces_before,
     fmt_str_literal, is_str_multiline, sub_expr_requests_parens,
 };
+use crate::header::fmt_exposed_names;
 use crate::pattern::pattern_fmt_apply;
 use crate::pattern::pattern_lift_spaces_before;
 use crate::spaces::{
@@ -598,7 +599,7 @@ impl<'a> Formattable for ModuleImport<'a> {
 
         if let Some(exposed) = exposed {
             exposed.keyword.format(buf, indent);
-            fmt_collection(buf, indent, Braces::Square, exposed.item, Newlines::No);
+            fmt_exposed_names(buf, indent, Braces::Square, exposed.item);
         }
     }
 }
@@ -695,7 +695,10 @@ fn fmt_apply(
                     a.is_multiline()
                         && (!a.extract_spaces().before.is_empty() || !is_outdentable(&a.value))
                 })
-                .unwrap_or_default());
+                .unwrap_or_default()
+            || exceeds_max_width(buf, indent, 0, |flat| {
+                fmt_apply(loc_expr, loc_args, indent, flat)
+            }));
 
     let arg_indent = if needs_indent {
         indent + INDENT
@@ -756,6 +759,28 @@ fn fmt_apply(
     }
 }
 
+/// Whether the output of `write`, plus `extra` characters, would run past the configured
+/// `max_width` when put on the current line. Output that spans multiple lines anyway never
+/// counts as too wide, since its line breaks already decide its layout.
+pub(crate) fn exceeds_max_width(
+    buf: &Buf<'_>,
+    indent: u16,
+    extra: usize,
+    write: impl FnOnce(&mut Buf<'_>),
+) -> bool {
+    if buf.options().max_width == usize::MAX {
+        return false;
+    }
+
+    // The scratch buffer uses the default options, so it follows the source's line breaks.
+    let mut flat = Buf::new_in(buf.text.bump(), buf.flags());
+    write(&mut flat);
+
+    let text = flat.text.as_str().trim_start_matches(' ');
+
+    !text.contains('\n') && !buf.fits(indent, text.chars().count() + extra)
+}
+
 fn is_outdentable_collection(expr: &Expr<'_>) -> bool {
     match expr {
         Expr::Tuple(items) => is_collection_multiline(items),
@@ -942,6 +967,8 @@ fn push_op(buf: &mut Buf, op: BinOp) {
         called_via::BinOp::Or => buf.push_str("||"),
         called_via::BinOp::Pizza => buf.push_str("|>"),
         called_via::BinOp::DoubleQuestion => buf.push_str("??"),
+        called_via::BinOp::DoubleDot => buf.push_str(".."),
+        called_via::BinOp::DoubleDotEquals => buf.push_str("..="),
     }
 }
 
@@ -1397,7 +1424,10 @@ fn fmt_binops<'a>(
     indent: u16,
 ) {
     let is_multiline = loc_right_side.value.is_multiline()
-        || lefts.iter().any(|(expr, _)| expr.value.is_multiline());
+        || lefts.iter().any(|(expr, _)| expr.value.is_multiline())
+        || exceeds_max_width(buf, indent, 0, |flat| {
+            fmt_binops(flat, lefts, loc_right_side, indent)
+        });
 
     for (loc_left_side, loc_binop) in lefts {
         let binop = loc_binop.value;
@@ -2095,8 +2125,21 @@ fn fmt_record_like<'a, 'b: 'a, Field, ToSpacesAround>(
             }
         }
 
+        // The `{` and any prefix are already written, so laying this out on a single line
+        // adds ` field, field }`.
         let is_multiline = loc_fields.iter().any(|loc_field| loc_field.is_multiline())
-            || !final_comments.is_empty();
+            || !final_comments.is_empty()
+            || exceeds_max_width(buf, indent, " ".len() + " }".len(), |flat| {
+                let mut iter = loc_fields.iter().peekable();
+                while let Some(field) = iter.next() {
+                    field.format_with_options(flat, Parens::NotNeeded, Newlines::No, indent);
+
+                    if iter.peek().is_some() {
+                        flat.push_str(",");
+                        flat.spaces(1);
+                    }
+                }
+            });
 
         if is_multiline {
             let field_indent = indent + INDENT;
@@ -2225,7 +2268,9 @@ pub fn sub_expr_requests_parens(expr: &Expr<'_>) -> bool {
                     | BinOp::And
                     | BinOp::Or
                     | BinOp::Pizza
-                    | BinOp::DoubleQuestion => true,
+                    | BinOp::DoubleQuestion
+                    | BinOp::DoubleDot
+                    | BinOp::DoubleDotEquals => true,
                 })
         }
         Expr::If { .. } => true,
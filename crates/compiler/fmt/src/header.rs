@@ -373,6 +373,34 @@ fn fmt_to(buf: &mut Buf, to: To, indent: u16) {
     }
 }
 
+/// Formats the names an import exposes, sorted if [crate::FormatOptions::sort_exposing] is set.
+pub fn fmt_exposed_names<'a>(
+    buf: &mut Buf,
+    indent: u16,
+    braces: Braces,
+    names: Collection<'a, Loc<Spaced<'a, ExposedName<'a>>>>,
+) {
+    let mut sorted = Vec::new();
+
+    let names = if buf.options().sort_exposing {
+        sorted.extend_from_slice(names.items);
+        sorted.sort_by(|a, b| exposed_name_order(a).cmp(&exposed_name_order(b)));
+
+        names.replace_items(&sorted)
+    } else {
+        names
+    };
+
+    fmt_collection(buf, indent, braces, names, Newlines::No)
+}
+
+/// Types come before values, and each alphabetically
+fn exposed_name_order<'a>(name: &Loc<Spaced<'a, ExposedName<'a>>>) -> (bool, &'a str) {
+    let name: &'a str = (*name.value.item()).into();
+
+    (!name.starts_with(char::is_uppercase), name)
+}
+
 fn fmt_exposes<N: Formattable + Copy + core::fmt::Debug>(
     buf: &mut Buf,
     loc_entries: Collection<'_, Loc<Spaced<'_, N>>>,
@@ -494,13 +522,7 @@ fn fmt_imports_entry(buf: &mut Buf, entry: &ImportsEntry, indent: u16) {
             if !loc_exposes_entries.is_empty() {
                 buf.push('.');
 
-                fmt_collection(
-                    buf,
-                    indent,
-                    Braces::Curly,
-                    *loc_exposes_entries,
-                    Newlines::No,
-                )
+                fmt_exposed_names(buf, indent, Braces::Curly, *loc_exposes_entries)
             }
         }
 
@@ -512,7 +534,7 @@ fn fmt_imports_entry(buf: &mut Buf, entry: &ImportsEntry, indent: u16) {
             if !entries.is_empty() {
                 buf.push('.');
 
-                fmt_collection(buf, indent, Braces::Curly, *entries, Newlines::No)
+                fmt_exposed_names(buf, indent, Braces::Curly, *entries)
             }
         }
 
@@ -11,7 +11,15 @@ pub mod node;
 pub mod pattern;
 pub mod spaces;
 
+use annotation::Formattable;
 use bumpalo::{collections::String, Bump};
+use def::fmt_defs;
+use header::fmt_header;
+use roc_parse::ast::{CommentOrNewline, Defs, FullAst};
+use roc_parse::header::{parse_header, parse_module_defs};
+use roc_parse::parser::SyntaxError;
+use roc_parse::state::State;
+use roc_region::all::Region;
 
 #[derive(Debug)]
 pub struct Buf<'a> {
@@ -21,6 +29,7 @@ pub struct Buf<'a> {
     beginning_of_line: bool,
     line_indent: u16,
     flags: MigrationFlags,
+    options: FormatOptions,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -38,8 +47,40 @@ impl MigrationFlags {
     }
 }
 
+/// Layout settings for the formatter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Records, function applications and operator chains (like pipelines) that would extend
+    /// past this column are broken onto multiple lines, even if they were written on one.
+    ///
+    /// Defaults to `usize::MAX`, so the layout follows the line breaks in the source.
+    pub max_width: usize,
+    /// Sort the names that imports expose: types before values, each alphabetically.
+    /// Comments stay with the name they're attached to.
+    ///
+    /// Off by default, so formatting doesn't reorder code people wrote.
+    pub sort_exposing: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            max_width: usize::MAX,
+            sort_exposing: false,
+        }
+    }
+}
+
 impl<'a> Buf<'a> {
     pub fn new_in(arena: &'a Bump, flags: MigrationFlags) -> Buf<'a> {
+        Self::new_in_with_options(arena, flags, FormatOptions::default())
+    }
+
+    pub fn new_in_with_options(
+        arena: &'a Bump,
+        flags: MigrationFlags,
+        options: FormatOptions,
+    ) -> Buf<'a> {
         Buf {
             text: String::new_in(arena),
             line_indent: 0,
@@ -47,6 +88,7 @@ impl<'a> Buf<'a> {
             newlines_to_flush: 0,
             beginning_of_line: true,
             flags,
+            options,
         }
     }
 
@@ -54,6 +96,33 @@ impl<'a> Buf<'a> {
         self.flags
     }
 
+    pub fn options(&self) -> FormatOptions {
+        self.options
+    }
+
+    /// The column the next character will be written at, assuming it is written at `indent`
+    /// if we're still at the beginning of a line.
+    fn column(&self, indent: u16) -> usize {
+        if self.beginning_of_line {
+            indent as usize
+        } else if self.newlines_to_flush > 0 {
+            self.spaces_to_flush
+        } else {
+            let line = match self.text.rfind('\n') {
+                Some(index) => &self.text[index + 1..],
+                None => self.text.as_str(),
+            };
+
+            line.chars().count() + self.spaces_to_flush
+        }
+    }
+
+    /// Whether `width` more characters, written starting at `indent` if we're at the beginning
+    /// of a line, stay within `max_width`.
+    pub fn fits(&self, indent: u16, width: usize) -> bool {
+        self.column(indent).saturating_add(width) <= self.options.max_width
+    }
+
     pub fn as_str(&'a self) -> &'a str {
         self.text.as_str()
     }
@@ -191,3 +260,98 @@ impl<'a> Buf<'a> {
         self.spaces_to_flush == 0 && self.text.is_empty()
     }
 }
+
+/// Formats a whole module from its AST, without needing the source it was parsed from, e.g.
+/// for a code generator that builds the AST itself.
+///
+/// Comments and line breaks only appear where the AST has them, except that consecutive defs
+/// always go on separate lines. In particular, a blank line after the header has to be in the
+/// spaces before the first def.
+pub fn format_ast(
+    ast: &FullAst<'_>,
+    flags: MigrationFlags,
+    options: FormatOptions,
+) -> std::string::String {
+    let arena = Bump::new();
+    let mut buf = Buf::new_in_with_options(&arena, flags, options);
+
+    fmt_header(&mut buf, &ast.header);
+    fmt_defs(&mut buf, &ast.defs, 0);
+    buf.fmt_end_of_file();
+
+    buf.as_str().to_string()
+}
+
+/// The result of [format_range]: `text` is the replacement for the bytes of the source
+/// within `region`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormattedRange {
+    pub region: Region,
+    pub text: std::string::String,
+}
+
+/// Formats only the top-level defs of the module in `src` that overlap `region`, e.g. for an
+/// editor's "format selection".
+///
+/// The returned region runs from the start of the first of those defs to the end of the last,
+/// so nothing outside of it is touched, including the whitespace around the defs. Returns
+/// `None` if `region` doesn't overlap any def, e.g. because it's within the header.
+pub fn format_range<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    region: Region,
+    flags: MigrationFlags,
+) -> Result<Option<FormattedRange>, SyntaxError<'a>> {
+    let (_, state) = parse_header(arena, State::new(src.as_bytes()))
+        .map_err(|e| SyntaxError::Header(e.problem))?;
+    let defs = parse_module_defs(arena, state, Defs::default())?;
+
+    let overlaps = |def_region: &Region| {
+        def_region.start() <= region.end() && region.start() <= def_region.end()
+    };
+
+    let (first, last) = match (
+        defs.regions.iter().position(overlaps),
+        defs.regions.iter().rposition(overlaps),
+    ) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Ok(None),
+    };
+
+    // Keep the spaces between the selected defs, but not the ones before the first or after
+    // the last, since those are outside of the region we replace.
+    let mut selected = Defs::default();
+
+    for (index, def) in defs.loc_defs().enumerate().take(last + 1).skip(first) {
+        let spaces_before: &[CommentOrNewline] = if index == first {
+            &[]
+        } else {
+            &defs.spaces[defs.space_before[index].indices()]
+        };
+        let spaces_after: &[CommentOrNewline] = if index == last {
+            &[]
+        } else {
+            &defs.spaces[defs.space_after[index].indices()]
+        };
+
+        match def {
+            Ok(type_def) => {
+                selected.push_type_def(type_def.value, type_def.region, spaces_before, spaces_after)
+            }
+            Err(value_def) => selected.push_value_def(
+                value_def.value,
+                value_def.region,
+                spaces_before,
+                spaces_after,
+            ),
+        }
+    }
+
+    let mut buf = Buf::new_in(arena, flags);
+    selected.format(&mut buf, 0);
+
+    Ok(Some(FormattedRange {
+        region: Region::span_across(&defs.regions[first], &defs.regions[last]),
+        text: buf.as_str().trim_end().to_string(),
+    }))
+}
@@ -83,6 +83,7 @@ impl<'a> Formattable for Pattern<'a> {
             | Pattern::FloatLiteral(..)
             | Pattern::SingleQuote(_)
             | Pattern::Underscore(_)
+            | Pattern::RecordRest(_)
             | Pattern::Malformed(_)
             | Pattern::MalformedIdent(_, _)
             | Pattern::QualifiedIdentifier { .. } => false,
@@ -255,6 +256,15 @@ fn fmt_pattern_only(
             loc_pattern.format(buf, indent);
         }
 
+        Pattern::RecordRest(opt_name) => {
+            buf.indent(indent);
+            buf.push_str("..");
+
+            if let Some(name) = opt_name {
+                snakify_camel_ident(buf, name);
+            }
+        }
+
         Pattern::NumLiteral(string) => {
             buf.indent(indent);
             let needs_parens = parens == Parens::InClosurePattern;
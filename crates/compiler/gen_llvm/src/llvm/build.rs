@@ -19,7 +19,7 @@ use inkwell::basic_block::BasicBlock;
 use inkwell::builder::Builder;
 use inkwell::context::Context;
 use inkwell::debug_info::{
-    AsDIScope, DICompileUnit, DIFlagsConstants, DISubprogram, DebugInfoBuilder,
+    AsDIScope, DICompileUnit, DIFile, DIFlagsConstants, DISubprogram, DebugInfoBuilder,
 };
 use inkwell::memory_buffer::MemoryBuffer;
 use inkwell::module::{Linkage, Module};
@@ -53,10 +53,11 @@ use roc_mono::layout::{
     Builtin, InLayout, LambdaName, LambdaSet, Layout, LayoutIds, LayoutInterner, LayoutRepr, Niche,
     RawFunctionLayout, STLayoutInterner, TagIdIntType, UnionLayout,
 };
+use roc_region::all::{LineColumn, LineColumnIndex, Region};
 use roc_std::RocDec;
 use roc_target::{PtrWidth, Target};
 use std::convert::TryInto;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use super::convert::{struct_type_from_union_layout, RocUnion};
 use super::intrinsics::{
@@ -681,6 +682,32 @@ macro_rules! debug_info_init {
     }};
 }
 
+/// The kind of machine to generate code for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodegenTarget {
+    /// The machine the compiler itself runs on
+    Native,
+    /// `wasm32-unknown-unknown`, e.g. to run Roc programs in the browser
+    Wasm32,
+}
+
+impl CodegenTarget {
+    pub fn target(self) -> Target {
+        match self {
+            CodegenTarget::Native => target_lexicon::Triple::host().into(),
+            CodegenTarget::Wasm32 => Target::Wasm32,
+        }
+    }
+
+    /// The extension of the object files generated for this target
+    pub fn object_file_extension(self) -> &'static str {
+        match self {
+            CodegenTarget::Native => "o",
+            CodegenTarget::Wasm32 => "wasm",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum LlvmBackendMode {
     /// Assumes primitives (roc_alloc, roc_panic, etc) are provided by the host
@@ -741,6 +768,42 @@ pub struct Env<'a, 'ctx, 'env> {
     pub target: Target,
     pub mode: LlvmBackendMode,
     pub exposed_to_host: MutSet<Symbol>,
+    /// Whether `+`, `-` and `*` on sized integers crash when they overflow. Without these
+    /// checks, they wrap around like `Num.addWrap`, `Num.subWrap` and `Num.mulWrap` do.
+    pub overflow_checks: bool,
+    /// The source code that procedures were generated from. Debug info uses it to point each
+    /// procedure at the line it was defined on; when it is empty, all locations are line 0.
+    pub source_files: SourceFiles<'env>,
+}
+
+/// The source code of the modules being compiled, indexed so that the [Region] of a procedure
+/// can be turned into the file, line and column that DWARF debug info refers to.
+#[derive(Debug, Default)]
+pub struct SourceFiles<'a> {
+    files: MutMap<ModuleId, (&'a Path, LineColumnIndex<'a>)>,
+}
+
+impl<'a> SourceFiles<'a> {
+    pub fn new(sources: &'a MutMap<ModuleId, (PathBuf, Box<str>)>) -> Self {
+        let files = sources
+            .iter()
+            .map(|(module_id, (path, src))| {
+                (*module_id, (path.as_path(), LineColumnIndex::new(src)))
+            })
+            .collect();
+
+        SourceFiles { files }
+    }
+
+    fn location(&self, module_id: ModuleId, region: Region) -> Option<(&'a Path, LineColumn)> {
+        if region.is_synthetic() {
+            return None;
+        }
+
+        let (path, index) = self.files.get(&module_id)?;
+
+        Some((path, index.offset_to_line_column(region.start().offset)))
+    }
 }
 
 impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
@@ -974,8 +1037,70 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
     }
 
     pub fn new_subprogram(&self, function_name: &str) -> DISubprogram<'ctx> {
+        self.new_subprogram_at(function_name, self.compile_unit.get_file(), 0)
+    }
+
+    /// A subprogram for a procedure that was generated from Roc source code, pointing at the line
+    /// where that code starts.
+    pub fn new_proc_subprogram(
+        &self,
+        function_name: &str,
+        proc: &roc_mono::ir::Proc<'a>,
+    ) -> DISubprogram<'ctx> {
+        match self.proc_source_location(proc) {
+            Some((file, line_column)) => {
+                self.new_subprogram_at(function_name, file, line_column.line)
+            }
+            None => self.new_subprogram(function_name),
+        }
+    }
+
+    /// Attach the source location of `proc` to the instructions that are built next, so that a
+    /// debugger stepping into `fn_val` stops at the Roc code it was generated from.
+    pub fn set_proc_debug_location(
+        &self,
+        fn_val: FunctionValue<'ctx>,
+        proc: &roc_mono::ir::Proc<'a>,
+    ) {
+        match self.proc_source_location(proc) {
+            Some((_, line_column)) => {
+                let subprogram = fn_val.get_subprogram().expect("subprogram");
+                let loc = self.dibuilder.create_debug_location(
+                    self.context,
+                    line_column.line,
+                    line_column.column,
+                    subprogram.as_debug_info_scope(),
+                    /* inlined_at */ None,
+                );
+
+                self.builder.set_current_debug_location(loc);
+            }
+            None => debug_info_init!(self, fn_val),
+        }
+    }
+
+    fn proc_source_location(
+        &self,
+        proc: &roc_mono::ir::Proc<'a>,
+    ) -> Option<(DIFile<'ctx>, LineColumn)> {
+        let (path, line_column) = self
+            .source_files
+            .location(proc.name.name().module_id(), proc.region)?;
+
+        let filename = path.file_name().unwrap_or_default().to_string_lossy();
+        let directory = path.parent().unwrap_or(Path::new(".")).to_string_lossy();
+        let file = self.dibuilder.create_file(&filename, &directory);
+
+        Some((file, line_column))
+    }
+
+    fn new_subprogram_at(
+        &self,
+        function_name: &str,
+        file: DIFile<'ctx>,
+        line_no: u32,
+    ) -> DISubprogram<'ctx> {
         let dibuilder = self.dibuilder;
-        let compile_unit = self.compile_unit;
 
         let ditype = dibuilder
             .create_basic_type(
@@ -987,22 +1112,22 @@ impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
             .unwrap();
 
         let subroutine_type = dibuilder.create_subroutine_type(
-            compile_unit.get_file(),
+            file,
             /* return type */ Some(ditype.as_type()),
             /* parameter types */ &[],
             inkwell::debug_info::DIFlags::PUBLIC,
         );
 
         dibuilder.create_function(
-            /* scope */ compile_unit.get_file().as_debug_info_scope(),
+            /* scope */ file.as_debug_info_scope(),
             /* func name */ function_name,
             /* linkage_name */ None,
-            /* file */ compile_unit.get_file(),
-            /* line_no */ 0,
+            /* file */ file,
+            /* line_no */ line_no,
             /* DIType */ subroutine_type,
             /* is_local_to_unit */ true,
             /* is_definition */ true,
-            /* scope_line */ 0,
+            /* scope_line */ line_no,
             /* flags */ inkwell::debug_info::DIFlags::PUBLIC,
             /* is_optimized */ false,
         )
@@ -5316,10 +5441,20 @@ pub(crate) fn build_proc_headers<'a, 'r, 'ctx>(
     roc_mono::ir::Proc<'a>,
     std::vec::Vec<(&'a FuncSpecSolutions, FunctionValue<'ctx>)>,
 )> {
+    // Sort the procedures so that the order functions are declared (and later defined) in does
+    // not depend on the insertion order or hashing of `procedures`.
+    let mut procedures: std::vec::Vec<_> = procedures
+        .into_iter()
+        .map(|((symbol, layout), proc)| {
+            let name_bytes = roc_alias_analysis::func_name_bytes(&proc);
+            (symbol, name_bytes, layout, proc)
+        })
+        .collect();
+    procedures.sort_unstable_by(|(s1, b1, _, _), (s2, b2, _, _)| (s1, b1).cmp(&(s2, b2)));
+
     // Populate Procs further and get the low-level Expr from the canonical Expr
     let mut headers = std::vec::Vec::with_capacity(procedures.len());
-    for ((symbol, layout), proc) in procedures {
-        let name_bytes = roc_alias_analysis::func_name_bytes(&proc);
+    for (symbol, name_bytes, layout, proc) in procedures {
         let func_name = FuncName(&name_bytes);
 
         let func_solutions = mod_solutions.func_solutions(func_name).unwrap();
@@ -5751,10 +5886,10 @@ fn build_proc_header<'a, 'ctx>(
         Linkage::Internal,
     );
 
-    let subprogram = env.new_subprogram(&fn_name);
+    let subprogram = env.new_proc_subprogram(&fn_name, proc);
     fn_val.set_subprogram(subprogram);
 
-    debug_info_init!(env, fn_val);
+    env.set_proc_debug_location(fn_val, proc);
 
     if env.exposed_to_host.contains(&symbol) {
         let arguments = Vec::from_iter_in(proc.args.iter().map(|(layout, _)| *layout), env.arena);
@@ -6046,7 +6181,7 @@ fn build_proc<'a, 'ctx>(
 
     builder.position_at_end(entry);
 
-    debug_info_init!(env, fn_val);
+    env.set_proc_debug_location(fn_val, proc);
 
     // Add args to scope
     for (arg_val, (layout, arg_symbol)) in fn_val.get_param_iter().zip(args) {
@@ -6072,6 +6207,27 @@ fn build_proc<'a, 'ctx>(
     }
 }
 
+/// The textual LLVM IR of the module, i.e. the contents of its `.ll` file. Useful for
+/// debugging codegen.
+pub fn emit_llvm_ir(module: &Module<'_>) -> String {
+    module.print_to_string().to_string()
+}
+
+/// LLVM's module verifier rejected the generated module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleVerificationError {
+    /// LLVM's description of everything that is wrong with the module.
+    pub message: String,
+}
+
+/// Runs LLVM's module verifier (`LLVMVerifyModule`), returning its findings as an error rather
+/// than aborting.
+pub fn verify_module(module: &Module<'_>) -> Result<(), ModuleVerificationError> {
+    module.verify().map_err(|message| ModuleVerificationError {
+        message: message.to_string(),
+    })
+}
+
 pub fn verify_fn(fn_val: FunctionValue<'_>) {
     if !fn_val.verify(print_fn_verification_output()) {
         unsafe {
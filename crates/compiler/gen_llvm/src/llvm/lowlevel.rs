@@ -1447,6 +1447,7 @@ fn build_int_binop<'ctx>(
     let bd = env.builder;
 
     match op {
+        NumAdd if !env.overflow_checks => bd.new_build_int_add(lhs, rhs, "add_int_wrap").into(),
         NumAdd => {
             let result = env
                 .call_intrinsic(
@@ -1478,6 +1479,7 @@ fn build_int_binop<'ctx>(
         NumAddSaturated => {
             env.call_intrinsic(&LLVM_ADD_SATURATED[int_width], &[lhs.into(), rhs.into()])
         }
+        NumSub if !env.overflow_checks => bd.new_build_int_sub(lhs, rhs, "sub_int").into(),
         NumSub => {
             let result = env
                 .call_intrinsic(
@@ -1509,6 +1511,7 @@ fn build_int_binop<'ctx>(
         NumSubSaturated => {
             env.call_intrinsic(&LLVM_SUB_SATURATED[int_width], &[lhs.into(), rhs.into()])
         }
+        NumMul if !env.overflow_checks => bd.new_build_int_mul(lhs, rhs, "mul_int").into(),
         NumMul => {
             let result = env
                 .call_intrinsic(
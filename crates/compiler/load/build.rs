@@ -100,6 +100,7 @@ fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path:
         &module.sources,
         &module.interns,
         &mut module.can_problems,
+        &mut module.can_warnings,
         &mut module.type_problems,
     );
 
@@ -16,6 +16,7 @@ const SKIP_SUBS_CACHE: bool = {
     }
 };
 
+pub use roc_can::scope::ShadowPolicy;
 pub use roc_load_internal::docs;
 pub use roc_load_internal::file::{
     ExecutionMode, ExpectMetadata, LoadConfig, LoadResult, LoadStart, LoadingProblem, Phase,
@@ -53,6 +54,7 @@ pub fn load_single_threaded<'a>(
     load_start: LoadStart<'a>,
     target: Target,
     function_kind: FunctionKind,
+    step_budget: Option<u64>,
     render: RenderTarget,
     palette: Palette,
     roc_cache_dir: RocCacheDir<'_>,
@@ -67,6 +69,8 @@ pub fn load_single_threaded<'a>(
         exposed_types,
         target,
         function_kind,
+        step_budget,
+        ShadowPolicy::Disallow,
         cached_subs,
         render,
         palette,
@@ -181,6 +185,7 @@ pub fn load_and_typecheck_str<'a>(
     opt_main_path: Option<PathBuf>,
     target: Target,
     function_kind: FunctionKind,
+    step_budget: Option<u64>,
     render: RenderTarget,
     roc_cache_dir: RocCacheDir<'_>,
     palette: Palette,
@@ -204,6 +209,7 @@ pub fn load_and_typecheck_str<'a>(
         load_start,
         target,
         function_kind,
+        step_budget,
         render,
         palette,
         roc_cache_dir,
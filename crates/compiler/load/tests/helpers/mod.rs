@@ -13,7 +13,7 @@ use roc_constrain::expr::constrain_expr;
 use roc_derive::SharedDerivedModule;
 use roc_module::symbol::{IdentIds, Interns, ModuleId, ModuleIds, PQModuleName, PackageModuleIds};
 use roc_parse::parser::{SourceError, SyntaxError};
-use roc_problem::can::Problem;
+use roc_problem::can::{Problem, Warning};
 use roc_region::all::Loc;
 use roc_solve::module::SolveConfig;
 use roc_solve::solve::RunSolveOutput;
@@ -54,6 +54,8 @@ pub fn infer_expr(
         module_params: None,
         module_params_vars: Default::default(),
         host_exposed_symbols: None,
+        trace_generalization: false,
+        step_budget: None,
         #[cfg(debug_assertions)]
         checkmate: None,
     };
@@ -115,6 +117,7 @@ pub struct CanExprOut {
     pub loc_expr: Loc<Expr>,
     pub output: Output,
     pub problems: Vec<Problem>,
+    pub warnings: Vec<Warning>,
     pub home: ModuleId,
     pub interns: Interns,
     pub var_store: VarStore,
@@ -242,6 +245,7 @@ pub fn can_expr_with<'a>(
         loc_expr,
         output,
         problems: env.problems,
+        warnings: env.warnings,
         home: env.home,
         var_store,
         interns,
@@ -13,7 +13,9 @@ mod test_reporting {
     use indoc::indoc;
     use roc_can::abilities::AbilitiesStore;
     use roc_can::expr::PendingDerives;
-    use roc_load::{self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+    use roc_load::{
+        self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, ShadowPolicy, Threading,
+    };
     use roc_module::symbol::{Interns, ModuleId};
     use roc_packaging::cache::RocCacheDir;
     use roc_parse::header::parse_header;
@@ -22,7 +24,7 @@ mod test_reporting {
     use roc_problem::Severity;
     use roc_region::all::LineInfo;
     use roc_reporting::report::{
-        can_problem, parse_problem, type_problem, RenderTarget, Report, ANSI_STYLE_CODES,
+        can_reports, parse_problem, type_problem, RenderTarget, Report, ANSI_STYLE_CODES,
         DEFAULT_PALETTE,
     };
     use roc_reporting::report::{RocDocAllocator, RocDocBuilder};
@@ -130,6 +132,8 @@ mod test_reporting {
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
                 function_kind: FunctionKind::LambdaSet,
+                step_budget: None,
+                shadow_policy: ShadowPolicy::Disallow,
             };
             let result = roc_load::load_and_typecheck(
                 arena,
@@ -156,6 +160,7 @@ mod test_reporting {
             String,
             Vec<TypeError>,
             Vec<roc_problem::can::Problem>,
+            Vec<roc_problem::can::Warning>,
             ModuleId,
             Interns,
         ),
@@ -165,15 +170,24 @@ mod test_reporting {
         let LoadedModule {
             module_id: home,
             mut can_problems,
+            mut can_warnings,
             mut type_problems,
             interns,
             ..
         } = result?;
 
         let can_problems = can_problems.remove(&home).unwrap_or_default();
+        let can_warnings = can_warnings.remove(&home).unwrap_or_default();
         let type_problems = type_problems.remove(&home).unwrap_or_default();
 
-        Ok((module_src, type_problems, can_problems, home, interns))
+        Ok((
+            module_src,
+            type_problems,
+            can_problems,
+            can_warnings,
+            home,
+            interns,
+        ))
     }
 
     fn list_reports_new<F>(subdir: &str, arena: &Bump, src: &str, finalize_render: F) -> String
@@ -188,17 +202,13 @@ mod test_reporting {
 
         match infer_expr_help_new(subdir, arena, src) {
             Err(LoadingProblem::FormattedReport(fail, _)) => fail,
-            Ok((module_src, type_problems, can_problems, home, interns)) => {
+            Ok((module_src, type_problems, can_problems, can_warnings, home, interns)) => {
                 let lines = LineInfo::new(&module_src);
                 let src_lines: Vec<&str> = module_src.split('\n').collect();
-                let mut reports = Vec::new();
-
                 let alloc = RocDocAllocator::new(&src_lines, home, &interns);
 
-                for problem in can_problems {
-                    let report = can_problem(&alloc, &lines, filename.clone(), problem.clone());
-                    reports.push(report);
-                }
+                let mut reports =
+                    can_reports(&alloc, &lines, filename.clone(), can_problems, can_warnings);
 
                 for problem in type_problems {
                     if let Some(report) =
@@ -227,6 +237,7 @@ mod test_reporting {
         }
     }
 
+    #[allow(clippy::type_complexity)]
     fn infer_expr_help<'a>(
         arena: &'a Bump,
         expr_src: &'a str,
@@ -234,6 +245,7 @@ mod test_reporting {
         (
             Vec<TypeError>,
             Vec<roc_problem::can::Problem>,
+            Vec<roc_problem::can::Warning>,
             ModuleId,
             Interns,
         ),
@@ -249,6 +261,7 @@ mod test_reporting {
             home,
             interns,
             problems: can_problems,
+            warnings: can_warnings,
             mut types,
             ..
         } = can_expr(arena, expr_src)?;
@@ -285,7 +298,7 @@ mod test_reporting {
             var,
         );
 
-        Ok((unify_problems, can_problems, home, interns))
+        Ok((unify_problems, can_problems, can_warnings, home, interns))
     }
 
     fn list_reports<F>(arena: &Bump, src: &str, buf: &mut String, callback: F)
@@ -314,15 +327,11 @@ mod test_reporting {
 
                 callback(doc.pretty(&alloc).append(alloc.line()), buf)
             }
-            Ok((type_problems, can_problems, home, interns)) => {
-                let mut reports = Vec::new();
-
+            Ok((type_problems, can_problems, can_warnings, home, interns)) => {
                 let alloc = RocDocAllocator::new(&src_lines, home, &interns);
 
-                for problem in can_problems {
-                    let report = can_problem(&alloc, &lines, filename.clone(), problem.clone());
-                    reports.push(report);
-                }
+                let mut reports =
+                    can_reports(&alloc, &lines, filename.clone(), can_problems, can_warnings);
 
                 for problem in type_problems {
                     if let Some(report) =
@@ -707,6 +716,16 @@ mod test_reporting {
          "#
         ),
         @r#"
+    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+
+    `y` is not used anywhere in your code.
+
+    4│      y = 9
+            ^
+
+    If you didn't intend on using `y` then remove it so future readers of
+    your code don't wonder why it is there.
+
     ── UNUSED ARGUMENT in /code/proj/Main.roc ──────────────────────────────────────
 
     `box` doesn't use `htmlChildren`.
@@ -719,16 +738,6 @@ mod test_reporting {
     with an underscore, like this: "_`htmlChildren`". Adding an underscore
     at the start of a variable name is a way of saying that the variable
     is not used.
-
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
-
-    `y` is not used anywhere in your code.
-
-    4│      y = 9
-            ^
-
-    If you didn't intend on using `y` then remove it so future readers of
-    your code don't wonder why it is there.
     "#
     );
 
@@ -743,7 +752,7 @@ mod test_reporting {
         );
 
         let arena = Bump::new();
-        let (_type_problems, _can_problems, home, interns) =
+        let (_type_problems, _can_problems, _can_warnings, home, interns) =
             infer_expr_help(&arena, src).expect("parse error");
 
         let mut buf = String::new();
@@ -774,7 +783,7 @@ mod test_reporting {
         );
 
         let arena = Bump::new();
-        let (_type_problems, _can_problems, home, mut interns) =
+        let (_type_problems, _can_problems, _can_warnings, home, mut interns) =
             infer_expr_help(&arena, src).expect("parse error");
 
         let mut buf = String::new();
@@ -1039,6 +1048,42 @@ mod test_reporting {
     "#
     );
 
+    test_report!(
+        tuple_arity_mismatch_in_list,
+        indoc!(
+            r"
+            [(1, 2), (1, 2, 3)]
+            "
+        ),
+        @r"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    This list contains elements with different types:
+
+    4│      [(1, 2), (1, 2, 3)]
+                     ^^^^^^^^^
+
+    Its 2nd element is a tuple of type:
+
+        (
+            Num *,
+            Num *,
+            Num *,
+        )
+
+    However, the preceding elements in the list all have the type:
+
+        (
+            Num *,
+            Num *,
+        )
+
+    Every element in a list must have the same type!
+
+    Tip: The tuple has 3 elements in the first type, but 2 in the second.
+    "
+    );
+
     test_report!(
         unwrap_num_elem_in_list,
         indoc!(
@@ -1761,6 +1806,8 @@ mod test_reporting {
         { foo : [True] }
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: The `.foo` field has the wrong type.
     "
     );
 
@@ -1789,6 +1836,8 @@ mod test_reporting {
         { foo : [True] }
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: The `.foo` field has the wrong type.
     "#
     );
 
@@ -2075,6 +2124,68 @@ mod test_reporting {
     "
     );
 
+    test_report!(
+        record_field_type_mismatch,
+        indoc!(
+            r#"
+            person : { name : Str, age : U8 }
+            person = { name: "Ana", age: "nine" }
+
+            person
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    Something is off with the body of the `person` definition:
+
+    4│      person : { name : Str, age : U8 }
+    5│      person = { name: "Ana", age: "nine" }
+                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    The body is a record of type:
+
+        { age : Str, … }
+
+    But the type annotation on `person` says it should be:
+
+        { age : U8, … }
+
+    Tip: The `.age` field has the wrong type.
+    "#
+    );
+
+    test_report!(
+        nested_record_field_type_mismatch,
+        indoc!(
+            r#"
+            person : { name : Str, address : { city : Str, zip : U32 } }
+            person = { name: "Ana", address: { city: "Oslo", zip: "0150" } }
+
+            person
+            "#
+        ),
+        @r#"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    Something is off with the body of the `person` definition:
+
+    4│      person : { name : Str, address : { city : Str, zip : U32 } }
+    5│      person = { name: "Ana", address: { city: "Oslo", zip: "0150" } }
+                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    The body is a record of type:
+
+        { address : { zip : Str, … }, … }
+
+    But the type annotation on `person` says it should be:
+
+        { address : { zip : U32, … }, … }
+
+    Tip: The `.address.zip` field has the wrong type.
+    "#
+    );
+
     // this previously reported the message below, not sure which is better
     //
     //                Something is off with the body of the `f` definition:
@@ -2897,6 +3008,101 @@ mod test_reporting {
     "
     );
 
+    test_no_problem!(
+        patterns_result_like_exhaustive,
+        indoc!(
+            r"
+            x : [Ok I64, Err Str]
+
+            when x is
+                Ok n -> n
+                Err _ -> 0
+            "
+        )
+    );
+
+    test_report!(
+        patterns_result_like_missing_err,
+        indoc!(
+            r"
+            x : [Ok I64, Err Str]
+
+            when x is
+                Ok n -> n
+            "
+        ),
+        @r"
+    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    6│>      when x is
+    7│>          Ok n -> n
+
+    Other possibilities include:
+
+        Err _
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "
+    );
+
+    test_report!(
+        patterns_result_like_redundant_branch,
+        indoc!(
+            r"
+            x : [Ok I64, Err Str]
+
+            when x is
+                Ok n -> n
+                Err _ -> 0
+                Err _ -> 1
+            "
+        ),
+        @r"
+    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+
+    The 3rd pattern is redundant:
+
+    6│      when x is
+    7│          Ok n -> n
+    8│          Err _ -> 0
+    9│          Err _ -> 1
+                ^^^^^
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "
+    );
+
+    test_report!(
+        patterns_guard_is_not_exhaustive,
+        indoc!(
+            r"
+            x : [Ok I64, Err Str]
+
+            when x is
+                Ok n if n > 0 -> n
+                Err _ -> 0
+            "
+        ),
+        @r"
+    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    6│>      when x is
+    7│>          Ok n if n > 0 -> n
+    8│>          Err _ -> 0
+
+    Other possibilities include:
+
+        Ok _    (note the lack of an if clause)
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "
+    );
+
     test_report!(
         unify_alias_other,
         indoc!(
@@ -2951,11 +3157,20 @@ mod test_reporting {
         @r"
     ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
 
-    The `Foo` alias is self-recursive in an invalid way:
+    The `Foo` alias is recursive in an invalid way:
 
     4│      Foo : { x : Bar }
             ^^^
 
+    The `Foo` alias depends on itself through the following chain of
+    definitions:
+
+        ┌─────┐
+        │     Foo
+        │     ↓
+        │     Bar
+        └─────┘
+
     Recursion in aliases is only allowed if recursion happens behind a
     tagged union, at least one variant of which is not recursive.
     "
@@ -3208,15 +3423,6 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
-
-    This definition of `MyAlias` has an unexpected pattern:
-
-    4│      MyAlias 1 : Num.I64
-                    ^
-
-    Only type variables like `a` or `value` can occur in this position.
-
     ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
 
     `MyAlias` is not used anywhere in your code.
@@ -3226,6 +3432,15 @@ mod test_reporting {
 
     If you didn't intend on using `MyAlias` then remove it so future readers
     of your code don't wonder why it is there.
+
+    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+
+    This definition of `MyAlias` has an unexpected pattern:
+
+    4│      MyAlias 1 : Num.I64
+                    ^
+
+    Only type variables like `a` or `value` can occur in this position.
     "
     );
 
@@ -3432,6 +3647,8 @@ mod test_reporting {
             Cons {} a,
             Nil,
         ] as a
+
+    Tip: The 2nd payload of the `Cons` tag has the wrong type.
     "#
     );
 
@@ -3487,6 +3704,8 @@ mod test_reporting {
             ACons I64 (BList I64 I64),
             ANil,
         ] as a
+
+    Tip: The 2nd payload of the `ACons` tag has the wrong type.
     "#
     );
 
@@ -4079,6 +4298,8 @@ mod test_reporting {
         { y : Str, … }
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: The `.y` field has the wrong type.
     "#
     );
 
@@ -4113,6 +4334,8 @@ mod test_reporting {
         { y ? Str, … }
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: The `.y` field has the wrong type.
     "#
     );
 
@@ -5727,6 +5950,22 @@ mod test_reporting {
     "#
     );
 
+    test_report!(
+        empty_interpolation,
+        r#""abc $() def""#,
+        @r#"
+    ── EMPTY INTERPOLATION in tmp/empty_interpolation/Test.roc ─────────────────────
+
+    This string interpolation is empty:
+
+    4│      "abc $() def"
+                 ^
+
+    Put an expression between the parentheses, like
+    "The count is $(count)". To write a literal $, escape it like \$.
+    "#
+    );
+
     test_report!(
         single_quote_too_long,
         r"'abcdef'",
@@ -6526,6 +6765,8 @@ All branches in an `if` must have the same type!
     But the type annotation on `x` says it should be:
 
         List [Foo Str]
+
+    Tip: The 1st payload of the `Foo` tag has the wrong type.
     "
     );
 
@@ -7232,6 +7473,8 @@ All branches in an `if` must have the same type!
     But the type annotation on `job` says it should be:
 
         [Job { inputs : List a }]a as a
+
+    Tip: The 1st payload of the `Job` tag has the wrong type.
     "
     );
 
@@ -7511,6 +7754,35 @@ All branches in an `if` must have the same type!
     "
     );
 
+    test_report!(
+        suffixed_int_literal_mismatches_annotation,
+        indoc!(
+            r"
+            x : I64
+            x = 5u8
+
+            x
+            "
+        ),
+        @r"
+    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+
+    Something is off with the body of the `x` definition:
+
+    4│      x : I64
+    5│      x = 5u8
+                ^^^
+
+    The body is an integer of type:
+
+        U8
+
+    But the type annotation on `x` says it should be:
+
+        I64
+    "
+    );
+
     test_report!(
         u8_overflow,
         "256u8",
@@ -7527,6 +7799,22 @@ All branches in an `if` must have the same type!
     "
     );
 
+    test_report!(
+        binary_u8_overflow,
+        "0b1_0000_0000u8",
+        @r"
+    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+
+    This integer literal overflows the type indicated by its suffix:
+
+    4│      0b1_0000_0000u8
+            ^^^^^^^^^^^^^^^
+
+    Tip: The suffix indicates this integer is a U8, whose maximum value is
+    255.
+    "
+    );
+
     test_report!(
         negative_u8,
         "-1u8",
@@ -8043,6 +8331,16 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
+    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+
+    `Age` is not used anywhere in your code.
+
+    4│      Age : Num.U8
+            ^^^^^^^^^^^^
+
+    If you didn't intend on using `Age` then remove it so future readers of
+    your code don't wonder why it is there.
+
     ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
 
     The opaque type Age referenced here is not defined:
@@ -8056,16 +8354,6 @@ All branches in an `if` must have the same type!
             ^^^
 
     Note: It looks like there are no opaque types declared in this scope yet!
-
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
-
-    `Age` is not used anywhere in your code.
-
-    4│      Age : Num.U8
-            ^^^^^^^^^^^^
-
-    If you didn't intend on using `Age` then remove it so future readers of
-    your code don't wonder why it is there.
     "
     );
 
@@ -8076,19 +8364,10 @@ All branches in an `if` must have the same type!
             OtherModule.@Age 21
             "
         ),
-        // TODO: get rid of the first error. Consider parsing OtherModule.@Age to completion
-        // and checking it during can. The reason the error appears is because it is parsed as
-        // Apply(Error(OtherModule), [@Age, 21])
+        // TODO: get rid of the OPAQUE TYPE NOT DEFINED error. Consider parsing OtherModule.@Age
+        // to completion and checking it during can. The reason the error appears is because it is
+        // parsed as Apply(Error(OtherModule), [@Age, 21])
         @r"
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
-
-    The opaque type Age referenced here is not defined:
-
-    4│      OtherModule.@Age 21
-                        ^^^^
-
-    Note: It looks like there are no opaque types declared in this scope yet!
-
     ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
 
     I am trying to parse a qualified name here:
@@ -8098,6 +8377,15 @@ All branches in an `if` must have the same type!
 
     I was expecting to see an identifier next, like height. A complete
     qualified name looks something like Json.Decode.string.
+
+    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+
+    The opaque type Age referenced here is not defined:
+
+    4│      OtherModule.@Age 21
+                        ^^^^
+
+    Note: It looks like there are no opaque types declared in this scope yet!
     "
     );
 
@@ -8784,16 +9072,6 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ABILITY HAS TYPE VARIABLES in /code/proj/Main.roc ───────────────────────────
-
-    The definition of the `MHash` ability includes type variables:
-
-    3│  MHash a b c implements
-              ^^^^^
-
-    Abilities cannot depend on type variables, but their member values
-    can!
-
     ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
 
     `MHash` is not used anywhere in your code.
@@ -8803,6 +9081,16 @@ All branches in an `if` must have the same type!
 
     If you didn't intend on using `MHash` then remove it so future readers
     of your code don't wonder why it is there.
+
+    ── ABILITY HAS TYPE VARIABLES in /code/proj/Main.roc ───────────────────────────
+
+    The definition of the `MHash` ability includes type variables:
+
+    3│  MHash a b c implements
+              ^^^^^
+
+    Abilities cannot depend on type variables, but their member values
+    can!
     "
     );
 
@@ -8891,6 +9179,16 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
+        ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+
+        `Ability` is not used anywhere in your code.
+
+        3│  Ability implements ab : {} -> {}
+            ^^^^^^^
+
+        If you didn't intend on using `Ability` then remove it so future readers
+        of your code don't wonder why it is there.
+
         ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE in /code/proj/Main.roc ─────────────
 
         The definition of the ability member `ab` does not include an `implements`
@@ -8905,16 +9203,6 @@ All branches in an `if` must have the same type!
             a implements Ability
 
         Otherwise, the function does not need to be part of the ability!
-
-        ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
-
-        `Ability` is not used anywhere in your code.
-
-        3│  Ability implements ab : {} -> {}
-            ^^^^^^^
-
-        If you didn't intend on using `Ability` then remove it so future readers
-        of your code don't wonder why it is there.
         "
     );
 
@@ -8956,16 +9244,6 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ILLEGAL IMPLEMENTS CLAUSE in /code/proj/Main.roc ────────────────────────────
-
-    An `implements` clause is not allowed here:
-
-    3│  MHash implements hash : (a where a implements MHash) -> Num.U64
-                                         ^^^^^^^^^^^^^^^^^^
-
-    `implements` clauses can only be specified on the top-level type
-    annotations.
-
     ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE in /code/proj/Main.roc ─────────────
 
     The definition of the ability member `hash` does not include an
@@ -8980,6 +9258,16 @@ All branches in an `if` must have the same type!
         a implements MHash
 
     Otherwise, the function does not need to be part of the ability!
+
+    ── ILLEGAL IMPLEMENTS CLAUSE in /code/proj/Main.roc ────────────────────────────
+
+    An `implements` clause is not allowed here:
+
+    3│  MHash implements hash : (a where a implements MHash) -> Num.U64
+                                         ^^^^^^^^^^^^^^^^^^
+
+    `implements` clauses can only be specified on the top-level type
+    annotations.
     "
     );
 
@@ -9774,17 +10062,6 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── IMPLEMENTATION NOT FOUND in /code/proj/Main.roc ─────────────────────────────
-
-    An implementation of `eq` could not be found in this scope:
-
-    5│  A := U8 implements [MEq {eq}]
-                                 ^^
-
-    Tip: consider adding a value of name `eq` in this scope, or using
-    another variable that implements this ability member, like
-    { eq: myeq }
-
     ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
 
     This type does not fully implement the `MEq` ability:
@@ -9795,6 +10072,17 @@ All branches in an `if` must have the same type!
     The following necessary members are missing implementations:
 
         eq
+
+    ── IMPLEMENTATION NOT FOUND in /code/proj/Main.roc ─────────────────────────────
+
+    An implementation of `eq` could not be found in this scope:
+
+    5│  A := U8 implements [MEq {eq}]
+                                 ^^
+
+    Tip: consider adding a value of name `eq` in this scope, or using
+    another variable that implements this ability member, like
+    { eq: myeq }
     "
     );
 
@@ -9812,29 +10100,29 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
 
-    Nothing is named `aMEq` in this scope.
+    This type does not fully implement the `MEq` ability:
 
     5│  A := U8 implements [ MEq {eq: aMEq} ]
-                                      ^^^^
+                             ^^^^^^^^^^^^^^
 
-    Did you mean one of these?
+    The following necessary members are missing implementations:
 
-        MEq
-        Eq
-        myMEq
         eq
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
 
-    This type does not fully implement the `MEq` ability:
+    Nothing is named `aMEq` in this scope.
 
     5│  A := U8 implements [ MEq {eq: aMEq} ]
-                             ^^^^^^^^^^^^^^
+                                      ^^^^
 
-    The following necessary members are missing implementations:
+    Did you mean one of these?
 
+        MEq
+        Eq
+        myMEq
         eq
     "
     );
@@ -9853,17 +10141,6 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── OPTIONAL ABILITY IMPLEMENTATION in /code/proj/Main.roc ──────────────────────
-
-    Ability implementations cannot be optional:
-
-    5│  A := U8 implements [ MEq {eq ? aMEq} ]
-                                  ^^^^^^^^^
-
-    Custom implementations must be supplied fully.
-
-
-
     ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
 
     This type does not fully implement the `MEq` ability:
@@ -9874,6 +10151,15 @@ All branches in an `if` must have the same type!
     The following necessary members are missing implementations:
 
         eq
+
+    ── OPTIONAL ABILITY IMPLEMENTATION in /code/proj/Main.roc ──────────────────────
+
+    Ability implementations cannot be optional:
+
+    5│  A := U8 implements [ MEq {eq ? aMEq} ]
+                                  ^^^^^^^^^
+
+    Custom implementations must be supplied fully.
     "
     );
 
@@ -9891,6 +10177,17 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
+    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+
+    This type does not fully implement the `Encoding` ability:
+
+    5│  A := U8 implements [ Encoding {toEncoder ? myEncoder} ]
+                             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    The following necessary members are missing implementations:
+
+        toEncoder
+
     ── OPTIONAL ABILITY IMPLEMENTATION in /code/proj/Main.roc ──────────────────────
 
     Ability implementations cannot be optional:
@@ -9903,17 +10200,6 @@ All branches in an `if` must have the same type!
     Hint: if you want this implementation to be derived, don't include a
     record of implementations. For example,    implements [Encoding] will
     attempt to derive `Encoding`
-
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
-
-    This type does not fully implement the `Encoding` ability:
-
-    5│  A := U8 implements [ Encoding {toEncoder ? myEncoder} ]
-                             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-
-    The following necessary members are missing implementations:
-
-        toEncoder
     "
     );
 
@@ -9929,16 +10215,6 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── QUALIFIED ABILITY IMPLEMENTATION in /code/proj/Main.roc ─────────────────────
-
-    This ability implementation is qualified:
-
-    5│  A := U8 implements [ MEq {eq : Bool.eq} ]
-                                       ^^^^^^^
-
-    Custom implementations must be defined in the local scope, and
-    unqualified.
-
     ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
 
     This type does not fully implement the `MEq` ability:
@@ -9949,6 +10225,16 @@ All branches in an `if` must have the same type!
     The following necessary members are missing implementations:
 
         eq
+
+    ── QUALIFIED ABILITY IMPLEMENTATION in /code/proj/Main.roc ─────────────────────
+
+    This ability implementation is qualified:
+
+    5│  A := U8 implements [ MEq {eq : Bool.eq} ]
+                                       ^^^^^^^
+
+    Custom implementations must be defined in the local scope, and
+    unqualified.
     "
     );
 
@@ -9964,28 +10250,28 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ABILITY IMPLEMENTATION NOT IDENTIFIER in /code/proj/Main.roc ────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
 
-    This ability implementation is not an identifier:
+    This type does not fully implement the `MEq` ability:
 
     5│  A := U8 implements [ MEq {eq : \m, n -> m == n} ]
-                                       ^^^^^^^^^^^^^^^
+                             ^^^^^^^^^^^^^^^^^^^^^^^^^^
 
-    Custom ability implementations defined in this position can only be
-    unqualified identifiers, not arbitrary expressions.
+    The following necessary members are missing implementations:
 
-    Tip: consider defining this expression as a variable.
+        eq
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── ABILITY IMPLEMENTATION NOT IDENTIFIER in /code/proj/Main.roc ────────────────
 
-    This type does not fully implement the `MEq` ability:
+    This ability implementation is not an identifier:
 
     5│  A := U8 implements [ MEq {eq : \m, n -> m == n} ]
-                             ^^^^^^^^^^^^^^^^^^^^^^^^^^
+                                       ^^^^^^^^^^^^^^^
 
-    The following necessary members are missing implementations:
+    Custom ability implementations defined in this position can only be
+    unqualified identifiers, not arbitrary expressions.
 
-        eq
+    Tip: consider defining this expression as a variable.
     "
     );
 
@@ -10644,6 +10930,22 @@ All branches in an `if` must have the same type!
 
                 But then it is used here:
 
+                6│      \a, _b -> f a _b 1
+                                      ^^
+
+                A variable's name can only start with an underscore if the variable is
+                unused. Since you are using this variable, you could remove the
+                underscore from its name in both places.
+
+                ── UNDERSCORE BINDING USED in /code/proj/Main.roc ──────────────────────────────
+
+                This variable's name starts with an underscore:
+
+                6│      \a, _b -> f a _b 1
+                            ^^
+
+                But then it is used here:
+
                 6│      \a, _b -> f a _b 1
                                       ^^
 
@@ -11252,6 +11554,9 @@ All branches in an `if` must have the same type!
         [Good … *, …]
 
     The branches must be cases of the `when` condition's type!
+
+    Tip: The `Good` tag has 1 payload in the first type, but 2 payloads in
+    the second.
     "#
     );
 
@@ -12063,7 +12368,7 @@ All branches in an `if` must have the same type!
         (
             Str,
             {}a -> {},
-        )a
+        )
 
     In particular, an implementation for
 
@@ -12310,6 +12615,47 @@ All branches in an `if` must have the same type!
         @"" // no error
     );
 
+    test_report!(
+        derive_eq_for_record_of_eq_fields,
+        indoc!(
+            r#"
+             app "test" provides [A] to "./platform"
+
+             A := { x : U8, y : Str } implements [Eq]
+             "#
+        ),
+        @"" // no error
+    );
+
+    test_report!(
+        derive_eq_for_record_with_function_field,
+        indoc!(
+            r#"
+             app "test" provides [A] to "./platform"
+
+             A := { f : U8 -> U8 } implements [Eq]
+             "#
+        ),
+        @r"
+    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+
+    I can't derive an implementation of the `Eq` ability for `A`:
+
+    3│  A := { f : U8 -> U8 } implements [Eq]
+                                          ^^
+
+    In particular, an implementation for
+
+        U8 -> U8
+
+    cannot be generated.
+
+    Note: `Eq` cannot be generated for functions.
+
+    Tip: You can define a custom implementation of `Eq` for `A`.
+    "
+    );
+
     test_report!(
         derive_eq_for_recursive_deriving,
         indoc!(
@@ -12495,7 +12841,7 @@ All branches in an `if` must have the same type!
         (
             Str,
             F64,
-        )a
+        )
 
     In particular, an implementation for
 
@@ -14492,7 +14838,7 @@ All branches in an `if` must have the same type!
           (
               Str,
               Str,
-          )a
+          )
 
       But you are trying to use it as:
 
@@ -15088,6 +15434,8 @@ All branches in an `if` must have the same type!
     But I expected the function to have return type:
 
         [Err (Num *), …]a
+
+    Tip: The 1st payload of the `Err` tag has the wrong type.
     "#
     );
 
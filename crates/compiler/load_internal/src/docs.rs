@@ -189,26 +189,12 @@ fn generate_entry_docs(
 
     let mut doc_entries = Vec::with_capacity(defs.tags.len() + 1);
 
-    if let Some(docs) = comments_or_new_lines_to_docs(header_comments) {
+    if let Some(docs) = ast::docs_from_spaces(header_comments) {
         doc_entries.push(DocEntry::ModuleDoc(docs));
     }
 
-    let mut before_comments_or_new_lines: Option<&[CommentOrNewline]> = None;
-    let mut scratchpad = Vec::new();
-
     for (index, either_index) in defs.tags.iter().enumerate() {
-        let spaces_before = &defs.spaces[defs.space_before[index].indices()];
-
-        scratchpad.clear();
-        scratchpad.extend(
-            before_comments_or_new_lines
-                .take()
-                .iter()
-                .flat_map(|e| e.iter()),
-        );
-        scratchpad.extend(spaces_before);
-
-        let docs = comments_or_new_lines_to_docs(&scratchpad);
+        let docs = defs.doc_comment(index);
 
         match either_index.split() {
             Err(value_index) => match &defs.value_defs[value_index.index()] {
@@ -386,7 +372,7 @@ fn generate_entry_docs(
                                 name: extracted.item.to_string(),
                                 type_annotation,
                                 able_variables,
-                                docs: comments_or_new_lines_to_docs(extracted.before),
+                                docs: ast::docs_from_spaces(extracted.before),
                             }
                         })
                         .collect();
@@ -403,14 +389,14 @@ fn generate_entry_docs(
                 }
             },
         }
-
-        let spaces_after = &defs.spaces[defs.space_after[index].indices()];
-        before_comments_or_new_lines = Some(spaces_after);
     }
 
-    let it = before_comments_or_new_lines.iter().flat_map(|e| e.iter());
+    let after_last = defs
+        .space_after
+        .last()
+        .map_or(&[][..], |spaces| &defs.spaces[spaces.indices()]);
 
-    for detached_doc in detached_docs_from_comments_and_new_lines(it) {
+    for detached_doc in detached_docs_from_comments_and_new_lines(after_last.iter()) {
         doc_entries.push(DetachedDoc(detached_doc));
     }
 
@@ -745,27 +731,3 @@ fn tag_to_doc(in_func_ann: bool, tag: ast::Tag) -> Option<Tag> {
         ast::Tag::SpaceAfter(&sub_tag, _) => tag_to_doc(in_func_ann, sub_tag),
     }
 }
-
-fn comments_or_new_lines_to_docs<'a>(
-    comments_or_new_lines: &'a [roc_parse::ast::CommentOrNewline<'a>],
-) -> Option<String> {
-    let mut docs = String::new();
-
-    for comment_or_new_line in comments_or_new_lines.iter() {
-        match comment_or_new_line {
-            CommentOrNewline::DocComment(doc_str) => {
-                docs.push_str(doc_str);
-                docs.push('\n');
-            }
-            CommentOrNewline::Newline | CommentOrNewline::LineComment(_) => {
-                docs = String::new();
-            }
-        }
-    }
-
-    if docs.is_empty() {
-        None
-    } else {
-        Some(docs)
-    }
-}
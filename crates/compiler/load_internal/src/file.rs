@@ -21,6 +21,7 @@ use roc_can::module::{
     canonicalize_module_defs, ExposedByModule, ExposedForModule, ExposedModuleTypes, Module,
     ModuleParams, ResolvedImplementations, TypeState,
 };
+use roc_can::scope::ShadowPolicy;
 use roc_collections::soa::slice_extend_new;
 use roc_collections::{default_hasher, BumpMap, MutMap, MutSet, VecMap, VecSet};
 use roc_constrain::module::constrain_module;
@@ -50,7 +51,7 @@ use roc_mono::reset_reuse;
 use roc_mono::{drop_specialization, inc_dec};
 use roc_packaging::cache::RocCacheDir;
 use roc_parse::ast::{self, CommentOrNewline, ExtractSpaces, Spaced, ValueDef};
-use roc_parse::header::parse_module_defs;
+use roc_parse::header::parse_module_defs_with_recovery;
 use roc_parse::header::{
     self, AppHeader, ExposedName, HeaderType, ImportsKeywordItem, PackageEntry, PackageHeader,
     PlatformHeader, To,
@@ -114,6 +115,12 @@ pub struct LoadConfig {
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
     pub function_kind: FunctionKind,
+    /// Give up type checking a module after this many unifications; see
+    /// [roc_solve::module::SolveConfig::step_budget].
+    pub step_budget: Option<u64>,
+    /// What to do when a name in a module shadows one already in scope; see
+    /// [ShadowPolicy].
+    pub shadow_policy: ShadowPolicy,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -320,6 +327,7 @@ fn start_phase<'a>(
                     exec_mode: state.exec_mode,
                     imported_module_params,
                     fx_mode: state.fx_mode,
+                    shadow_policy: state.shadow_policy,
                 }
             }
 
@@ -360,6 +368,7 @@ fn start_phase<'a>(
                     constraints,
                     constraint,
                     state.function_kind,
+                    state.step_budget,
                     pending_derives,
                     var_store,
                     available_modules,
@@ -643,7 +652,7 @@ enum Msg<'a> {
         module_expectations: VecMap<ModuleId, Expectations>,
     },
 
-    FailedToParse(FileError<'a, SyntaxError<'a>>),
+    FailedToParse(FileError<'a, Vec<SyntaxError<'a>>>),
     FailedToReadFile {
         filename: PathBuf,
         error: io::ErrorKind,
@@ -657,6 +666,7 @@ enum Msg<'a> {
 struct CanAndCon {
     constrained_module: ConstrainedModule,
     canonicalization_problems: Vec<roc_problem::can::Problem>,
+    canonicalization_warnings: Vec<roc_problem::can::Warning>,
     module_docs: Option<ModuleDocumentation>,
 }
 
@@ -714,6 +724,8 @@ struct State<'a> {
     pub platform_path: PlatformPath<'a>,
     pub target: Target,
     pub(self) function_kind: FunctionKind,
+    pub(self) step_budget: Option<u64>,
+    pub(self) shadow_policy: ShadowPolicy,
     pub fx_mode: FxMode,
 
     /// Note: only packages and platforms actually expose any modules;
@@ -780,6 +792,8 @@ impl<'a> State<'a> {
         opt_platform_shorthand: Option<&'a str>,
         target: Target,
         function_kind: FunctionKind,
+        step_budget: Option<u64>,
+        shadow_policy: ShadowPolicy,
         exposed_types: ExposedByModule,
         arc_modules: Arc<Mutex<PackageModuleIds<'a>>>,
         ident_ids_by_module: SharedIdentIdsByModule,
@@ -802,6 +816,8 @@ impl<'a> State<'a> {
             cache_dir,
             target,
             function_kind,
+            step_budget,
+            shadow_policy,
             fx_mode: FxMode::Task,
             platform_data: None,
             platform_path: PlatformPath::NotSpecified,
@@ -906,6 +922,7 @@ enum BuildTask<'a> {
         exec_mode: ExecutionMode,
         imported_module_params: VecMap<ModuleId, ModuleParams>,
         fx_mode: FxMode,
+        shadow_policy: ShadowPolicy,
     },
     Solve {
         module: Module,
@@ -916,6 +933,7 @@ enum BuildTask<'a> {
         constraints: Constraints,
         constraint: ConstraintSoa,
         function_kind: FunctionKind,
+        step_budget: Option<u64>,
         pending_derives: PendingDerives,
         var_store: VarStore,
         declarations: Declarations,
@@ -971,7 +989,8 @@ pub enum LoadingProblem<'a> {
         filename: PathBuf,
         error: io::ErrorKind,
     },
-    ParsingFailed(FileError<'a, SyntaxError<'a>>),
+    /// Every syntax error of the file, in source order
+    ParsingFailed(FileError<'a, Vec<SyntaxError<'a>>>),
     UnexpectedHeader(String),
     MultiplePlatformPackages {
         filename: PathBuf,
@@ -1008,7 +1027,11 @@ pub enum LoadingProblem<'a> {
 impl<'a> LoadingProblem<'a> {
     pub fn get_region(&self) -> Option<Region> {
         match self {
-            LoadingProblem::ParsingFailed(err) => err.problem.problem.get_region(),
+            LoadingProblem::ParsingFailed(err) => err
+                .problem
+                .problem
+                .first()
+                .and_then(SyntaxError::get_region),
             LoadingProblem::MultiplePlatformPackages {
                 filename: _,
                 module_id: _,
@@ -1126,6 +1149,8 @@ pub fn load_and_typecheck_str<'a>(
         threading,
         exec_mode: ExecutionMode::Check,
         function_kind,
+        step_budget: None,
+        shadow_policy: ShadowPolicy::Disallow,
     };
 
     match load(
@@ -1397,7 +1422,7 @@ fn load_packages_from_main<'a>(
     let (parsed_header, _) =
         roc_parse::header::parse_header(arena, parse_state.clone()).map_err(|fail| {
             LoadingProblem::ParsingFailed(
-                fail.map_problem(SyntaxError::Header)
+                fail.map_problem(|fail| vec![SyntaxError::Header(fail)])
                     .into_file_error(filename.clone()),
             )
         })?;
@@ -1541,6 +1566,8 @@ pub fn load<'a>(
             exposed_types,
             load_config.target,
             load_config.function_kind,
+            load_config.step_budget,
+            load_config.shadow_policy,
             cached_types,
             load_config.render,
             load_config.palette,
@@ -1553,6 +1580,8 @@ pub fn load<'a>(
             exposed_types,
             load_config.target,
             load_config.function_kind,
+            load_config.step_budget,
+            load_config.shadow_policy,
             cached_types,
             load_config.render,
             load_config.palette,
@@ -1570,6 +1599,8 @@ pub fn load_single_threaded<'a>(
     exposed_types: ExposedByModule,
     target: Target,
     function_kind: FunctionKind,
+    step_budget: Option<u64>,
+    shadow_policy: ShadowPolicy,
     cached_types: MutMap<ModuleId, TypeState>,
     render: RenderTarget,
     palette: Palette,
@@ -1603,6 +1634,8 @@ pub fn load_single_threaded<'a>(
         opt_platform_shorthand,
         target,
         function_kind,
+        step_budget,
+        shadow_policy,
         exposed_types,
         arc_modules,
         ident_ids_by_module,
@@ -1726,7 +1759,11 @@ fn state_thread_step<'a>(
                 }
 
                 Msg::FailedToParse(problem) => {
-                    let region = problem.problem.problem.get_region();
+                    let region = problem
+                        .problem
+                        .problem
+                        .first()
+                        .and_then(SyntaxError::get_region);
                     let module_ids = (*state.arc_modules).lock().clone().into_module_ids();
                     let buf = to_parse_problem_report(
                         problem,
@@ -1767,7 +1804,11 @@ fn state_thread_step<'a>(
                     match res_state {
                         Ok(new_state) => Ok(ControlFlow::Continue(new_state)),
                         Err(LoadingProblem::ParsingFailed(problem)) => {
-                            let region = problem.problem.problem.get_region();
+                            let region = problem
+                                .problem
+                                .problem
+                                .first()
+                                .and_then(SyntaxError::get_region);
                             let module_ids = Arc::try_unwrap(arc_modules)
                                 .unwrap_or_else(|_| {
                                     panic!(
@@ -1950,6 +1991,8 @@ fn load_multi_threaded<'a>(
     exposed_types: ExposedByModule,
     target: Target,
     function_kind: FunctionKind,
+    step_budget: Option<u64>,
+    shadow_policy: ShadowPolicy,
     cached_types: MutMap<ModuleId, TypeState>,
     render: RenderTarget,
     palette: Palette,
@@ -1999,6 +2042,8 @@ fn load_multi_threaded<'a>(
         opt_platform_shorthand,
         target,
         function_kind,
+        step_budget,
+        shadow_policy,
         exposed_types,
         arc_modules,
         ident_ids_by_module,
@@ -2439,6 +2484,7 @@ fn update<'a>(
         CanonicalizedAndConstrained(CanAndCon {
             constrained_module,
             canonicalization_problems,
+            canonicalization_warnings,
             module_docs,
         }) => {
             let module_id = constrained_module.module.module_id;
@@ -2447,6 +2493,10 @@ fn update<'a>(
                 .module_cache
                 .can_problems
                 .insert(module_id, canonicalization_problems);
+            state
+                .module_cache
+                .can_warnings
+                .insert(module_id, canonicalization_warnings);
 
             if let Some(docs) = module_docs {
                 state.module_cache.documentation.insert(module_id, docs);
@@ -3279,6 +3329,7 @@ fn finish_specialization<'a>(
     let ModuleCache {
         type_problems,
         can_problems,
+        can_warnings,
         sources,
         ..
     } = module_cache;
@@ -3298,6 +3349,7 @@ fn finish_specialization<'a>(
 
     Ok(MonomorphizedModule {
         can_problems,
+        can_warnings,
         type_problems,
         expectations: module_expectations,
         exposed_to_host,
@@ -3388,6 +3440,7 @@ fn finish(
         interns,
         solved,
         can_problems: state.module_cache.can_problems,
+        can_warnings: state.module_cache.can_warnings,
         type_problems: state.module_cache.type_problems,
         declarations_by_id,
         typechecked: state.module_cache.checked,
@@ -3561,7 +3614,7 @@ fn load_package_from_disk<'a>(
                     Ok(Msg::Many(messages))
                 }
                 Err(fail) => Err(LoadingProblem::ParsingFailed(
-                    fail.map_problem(SyntaxError::Header)
+                    fail.map_problem(|fail| vec![SyntaxError::Header(fail)])
                         .into_file_error(filename.to_path_buf()),
                 )),
             }
@@ -4126,7 +4179,7 @@ fn parse_header<'a>(
             })
         }
         Err(fail) => Err(LoadingProblem::ParsingFailed(
-            fail.map_problem(SyntaxError::Header)
+            fail.map_problem(|fail| vec![SyntaxError::Header(fail)])
                 .into_file_error(filename),
         )),
     }
@@ -4373,6 +4426,7 @@ impl<'a> BuildTask<'a> {
         constraints: Constraints,
         constraint: ConstraintSoa,
         function_kind: FunctionKind,
+        step_budget: Option<u64>,
         pending_derives: PendingDerives,
         var_store: VarStore,
         imported_modules: MutMap<ModuleId, Region>,
@@ -4399,6 +4453,7 @@ impl<'a> BuildTask<'a> {
             constraints,
             constraint,
             function_kind,
+            step_budget,
             pending_derives,
             var_store,
             declarations,
@@ -4710,6 +4765,7 @@ fn run_solve_solve(
     mut constraints: Constraints,
     constraint: ConstraintSoa,
     function_kind: FunctionKind,
+    step_budget: Option<u64>,
     pending_derives: PendingDerives,
     var_store: VarStore,
     module: Module,
@@ -4785,6 +4841,8 @@ fn run_solve_solve(
             module_params,
             module_params_vars: imported_param_vars,
             host_exposed_symbols: host_exposed_idents,
+            trace_generalization: false,
+            step_budget,
         };
 
         let solve_output = roc_solve::module::run_solve(
@@ -4854,6 +4912,7 @@ fn run_solve<'a>(
     constraints: Constraints,
     constraint: ConstraintSoa,
     function_kind: FunctionKind,
+    step_budget: Option<u64>,
     pending_derives: PendingDerives,
     var_store: VarStore,
     decls: Declarations,
@@ -4889,6 +4948,8 @@ fn run_solve<'a>(
                     constraints,
                     constraint,
                     function_kind,
+                    // the builtins are never pathological
+                    None,
                     pending_derives,
                     var_store,
                     module,
@@ -4922,6 +4983,7 @@ fn run_solve<'a>(
                 constraints,
                 constraint,
                 function_kind,
+                step_budget,
                 pending_derives,
                 var_store,
                 module,
@@ -5122,6 +5184,7 @@ fn canonicalize_and_constrain<'a>(
     exec_mode: ExecutionMode,
     imported_module_params: VecMap<ModuleId, ModuleParams>,
     fx_mode: FxMode,
+    shadow_policy: ShadowPolicy,
 ) -> CanAndCon {
     let canonicalize_start = Instant::now();
 
@@ -5174,6 +5237,7 @@ fn canonicalize_and_constrain<'a>(
         &mut var_store,
         opt_shorthand,
         fx_mode,
+        shadow_policy,
     );
 
     let mut types = Types::new();
@@ -5325,6 +5389,7 @@ fn canonicalize_and_constrain<'a>(
     CanAndCon {
         constrained_module,
         canonicalization_problems: module_output.problems,
+        canonicalization_warnings: module_output.warnings,
         module_docs,
     }
 }
@@ -5345,14 +5410,20 @@ fn parse<'a>(
     let header_import_defs =
         roc_parse::ast::Header::header_imports_to_defs(arena, header.header_imports);
 
-    let parsed_defs = match parse_module_defs(arena, parse_state.clone(), header_import_defs) {
-        Ok(success) => success,
-        Err(fail) => {
-            return Err(LoadingProblem::ParsingFailed(
-                fail.into_file_error(header.module_path, &parse_state),
-            ));
-        }
-    };
+    // Keep parsing after a syntax error, so that all of them are reported at once
+    let (parsed_defs, problems) =
+        parse_module_defs_with_recovery(arena, parse_state.clone(), header_import_defs);
+
+    if !problems.is_empty() {
+        let problems = SourceError {
+            problem: problems,
+            bytes: parse_state.original_bytes(),
+        };
+
+        return Err(LoadingProblem::ParsingFailed(
+            problems.into_file_error(header.module_path),
+        ));
+    }
 
     // SAFETY: By this point we've already incrementally verified that there
     // are no UTF-8 errors in these bytes. If there had been any UTF-8 errors,
@@ -5859,6 +5930,7 @@ fn build_pending_specializations<'a>(
                             body_var: expr_var,
                             // This is a 0-arity thunk, so it cannot be recursive
                             is_self_recursive: false,
+                            region: body.region,
                         };
 
                         procs_base.partial_procs.insert(symbol, proc);
@@ -5995,6 +6067,7 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    region: body.region,
                 };
 
                 procs_base.partial_procs.insert(symbol, proc);
@@ -6063,6 +6136,7 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    region: body.region,
                 };
 
                 // extend the region of the expect expression with the region of the preceding
@@ -6214,6 +6288,7 @@ fn load_derived_partial_procs<'a>(
                     body_var: derived_expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    region: Region::zero(),
                 }
             }
         };
@@ -6286,6 +6361,7 @@ fn run_task<'a>(
             exec_mode,
             imported_module_params,
             fx_mode,
+            shadow_policy,
         } => {
             let can_and_con = canonicalize_and_constrain(
                 arena,
@@ -6300,6 +6376,7 @@ fn run_task<'a>(
                 exec_mode,
                 imported_module_params,
                 fx_mode,
+                shadow_policy,
             );
 
             Ok(Msg::CanonicalizedAndConstrained(can_and_con))
@@ -6312,6 +6389,7 @@ fn run_task<'a>(
             constraints,
             constraint,
             function_kind,
+            step_budget,
             pending_derives,
             var_store,
             ident_ids,
@@ -6333,6 +6411,7 @@ fn run_task<'a>(
             constraints,
             constraint,
             function_kind,
+            step_budget,
             pending_derives,
             var_store,
             declarations,
@@ -6746,7 +6825,7 @@ fn to_unrecognized_package_shorthand_report(
 }
 
 fn to_parse_problem_report<'a>(
-    problem: FileError<'a, SyntaxError<'a>>,
+    problems: FileError<'a, Vec<SyntaxError<'a>>>,
     mut module_ids: ModuleIds,
     all_ident_ids: IdentIdsByModule,
     render: RenderTarget,
@@ -6755,7 +6834,8 @@ fn to_parse_problem_report<'a>(
     use roc_reporting::report::{parse_problem, RocDocAllocator};
 
     // TODO this is not in fact safe
-    let src = unsafe { from_utf8_unchecked(problem.problem.bytes) };
+    let bytes = problems.problem.bytes;
+    let src = unsafe { from_utf8_unchecked(bytes) };
     let src_lines = src.lines().collect::<Vec<_>>();
     // let mut src_lines: Vec<&str> = problem.prefix.lines().collect();
     // src_lines.extend(src.lines().skip(1));
@@ -6774,17 +6854,25 @@ fn to_parse_problem_report<'a>(
 
     let lines = LineInfo::new(src);
 
-    let report = parse_problem(
-        &alloc,
-        &lines,
-        problem.filename.clone(),
-        starting_line,
-        problem,
-    );
-
     let mut buf = String::new();
 
-    report.render(render, &mut buf, &alloc, &palette);
+    for (index, problem) in problems.problem.problem.into_iter().enumerate() {
+        if index > 0 {
+            buf.push_str("\n\n");
+        }
+
+        let problem = SourceError { problem, bytes }.into_file_error(problems.filename.clone());
+
+        let report = parse_problem(
+            &alloc,
+            &lines,
+            problems.filename.clone(),
+            starting_line,
+            problem,
+        );
+
+        report.render(render, &mut buf, &alloc, &palette);
+    }
 
     buf
 }
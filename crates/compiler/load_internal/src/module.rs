@@ -34,6 +34,7 @@ pub struct LoadedModule {
     pub interns: Interns,
     pub solved: Solved<Subs>,
     pub can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    pub can_warnings: MutMap<ModuleId, Vec<roc_problem::can::Warning>>,
     pub type_problems: MutMap<ModuleId, Vec<TypeError>>,
     pub declarations_by_id: MutMap<ModuleId, Declarations>,
     pub exposed_to_host: MutMap<Symbol, Variable>,
@@ -69,6 +70,10 @@ impl LoadedModule {
             total += problems.len();
         }
 
+        for warnings in self.can_warnings.values() {
+            total += warnings.len();
+        }
+
         for problems in self.type_problems.values() {
             total += problems.len();
         }
@@ -176,6 +181,7 @@ pub struct MonomorphizedModule<'a> {
     pub subs: Subs,
     pub layout_interner: STLayoutInterner<'a>,
     pub can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    pub can_warnings: MutMap<ModuleId, Vec<roc_problem::can::Warning>>,
     pub type_problems: MutMap<ModuleId, Vec<TypeError>>,
     pub procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
     pub host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
@@ -42,6 +42,7 @@ pub(crate) struct ModuleCache<'a> {
     pub(crate) top_level_thunks: MutMap<ModuleId, MutSet<Symbol>>,
     pub(crate) documentation: VecMap<ModuleId, ModuleDocumentation>,
     pub(crate) can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    pub(crate) can_warnings: MutMap<ModuleId, Vec<roc_problem::can::Warning>>,
     pub(crate) type_problems: MutMap<ModuleId, Vec<TypeError>>,
 
     pub(crate) sources: MutMap<ModuleId, (PathBuf, &'a str)>,
@@ -115,6 +116,7 @@ impl Default for ModuleCache<'_> {
             top_level_thunks: Default::default(),
             documentation: Default::default(),
             can_problems: Default::default(),
+            can_warnings: Default::default(),
             type_problems: Default::default(),
             sources: Default::default(),
         }
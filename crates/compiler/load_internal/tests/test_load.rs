@@ -17,6 +17,7 @@ mod helpers;
 use crate::helpers::fixtures_dir;
 use bumpalo::Bump;
 use roc_can::module::ExposedByModule;
+use roc_can::scope::ShadowPolicy;
 use roc_load_internal::docs::DocDef;
 use roc_load_internal::file::{
     ExecutionMode, LoadConfig, LoadResult, LoadStart, LoadingProblem, Threading,
@@ -25,9 +26,9 @@ use roc_load_internal::module::LoadedModule;
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{Interns, ModuleId};
 use roc_packaging::cache::RocCacheDir;
-use roc_problem::can::Problem;
+use roc_problem::can::{Problem, Warning};
 use roc_region::all::LineInfo;
-use roc_reporting::report::{can_problem, DEFAULT_PALETTE};
+use roc_reporting::report::{can_reports, DEFAULT_PALETTE};
 use roc_reporting::report::{strip_colors, RenderTarget};
 use roc_reporting::report::{type_problem, RocDocAllocator};
 use roc_solve::FunctionKind;
@@ -59,6 +60,8 @@ fn load_and_typecheck(
     let load_config = LoadConfig {
         target,
         function_kind,
+        step_budget: None,
+        shadow_policy: ShadowPolicy::Disallow,
         render: RenderTarget::Generic,
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
@@ -84,6 +87,7 @@ const TARGET: Target = Target::LinuxX64;
 
 fn format_can_problems(
     problems: Vec<Problem>,
+    warnings: Vec<Warning>,
     home: ModuleId,
     interns: &Interns,
     filename: PathBuf,
@@ -94,9 +98,9 @@ fn format_can_problems(
     let src_lines: Vec<&str> = src.split('\n').collect();
     let lines = LineInfo::new(src);
     let alloc = RocDocAllocator::new(&src_lines, home, interns);
-    let reports = problems
+    let reports = can_reports(&alloc, &lines, filename, problems, warnings)
         .into_iter()
-        .map(|problem| can_problem(&alloc, &lines, filename.clone(), problem).pretty(&alloc));
+        .map(|report| report.pretty(&alloc));
 
     let mut buf = String::new();
     alloc
@@ -148,9 +152,11 @@ fn multiple_modules(subdir: &str, files: Vec<(&str, &str)>) -> Result<LoadedModu
             let (filepath, src) = loaded_module.sources.get(&home).unwrap();
 
             let can_problems = loaded_module.can_problems.remove(&home).unwrap_or_default();
-            if !can_problems.is_empty() {
+            let can_warnings = loaded_module.can_warnings.remove(&home).unwrap_or_default();
+            if !can_problems.is_empty() || !can_warnings.is_empty() {
                 return Err(format_can_problems(
                     can_problems,
+                    can_warnings,
                     home,
                     &loaded_module.interns,
                     filepath.clone(),
@@ -258,11 +264,13 @@ fn load_fixture(
 
     let (filepath, src) = loaded_module.sources.get(&home).unwrap();
     let can_problems = loaded_module.can_problems.remove(&home).unwrap_or_default();
-    if !can_problems.is_empty() {
+    let can_warnings = loaded_module.can_warnings.remove(&home).unwrap_or_default();
+    if !can_problems.is_empty() || !can_warnings.is_empty() {
         panic!(
             "{}",
             format_can_problems(
                 can_problems,
+                can_warnings,
                 home,
                 &loaded_module.interns,
                 filepath.clone(),
@@ -299,6 +307,10 @@ fn expect_types(mut loaded_module: LoadedModule, mut expected_types: HashMap<&st
         loaded_module.can_problems.remove(&home).unwrap_or_default(),
         Vec::new()
     );
+    assert_eq!(
+        loaded_module.can_warnings.remove(&home).unwrap_or_default(),
+        Vec::new()
+    );
     assert!(loaded_module
         .type_problems
         .remove(&home)
@@ -433,6 +445,10 @@ fn module_with_deps() {
         loaded_module.can_problems.remove(&home).unwrap_or_default(),
         Vec::new()
     );
+    assert_eq!(
+        loaded_module.can_warnings.remove(&home).unwrap_or_default(),
+        Vec::new()
+    );
     assert!(loaded_module
         .type_problems
         .remove(&home)
@@ -750,27 +766,46 @@ fn parse_problem() {
             report,
             indoc!(
                 "
-                    ── UNFINISHED LIST in tmp/parse_problem/Main.roc ───────────────────────────────
+                    ── UNCLOSED DELIMITER in tmp/parse_problem/Main.roc ────────────────────────────
 
-                    I am partway through started parsing a list, but I got stuck here:
+                    This delimiter is never closed:
 
                     3│  main = [
-                    4│
-                    5│
-                        ^
-
-                    I was expecting to see a closing square bracket before this, so try
-                    adding a ] and see if that helps?
+                               ^
 
-                    Note: When I get stuck like this, it usually means that there is a
-                    missing parenthesis or bracket somewhere earlier. It could also be a
-                    stray keyword or operator."
+                    I treated it as closed right before the next top-level definition, so
+                    I could keep looking for other problems."
             )
         ),
         Ok(_) => unreachable!("we expect failure here"),
     }
 }
 
+#[test]
+fn parse_problems_are_all_reported() {
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r"
+                module [main, other]
+
+                main = [1, 2
+
+                other = { x: 1
+                "
+        ),
+    )];
+
+    match multiple_modules("parse_problems_are_all_reported", modules) {
+        Err(report) => {
+            assert_eq!(report.matches("UNCLOSED DELIMITER").count(), 2, "{report}");
+            assert!(report.contains("3│  main = [1, 2"), "{report}");
+            assert!(report.contains("5│  other = { x: 1"), "{report}");
+        }
+        Ok(_) => unreachable!("we expect failure here"),
+    }
+}
+
 #[test]
 #[should_panic(expected = "FILE NOT FOUND")]
 fn file_not_found() {
@@ -915,6 +950,15 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
         err,
         indoc!(
             r"
+                ── UNUSED IMPORT in ...aque_wrapped_unwrapped_outside_defining_module/Main.roc ─
+
+                Age is imported but not used.
+
+                3│  import Age exposing [Age]
+                    ^^^^^^^^^^^^^^^^^^^^^^^^^
+
+                Since Age isn't used, you don't need to import it.
+
                 ── OPAQUE TYPE DECLARED OUTSIDE SCOPE in ...d_outside_defining_module/Main.roc ─
 
                 The unwrapped opaque type Age referenced here:
@@ -942,15 +986,6 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
                                          ^^^
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
-
-                ── UNUSED IMPORT in ...aque_wrapped_unwrapped_outside_defining_module/Main.roc ─
-
-                Age is imported but not used.
-
-                3│  import Age exposing [Age]
-                    ^^^^^^^^^^^^^^^^^^^^^^^^^
-
-                Since Age isn't used, you don't need to import it.
                 "
         ),
         "\n{}",
@@ -1032,48 +1067,48 @@ fn unused_imports() {
             r"
             ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
 
-            Dep2 is imported but not used.
+            Dep1 is imported but not used.
 
-            11│      import Dep2
-                     ^^^^^^^^^^^
+            3│  import Dep1
+                ^^^^^^^^^^^
 
-            Since Dep2 isn't used, you don't need to import it.
+            Since Dep1 isn't used, you don't need to import it.
 
             ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
 
-            Dep2 is imported but not used.
+            `Dep3.Three` is not used in this module.
 
-            15│      import Dep2 exposing [two]
-                     ^^^^^^^^^^^^^^^^^^^^^^^^^^
+            4│  import Dep3 exposing [Three]
+                                      ^^^^^
 
-            Since Dep2 isn't used, you don't need to import it.
+            Since `Dep3.Three` isn't used, you don't need to import it.
 
             ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
 
             Dep2 is imported but not used.
 
-            22│      import Dep2 as D2
-                     ^^^^^^^^^^^^^^^^^
+            11│      import Dep2
+                     ^^^^^^^^^^^
 
             Since Dep2 isn't used, you don't need to import it.
 
             ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
 
-            Dep1 is imported but not used.
+            Dep2 is imported but not used.
 
-            3│  import Dep1
-                ^^^^^^^^^^^
+            15│      import Dep2 exposing [two]
+                     ^^^^^^^^^^^^^^^^^^^^^^^^^^
 
-            Since Dep1 isn't used, you don't need to import it.
+            Since Dep2 isn't used, you don't need to import it.
 
             ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
 
-            `Dep3.Three` is not used in this module.
+            Dep2 is imported but not used.
 
-            4│  import Dep3 exposing [Three]
-                                      ^^^^^
+            22│      import Dep2 as D2
+                     ^^^^^^^^^^^^^^^^^
 
-            Since `Dep3.Three` isn't used, you don't need to import it.
+            Since Dep2 isn't used, you don't need to import it.
             "
         ),
         "\n{}",
@@ -1223,6 +1258,58 @@ fn explicit_builtin_type_import() {
     );
 }
 
+#[test]
+fn import_shadows_builtin() {
+    let modules = vec![
+        (
+            "Table.roc",
+            indoc!(
+                r#"
+                module [Dict, empty]
+
+                Dict := List (Str, Str)
+
+                empty : Dict
+                empty = @Dict []
+                "#
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r#"
+                module [main]
+
+                import Table exposing [Dict]
+
+                main : Dict
+                main = Table.empty
+                "#
+            ),
+        ),
+    ];
+    let err = multiple_modules("import_shadows_builtin", modules).unwrap_err();
+    assert_eq!(
+        err,
+        indoc!(
+            r"
+            ── IMPORT SHADOWS BUILTIN in tmp/import_shadows_builtin/Main.roc ───────────────
+
+            This import exposes `Table.Dict`:
+
+            3│  import Table exposing [Dict]
+                                       ^^^^
+
+            It replaces the builtin `Dict.Dict`, which is otherwise always in scope.
+            From here on, `Dict` refers to the imported one.
+
+            To keep the builtin, stop exposing `Dict` in this import and write
+            `Table.Dict` wherever you mean the imported one.
+            "
+        )
+    );
+}
+
 #[test]
 fn import_shadows_symbol() {
     let modules = vec![
@@ -1256,6 +1343,15 @@ fn import_shadows_symbol() {
         err,
         indoc!(
             r"
+            ── UNUSED IMPORT in tmp/import_shadows_symbol/Main.roc ─────────────────────────
+
+            One is imported but not used.
+
+            5│  import One exposing [one]
+                ^^^^^^^^^^^^^^^^^^^^^^^^^
+
+            Since One isn't used, you don't need to import it.
+
             ── DUPLICATE NAME in tmp/import_shadows_symbol/Main.roc ────────────────────────
 
             This import exposes `One.one`:
@@ -1269,15 +1365,6 @@ fn import_shadows_symbol() {
                 ^^^
 
             You can rename it, or use the qualified name: `One.one`
-
-            ── UNUSED IMPORT in tmp/import_shadows_symbol/Main.roc ─────────────────────────
-
-            One is imported but not used.
-
-            5│  import One exposing [one]
-                ^^^^^^^^^^^^^^^^^^^^^^^^^
-
-            Since One isn't used, you don't need to import it.
             "
         )
     );
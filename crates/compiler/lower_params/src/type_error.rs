@@ -31,7 +31,7 @@ pub fn remove_module_param_arguments(
 
     for error in errors {
         match error {
-            TypeError::BadExpr(_, _, found, Expected::ForReason(reason, expected, _)) => {
+            TypeError::BadExpr(_, _, found, Expected::ForReason(reason, expected, _), _) => {
                 remove_for_reason(&env, found, reason, expected);
             }
 
@@ -48,6 +48,7 @@ pub fn remove_module_param_arguments(
                     _,
                     expected,
                 ),
+                _,
             ) if env.is_extended(name) => {
                 if *arity > 1 {
                     *arity -= 1;
@@ -71,8 +72,8 @@ pub fn remove_module_param_arguments(
                 }
             }
 
-            TypeError::BadExpr(_, _, _, Expected::FromAnnotation(_, _, _, _))
-            | TypeError::BadExpr(_, _, _, Expected::NoExpectation(_)) => {}
+            TypeError::BadExpr(_, _, _, Expected::FromAnnotation(_, _, _, _), _)
+            | TypeError::BadExpr(_, _, _, Expected::NoExpectation(_), _) => {}
 
             // Irrelevant
             TypeError::BadPattern(_, _, _, _)
@@ -105,7 +106,8 @@ pub fn remove_module_param_arguments(
             | TypeError::ExpectedEffectful(_, _)
             | TypeError::UnsuffixedEffectfulFunction(_, _)
             | TypeError::SuffixedPureFunction(_, _)
-            | TypeError::InvalidTryTarget(_, _, _) => {}
+            | TypeError::InvalidTryTarget(_, _, _)
+            | TypeError::BudgetExceeded(_) => {}
         }
     }
 }
@@ -3,15 +3,17 @@ use self::BinOp::*;
 use std::cmp::Ordering;
 use std::fmt;
 
-const PRECEDENCES: [(BinOp, u8); 17] = [
-    (Caret, 8),
-    (Star, 7),
-    (Slash, 7),
-    (DoubleSlash, 6),
-    (Percent, 6),
-    (Plus, 5),
-    (Minus, 5),
-    (DoubleQuestion, 5),
+const PRECEDENCES: [(BinOp, u8); 19] = [
+    (Caret, 9),
+    (Star, 8),
+    (Slash, 8),
+    (DoubleSlash, 7),
+    (Percent, 7),
+    (Plus, 6),
+    (Minus, 6),
+    (DoubleQuestion, 6),
+    (DoubleDot, 5),
+    (DoubleDotEquals, 5),
     (Pizza, 4),
     (Equals, 3),
     (NotEquals, 3),
@@ -23,7 +25,7 @@ const PRECEDENCES: [(BinOp, u8); 17] = [
     (Or, 0),
 ];
 
-const ASSOCIATIVITIES: [(BinOp, Associativity); 17] = [
+const ASSOCIATIVITIES: [(BinOp, Associativity); 19] = [
     (Caret, RightAssociative),
     (Star, LeftAssociative),
     (Slash, LeftAssociative),
@@ -32,6 +34,8 @@ const ASSOCIATIVITIES: [(BinOp, Associativity); 17] = [
     (Plus, LeftAssociative),
     (Minus, LeftAssociative),
     (DoubleQuestion, LeftAssociative),
+    (DoubleDot, NonAssociative),
+    (DoubleDotEquals, NonAssociative),
     (Pizza, LeftAssociative),
     (Equals, NonAssociative),
     (NotEquals, NonAssociative),
@@ -43,7 +47,7 @@ const ASSOCIATIVITIES: [(BinOp, Associativity); 17] = [
     (Or, RightAssociative),
 ];
 
-const DISPLAY_STRINGS: [(BinOp, &str); 17] = [
+const DISPLAY_STRINGS: [(BinOp, &str); 19] = [
     (Caret, "^"),
     (Star, "*"),
     (Slash, "/"),
@@ -52,6 +56,8 @@ const DISPLAY_STRINGS: [(BinOp, &str); 17] = [
     (Plus, "+"),
     (Minus, "-"),
     (DoubleQuestion, "??"),
+    (DoubleDot, ".."),
+    (DoubleDotEquals, "..="),
     (Pizza, "|>"),
     (Equals, "=="),
     (NotEquals, "!="),
@@ -158,6 +164,8 @@ pub enum BinOp {
     Plus,
     Minus,
     DoubleQuestion,
+    DoubleDot,
+    DoubleDotEquals,
     Pizza,
     Equals,
     NotEquals,
@@ -176,7 +184,8 @@ impl BinOp {
         match self {
             Caret | Star | Slash | Percent | Plus | Minus | LessThan | GreaterThan => 1,
             DoubleSlash | Equals | NotEquals | LessThanOrEq | GreaterThanOrEq | And | Or
-            | Pizza | DoubleQuestion => 2,
+            | Pizza | DoubleQuestion | DoubleDot => 2,
+            DoubleDotEquals => 3,
         }
     }
 }
@@ -205,18 +214,19 @@ pub enum Associativity {
     /// non-associative operators:
     ///
     /// comparison: == > >= < <=
+    /// range: .. ..=
     NonAssociative,
 }
 
 impl BinOp {
     pub fn associativity(self) -> Associativity {
-        const ASSOCIATIVITY_TABLE: [Associativity; 17] = generate_associativity_table();
+        const ASSOCIATIVITY_TABLE: [Associativity; 19] = generate_associativity_table();
 
         ASSOCIATIVITY_TABLE[self as usize]
     }
 
     fn precedence(self) -> u8 {
-        const PRECEDENCE_TABLE: [u8; 17] = generate_precedence_table();
+        const PRECEDENCE_TABLE: [u8; 19] = generate_precedence_table();
 
         PRECEDENCE_TABLE[self as usize]
     }
@@ -236,14 +246,14 @@ impl Ord for BinOp {
 
 impl std::fmt::Display for BinOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        const DISPLAY_TABLE: [&str; 17] = generate_display_table();
+        const DISPLAY_TABLE: [&str; 19] = generate_display_table();
 
         write!(f, "{}", DISPLAY_TABLE[*self as usize])
     }
 }
 
-const fn generate_precedence_table() -> [u8; 17] {
-    let mut table = [0u8; 17];
+const fn generate_precedence_table() -> [u8; 19] {
+    let mut table = [0u8; 19];
     let mut i = 0;
 
     while i < PRECEDENCES.len() {
@@ -254,8 +264,8 @@ const fn generate_precedence_table() -> [u8; 17] {
     table
 }
 
-const fn generate_associativity_table() -> [Associativity; 17] {
-    let mut table = [NonAssociative; 17];
+const fn generate_associativity_table() -> [Associativity; 19] {
+    let mut table = [NonAssociative; 19];
     let mut i = 0;
 
     while i < ASSOCIATIVITIES.len() {
@@ -266,8 +276,8 @@ const fn generate_associativity_table() -> [Associativity; 17] {
     table
 }
 
-const fn generate_display_table() -> [&'static str; 17] {
-    let mut table = [""; 17];
+const fn generate_display_table() -> [&'static str; 19] {
+    let mut table = [""; 19];
     let mut i = 0;
 
     while i < DISPLAY_STRINGS.len() {
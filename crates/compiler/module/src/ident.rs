@@ -19,6 +19,92 @@ impl Ident {
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    /// Create an unqualified identifier, checking that it follows the same rules the parser uses:
+    /// it starts with a letter, continues with letters, ASCII digits and underscores, and a
+    /// lowercase identifier may end in a single `!`.
+    ///
+    /// Uppercase identifiers (type, tag and module names) and lowercase ones (values, fields)
+    /// are both accepted; use `is_uppercase` on the first char to tell them apart.
+    pub fn try_parse(string: &str) -> Result<Ident, IdentError> {
+        let mut chars = string.char_indices();
+
+        let starts_lowercase = match chars.next() {
+            None => return Err(IdentError::Empty),
+            Some((_, ch)) if ch.is_ascii_digit() => return Err(IdentError::StartsWithDigit),
+            Some((_, ch)) if ch.is_alphabetic() => ch.is_lowercase(),
+            Some((offset, ch)) => return Err(IdentError::InvalidChar(ch, offset)),
+        };
+
+        for (offset, ch) in chars {
+            let is_final_bang = ch == '!' && starts_lowercase && offset + 1 == string.len();
+
+            // We can't use ch.is_alphanumeric() here because that passes for
+            // things that are "numeric" but not ASCII digits, like `¾`
+            if !(ch.is_alphabetic() || ch.is_ascii_digit() || ch == '_' || is_final_bang) {
+                return Err(IdentError::InvalidChar(ch, offset));
+            }
+        }
+
+        Ok(Ident::from(string))
+    }
+}
+
+/// Why a string is not a valid identifier, see `Ident::try_parse` and `parse_qualified`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdentError {
+    Empty,
+    StartsWithDigit,
+    /// A char that may not appear at this byte offset
+    InvalidChar(char, usize),
+    /// A segment of a module path, starting at this byte offset, is not capitalized
+    LowercaseModuleName(usize),
+}
+
+/// Split a possibly-qualified name like `Json.Core.decode` into its module path
+/// (`Json`, `Core`) and the final identifier (`decode`). A bare identifier has an empty path.
+///
+/// Every segment of the path must be an uppercase name; the final identifier follows the rules
+/// of `Ident::try_parse`. Byte offsets in the error are relative to the whole string.
+pub fn parse_qualified(string: &str) -> Result<(Vec<ModuleName>, Ident), IdentError> {
+    if string.is_empty() {
+        return Err(IdentError::Empty);
+    }
+
+    let mut module_path = Vec::new();
+    let mut start = 0;
+
+    loop {
+        let rest = &string[start..];
+        let (segment, is_last) = match rest.find('.') {
+            Some(end) => (&rest[..end], false),
+            None => (rest, true),
+        };
+
+        if segment.is_empty() {
+            // a trailing dot is the one before this segment; a leading or
+            // doubled dot is the one at the start of this segment
+            let offset = if is_last { start - 1 } else { start };
+
+            return Err(IdentError::InvalidChar('.', offset));
+        }
+
+        let ident = Ident::try_parse(segment).map_err(|error| match error {
+            IdentError::InvalidChar(ch, offset) => IdentError::InvalidChar(ch, start + offset),
+            other => other,
+        })?;
+
+        if is_last {
+            return Ok((module_path, ident));
+        }
+
+        if !segment.starts_with(char::is_uppercase) {
+            return Err(IdentError::LowercaseModuleName(start));
+        }
+
+        module_path.push(ModuleName::from(segment));
+        start += segment.len() + 1;
+    }
 }
 
 pub struct QualifiedModuleName<'a> {
@@ -430,3 +516,145 @@ mod suffix_test {
         IdentSuffix::from_name("");
     }
 }
+
+#[cfg(test)]
+mod try_parse_test {
+    use crate::ident::{Ident, IdentError};
+
+    #[test]
+    fn lowercase_and_uppercase() {
+        assert_eq!(Ident::try_parse("foo"), Ok(Ident::from("foo")));
+        assert_eq!(Ident::try_parse("Foo"), Ok(Ident::from("Foo")));
+        assert_eq!(Ident::try_parse("foo_bar2"), Ok(Ident::from("foo_bar2")));
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(Ident::try_parse("Járnak"), Ok(Ident::from("Járnak")));
+        assert_eq!(Ident::try_parse("bögre"), Ok(Ident::from("bögre")));
+        assert_eq!(Ident::try_parse("Ünnep"), Ok(Ident::from("Ünnep")));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Ident::try_parse(""), Err(IdentError::Empty));
+    }
+
+    #[test]
+    fn starts_with_digit() {
+        assert_eq!(Ident::try_parse("1st"), Err(IdentError::StartsWithDigit));
+    }
+
+    #[test]
+    fn invalid_start() {
+        assert_eq!(
+            Ident::try_parse("_foo"),
+            Err(IdentError::InvalidChar('_', 0))
+        );
+    }
+
+    #[test]
+    fn internal_space() {
+        assert_eq!(
+            Ident::try_parse("foo bar"),
+            Err(IdentError::InvalidChar(' ', 3))
+        );
+    }
+
+    #[test]
+    fn offset_is_in_bytes() {
+        assert_eq!(
+            Ident::try_parse("Járnak¾"),
+            Err(IdentError::InvalidChar('¾', 7))
+        );
+    }
+
+    #[test]
+    fn trailing_bang() {
+        assert_eq!(Ident::try_parse("foo!"), Ok(Ident::from("foo!")));
+        assert_eq!(
+            Ident::try_parse("Foo!"),
+            Err(IdentError::InvalidChar('!', 3))
+        );
+        assert_eq!(
+            Ident::try_parse("foo!bar"),
+            Err(IdentError::InvalidChar('!', 3))
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_qualified_test {
+    use crate::ident::{parse_qualified, Ident, IdentError, ModuleName};
+
+    #[test]
+    fn bare_identifier() {
+        assert_eq!(
+            parse_qualified("decode"),
+            Ok((vec![], Ident::from("decode")))
+        );
+    }
+
+    #[test]
+    fn module_path() {
+        assert_eq!(
+            parse_qualified("Json.Core.decode"),
+            Ok((
+                vec![ModuleName::from("Json"), ModuleName::from("Core")],
+                Ident::from("decode")
+            ))
+        );
+    }
+
+    #[test]
+    fn qualified_type_name() {
+        assert_eq!(
+            parse_qualified("Dict.Dict"),
+            Ok((vec![ModuleName::from("Dict")], Ident::from("Dict")))
+        );
+    }
+
+    #[test]
+    fn leading_dot() {
+        assert_eq!(
+            parse_qualified(".decode"),
+            Err(IdentError::InvalidChar('.', 0))
+        );
+    }
+
+    #[test]
+    fn trailing_dot() {
+        assert_eq!(
+            parse_qualified("Json.Core."),
+            Err(IdentError::InvalidChar('.', 9))
+        );
+    }
+
+    #[test]
+    fn doubled_dot() {
+        assert_eq!(
+            parse_qualified("Json..decode"),
+            Err(IdentError::InvalidChar('.', 5))
+        );
+    }
+
+    #[test]
+    fn lowercase_module_name() {
+        assert_eq!(
+            parse_qualified("Json.core.decode"),
+            Err(IdentError::LowercaseModuleName(5))
+        );
+    }
+
+    #[test]
+    fn invalid_tail() {
+        assert_eq!(
+            parse_qualified("Json.Core.de code"),
+            Err(IdentError::InvalidChar(' ', 12))
+        );
+        assert_eq!(
+            parse_qualified("Json.1st"),
+            Err(IdentError::StartsWithDigit)
+        );
+    }
+}
@@ -211,6 +211,8 @@ pub struct PartialProc<'a> {
     pub body: roc_can::expr::Expr,
     pub body_var: Variable,
     pub is_self_recursive: bool,
+    /// Where the body of this function is in the source code of its module
+    pub region: Region,
 }
 
 impl<'a> PartialProc<'a> {
@@ -225,6 +227,7 @@ impl<'a> PartialProc<'a> {
         ret_var: Variable,
     ) -> PartialProc<'a> {
         let number_of_arguments = loc_args.len();
+        let region = loc_body.region;
 
         match patterns_to_when(env, loc_args, ret_var, loc_body) {
             Ok((_, pattern_symbols, body)) => {
@@ -240,6 +243,7 @@ impl<'a> PartialProc<'a> {
                     body: body.value,
                     body_var: ret_var,
                     is_self_recursive,
+                    region,
                 }
             }
 
@@ -257,6 +261,7 @@ impl<'a> PartialProc<'a> {
                     body: roc_can::expr::Expr::RuntimeError(error.value),
                     body_var: ret_var,
                     is_self_recursive: false,
+                    region,
                 }
             }
         }
@@ -309,6 +314,9 @@ pub struct Proc<'a> {
     pub ret_layout: InLayout<'a>,
     pub is_self_recursive: SelfRecursive,
     pub is_erased: bool,
+    /// Where this procedure came from in the source code of the module that defines `name`.
+    /// [Region::zero] for procedures the compiler generated itself.
+    pub region: Region,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -1110,6 +1118,7 @@ impl<'a> Procs<'a> {
                                         body: body.value,
                                         body_var: ret_var,
                                         is_self_recursive,
+                                        region: body.region,
                                     };
 
                                     self.partial_procs.insert(name.name(), partial_proc);
@@ -1138,6 +1147,7 @@ impl<'a> Procs<'a> {
                                     body: body.value,
                                     body_var: ret_var,
                                     is_self_recursive,
+                                    region: body.region,
                                 };
 
                                 self.partial_procs.insert(name.name(), partial_proc)
@@ -3333,6 +3343,7 @@ fn generate_runtime_error_function<'a>(
         ret_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased,
+        region: Region::zero(),
     }
 }
 
@@ -3428,6 +3439,7 @@ fn generate_host_exposed_function<'a>(
                 ret_layout: result,
                 is_self_recursive: SelfRecursive::NotSelfRecursive,
                 is_erased: false,
+                region: Region::zero(),
             };
 
             let top_level = ProcLayout::from_raw_named(env.arena, lambda_name, layout);
@@ -3492,6 +3504,7 @@ fn generate_host_exposed_lambda_set<'a>(
         ret_layout: return_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased: false,
+        region: Region::zero(),
     };
 
     let top_level = ProcLayout::new(
@@ -3518,6 +3531,7 @@ fn specialize_proc_help<'a>(
 ) -> Result<Proc<'a>, LayoutProblem> {
     let partial_proc = procs.partial_procs.get_id(partial_proc_id);
     let captured_symbols = partial_proc.captured_symbols;
+    let region = partial_proc.region;
 
     let _unified = env.unify(
         procs.externals_we_need.values_mut(),
@@ -3586,6 +3600,7 @@ fn specialize_proc_help<'a>(
                 ret_layout,
                 is_self_recursive: recursivity,
                 is_erased,
+                region,
             }
         }
         SpecializedLayout::FunctionBody {
@@ -3801,6 +3816,7 @@ fn specialize_proc_help<'a>(
                 ret_layout,
                 is_self_recursive: recursivity,
                 is_erased,
+                region,
             }
         }
     };
@@ -10141,6 +10157,7 @@ fn find_lambda_sets_help(
                     }
                 }
                 FlatType::EmptyRecord => {}
+                FlatType::EmptyTuple => {}
                 FlatType::EmptyTagUnion => {}
                 FlatType::EffectfulFunc => {}
             },
@@ -10375,6 +10392,7 @@ where
             ret_layout: *field,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            region: Region::zero(),
         };
 
         answer.push(GlueProc {
@@ -10470,6 +10488,7 @@ where
             ret_layout: *field,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            region: Region::zero(),
         };
 
         answer.push(GlueProc {
@@ -5,13 +5,14 @@ use crate::layout::{
 };
 use bumpalo::collections::Vec;
 use roc_builtins::bitcode::{FloatWidth, IntWidth};
-use roc_collections::all::{BumpMap, BumpMapDefault};
+use roc_collections::all::{BumpMap, BumpMapDefault, SendMap};
 use roc_error_macros::internal_error;
 use roc_exhaustive::{Ctor, CtorName, ListArity, RenderAs, TagId};
 use roc_module::ident::{Lowercase, TagName};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::Symbol;
 use roc_problem::can::{RuntimeError, ShadowKind};
+use roc_region::all::{Loc, Region};
 use roc_types::subs::Variable;
 
 use super::literal::{make_num_literal, IntOrFloatValue};
@@ -936,6 +937,7 @@ fn from_can_pattern_help<'a>(
         RecordDestructure {
             whole_var,
             destructs,
+            opt_rest,
             ..
         } => {
             // sorted fields based on the type
@@ -953,6 +955,9 @@ fn from_can_pattern_help<'a>(
 
             let mut field_layouts = Vec::with_capacity_in(sorted_fields.len(), env.arena);
 
+            // the fields that are not destructured, which make up the `..rest` (if any)
+            let mut rest_fields = SendMap::default();
+
             // next we step through both sequences of fields. The outer loop is the sequence based
             // on the type, since not all fields need to actually be destructured in the source
             // language.
@@ -979,6 +984,29 @@ fn from_can_pattern_help<'a>(
                                     assignments,
                                 )?);
                             }
+                            None if opt_rest.is_some() => {
+                                // this field is not destructured by the pattern, but
+                                // is part of the rest; bind it so the rest can use it
+                                let symbol = env.unique_symbol();
+
+                                mono_destructs.push(RecordDestruct {
+                                    label: label.clone(),
+                                    variable,
+                                    layout: field_layout,
+                                    typ: DestructType::Required(symbol),
+                                });
+
+                                rest_fields.insert(
+                                    label.clone(),
+                                    roc_can::expr::Field {
+                                        var: variable,
+                                        region: Region::zero(),
+                                        loc_expr: Box::new(Loc::at_zero(roc_can::expr::Expr::Var(
+                                            symbol, variable,
+                                        ))),
+                                    },
+                                );
+                            }
                             None => {
                                 // this field is not destructured by the pattern
                                 // put in an underscore
@@ -1045,6 +1073,19 @@ fn from_can_pattern_help<'a>(
                 }
             }
 
+            if let Some((rest_var, rest_sym)) = opt_rest {
+                let rest_expr = if rest_fields.is_empty() {
+                    roc_can::expr::Expr::EmptyRecord
+                } else {
+                    roc_can::expr::Expr::Record {
+                        record_var: *rest_var,
+                        fields: rest_fields,
+                    }
+                };
+
+                assignments.push((rest_sym.value, *rest_var, rest_expr));
+            }
+
             Ok(Pattern::RecordDestructure(
                 mono_destructs,
                 field_layouts.into_bump_slice(),
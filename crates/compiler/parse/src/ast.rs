@@ -1274,6 +1274,18 @@ impl<'a> Defs<'a> {
             })
     }
 
+    /// The doc comment right above the def at the given index, if it has one.
+    /// Its lines can end up in the spaces after the previous def, too.
+    pub fn doc_comment(&self, index: usize) -> Option<std::string::String> {
+        let after_previous = match index.checked_sub(1) {
+            Some(previous) => &self.spaces[self.space_after[previous].indices()],
+            None => &[][..],
+        };
+        let before = &self.spaces[self.space_before[index].indices()];
+
+        docs_from_spaces(after_previous.iter().chain(before))
+    }
+
     pub fn list_value_defs(&self) -> impl Iterator<Item = (usize, &ValueDef<'a>)> {
         self.tags
             .iter()
@@ -1718,6 +1730,32 @@ impl<'a> CommentOrNewline<'a> {
     }
 }
 
+/// The contiguous `##` lines at the end of these spaces, one doc line per line.
+/// A blank line or a regular comment after a doc comment detaches it.
+pub fn docs_from_spaces<'a, 'b: 'a>(
+    spaces: impl IntoIterator<Item = &'a CommentOrNewline<'b>>,
+) -> Option<std::string::String> {
+    let mut docs = std::string::String::new();
+
+    for comment_or_new_line in spaces {
+        match comment_or_new_line {
+            CommentOrNewline::DocComment(doc_str) => {
+                docs.push_str(doc_str);
+                docs.push('\n');
+            }
+            CommentOrNewline::Newline | CommentOrNewline::LineComment(_) => {
+                docs.clear();
+            }
+        }
+    }
+
+    if docs.is_empty() {
+        None
+    } else {
+        Some(docs)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct PatternAs<'a> {
     pub spaces_before: &'a [CommentOrNewline<'a>],
@@ -1760,6 +1798,10 @@ pub enum Pattern<'a> {
     /// Can only occur inside of a RecordDestructure
     OptionalField(&'a str, &'a Loc<Expr<'a>>),
 
+    /// A record-rest pattern, e.g. { x, ..rest } -> ... or { x, .. } -> ...
+    /// Can only occur inside of a RecordDestructure
+    RecordRest(Option<&'a str>),
+
     // Literal
     NumLiteral(&'a str),
     NonBase10Literal {
@@ -1912,6 +1954,13 @@ impl<'a> Pattern<'a> {
                     false
                 }
             }
+            RecordRest(x) => {
+                if let RecordRest(y) = other {
+                    x == y
+                } else {
+                    false
+                }
+            }
             SpaceBefore(x, _) | SpaceAfter(x, _) => match other {
                 SpaceBefore(y, _) | SpaceAfter(y, _) => x.equivalent(y),
                 y => x.equivalent(y),
@@ -2632,6 +2681,7 @@ impl<'a> Malformed for Pattern<'a> {
             RecordDestructure(items) => items.iter().any(|item| item.is_malformed()),
             RequiredField(_, pat) => pat.is_malformed(),
             OptionalField(_, expr) => expr.is_malformed(),
+            RecordRest(_) => false,
 
             NumLiteral(_) |
             NonBase10Literal { .. } |
@@ -156,7 +156,7 @@ fn loc_expr_in_parens_etc_help<'a>() -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>
 fn record_field_access_chain<'a>() -> impl Parser<'a, Vec<'a, Suffix<'a>>, EExpr<'a>> {
     zero_or_more(one_of!(
         skip_first(
-            byte(b'.', EExpr::Access),
+            access_dot(),
             specialize_err(
                 |_, pos| EExpr::Access(pos),
                 one_of!(
@@ -178,6 +178,15 @@ fn record_field_access_chain<'a>() -> impl Parser<'a, Vec<'a, Suffix<'a>>, EExpr
     ))
 }
 
+/// The `.` of a field access. A `..` is the range operator instead, so it makes no progress.
+fn access_dot<'a>() -> impl Parser<'a, (), EExpr<'a>> {
+    |_, state: State<'a>, _min_indent| match state.bytes() {
+        [b'.', b'.', ..] => Err((NoProgress, EExpr::Access(state.pos()))),
+        [b'.', ..] => Ok((MadeProgress, (), state.advance(1))),
+        _ => Err((NoProgress, EExpr::Access(state.pos()))),
+    }
+}
+
 /// In some contexts we want to parse the `_` as an expression, so it can then be turned into a
 /// pattern later
 fn loc_term_or_underscore_or_conditional<'a>(
@@ -594,12 +603,72 @@ fn stmt_start<'a>(
             loc(specialize_err(EExpr::Closure, closure_help(options))),
             expr_to_stmt
         ),
+        loc(record_rest_def(options)),
         loc(stmt_operator_chain(options)),
         fail_expr_start_e()
     ]
     .trace("stmt_start")
 }
 
+/// A def that destructures a record with a rest pattern, e.g. `{ x, ..rest } = r`.
+///
+/// Other defs parse their pattern as an expression first, but `..rest` is not valid in a record
+/// expression, so these get their own parser. Record patterns without a rest are left alone.
+fn record_rest_def<'a>(options: ExprParseOptions) -> impl Parser<'a, Stmt<'a>, EExpr<'a>> {
+    (move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        let start = state.pos();
+        let call_min_indent = state.line_indent() + 1;
+        let no_progress = || (NoProgress, EExpr::Start(start));
+
+        let (_, fields, state) = crate::pattern::record_pattern_fields()
+            .parse(arena, state, min_indent)
+            .map_err(|_| no_progress())?;
+
+        let has_rest = fields
+            .iter()
+            .any(|field| matches!(field.value.extract_spaces().item, Pattern::RecordRest(_)));
+
+        if !has_rest {
+            return Err(no_progress());
+        }
+
+        let pattern = Loc::at(
+            Region::new(start, state.pos()),
+            Pattern::RecordDestructure(fields),
+        );
+
+        let (_, _, state) = space0_e(EExpr::IndentEnd)
+            .parse(arena, state, min_indent)
+            .map_err(|_| no_progress())?;
+
+        let state = match operator().parse(arena, state, call_min_indent) {
+            Ok((_, OperatorOrDef::Assignment, state)) => state,
+            _ => return Err(no_progress()),
+        };
+
+        let (_, spaces_after_operator, state) = loc_space0_e(EExpr::IndentEnd)
+            .parse(arena, state, min_indent)
+            .map_err(|(_, fail)| (MadeProgress, fail))?;
+
+        let (_, body, state) = parse_block_inner(
+            options,
+            arena,
+            state,
+            call_min_indent,
+            EExpr::IndentEnd,
+            |a, _| a.clone(),
+            spaces_after_operator,
+            !spaces_after_operator.value.is_empty(),
+            false,
+        )?;
+
+        let value_def = ValueDef::Body(arena.alloc(pattern), arena.alloc(body));
+
+        Ok((MadeProgress, Stmt::ValueDef(value_def), state))
+    })
+    .trace("record_rest_def")
+}
+
 fn stmt_operator_chain<'a>(options: ExprParseOptions) -> impl Parser<'a, Stmt<'a>, EExpr<'a>> {
     (move |arena, state: State<'a>, min_indent: u32| {
         parse_stmt_operator_chain(arena, state, min_indent, options)
@@ -2360,15 +2429,21 @@ fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClo
             // It may turn out to be malformed, but it is definitely a closure.
             and(
                 // Parse the params
-                // Params are comma-separated
-                sep_by1_e(
-                    byte(b',', EClosure::Comma),
-                    space0_around_ee(
-                        specialize_err(EClosure::Pattern, closure_param()),
-                        EClosure::IndentArg,
-                        EClosure::IndentArrow,
+                // Params are comma-separated, with an optional trailing comma
+                skip_second(
+                    sep_by1_e(
+                        closure_comma(false),
+                        space0_around_ee(
+                            specialize_err(EClosure::Pattern, closure_param()),
+                            EClosure::IndentArg,
+                            EClosure::IndentArrow,
+                        ),
+                        EClosure::Arg,
                     ),
-                    EClosure::Arg,
+                    optional(skip_second(
+                        closure_comma(true),
+                        space0_e(EClosure::IndentArrow),
+                    )),
                 ),
                 skip_first(
                     // Parse the -> which separates params from body
@@ -2386,6 +2461,30 @@ fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClo
     )
 }
 
+/// Parses the comma after a closure param. With `trailing` set, only a comma
+/// followed by nothing but whitespace and the `->` is accepted; otherwise
+/// only a comma that is followed by another param is.
+fn closure_comma<'a>(trailing: bool) -> impl Parser<'a, (), EClosure<'a>> {
+    move |arena, state: State<'a>, min_indent: u32| {
+        if is_trailing_closure_comma(state.bytes()) == trailing {
+            byte(b',', EClosure::Comma).parse(arena, state, min_indent)
+        } else {
+            Err((NoProgress, EClosure::Comma(state.pos())))
+        }
+    }
+}
+
+fn is_trailing_closure_comma(bytes: &[u8]) -> bool {
+    match bytes.split_first() {
+        Some((b',', rest)) => rest
+            .iter()
+            .skip_while(|b| matches!(b, b' ' | b'\n' | b'\r'))
+            .take(2)
+            .eq(b"->"),
+        _ => false,
+    }
+}
+
 mod when {
     use parser::indented_seq_skip_first;
 
@@ -3375,7 +3474,9 @@ fn starts_with_spaces_conservative(value: &Pattern<'_>) -> bool {
         Pattern::As(left, _) => starts_with_spaces_conservative(&left.value),
         Pattern::Apply(left, _) => starts_with_spaces_conservative(&left.value),
         Pattern::RecordDestructure(_) => false,
-        Pattern::RequiredField(_, _) | Pattern::OptionalField(_, _) => false,
+        Pattern::RequiredField(_, _) | Pattern::OptionalField(_, _) | Pattern::RecordRest(_) => {
+            false
+        }
         Pattern::SpaceBefore(_, _) => true,
         Pattern::SpaceAfter(inner, _) => starts_with_spaces_conservative(inner),
         Pattern::Malformed(_) | Pattern::MalformedIdent(_, _) => true,
@@ -3445,6 +3546,7 @@ fn pat_ends_with_spaces_conservative(pat: &Pattern<'_>) -> bool {
         Pattern::RecordDestructure(_) => false,
         Pattern::RequiredField(_, _) => unreachable!(),
         Pattern::OptionalField(_, _) => unreachable!(),
+        Pattern::RecordRest(_) => unreachable!(),
         Pattern::SpaceBefore(inner, _) => pat_ends_with_spaces_conservative(inner),
         Pattern::SpaceAfter(_, _) => true,
         Pattern::Malformed(_) | Pattern::MalformedIdent(_, _) => false,
@@ -4047,6 +4149,8 @@ where
         ":=" => good!(OperatorOrDef::AliasOrOpaque(AliasOrOpaque::Opaque), 2),
         ":" => good!(OperatorOrDef::AliasOrOpaque(AliasOrOpaque::Alias), 1),
         "??" => good!(OperatorOrDef::BinOp(BinOp::DoubleQuestion), 2),
+        ".." => good!(OperatorOrDef::BinOp(BinOp::DoubleDot), 2),
+        "..=" => good!(OperatorOrDef::BinOp(BinOp::DoubleDotEquals), 3),
         "|>" => good!(OperatorOrDef::BinOp(BinOp::Pizza), 2),
         "==" => good!(OperatorOrDef::BinOp(BinOp::Equals), 2),
         "!=" => good!(OperatorOrDef::BinOp(BinOp::NotEquals), 2),
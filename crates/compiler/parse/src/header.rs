@@ -48,6 +48,287 @@ pub fn parse_module_defs<'a>(
     }
 }
 
+/// Like [parse_module_defs], but keeps going after a problem so that several of them can be
+/// reported at once.
+///
+/// When a top-level def is missing a closing `]`, `}` or `)`, the missing delimiters are inserted
+/// at the end of that def (right before the next top-level def), and a
+/// [SyntaxError::UnclosedDelimiter] is reported for each of them. A def with any other problem is
+/// skipped, and if it uses a reserved keyword as a name, a single [SyntaxError::ReservedKeyword]
+/// is reported for it. Either way, parsing resumes at the next top-level def.
+///
+/// Loading a module uses this, so that all syntax errors of a file are reported together. The
+/// recovered defs contain the inserted delimiters, so they should be used for diagnostics, not
+/// formatted back into the source.
+pub fn parse_module_defs_with_recovery<'a>(
+    arena: &'a bumpalo::Bump,
+    state: State<'a>,
+    defs: Defs<'a>,
+) -> (Defs<'a>, Vec<SyntaxError<'a>>) {
+    let mut problems = Vec::new();
+    let defs = parse_defs_recovering(arena, state, defs, &mut problems);
+
+    (defs, problems)
+}
+
+fn parse_defs_recovering<'a>(
+    arena: &'a bumpalo::Bump,
+    state: State<'a>,
+    defs: Defs<'a>,
+    problems: &mut Vec<SyntaxError<'a>>,
+) -> Defs<'a> {
+    let bytes = state.original_bytes();
+    let start = state.pos().offset as usize;
+
+    let fail = match crate::expr::parse_top_level_defs(arena, state.clone(), defs.clone()) {
+        Ok((_, defs, state)) => match end_of_file().parse(arena, state, 0) {
+            Ok(_) => return defs,
+            Err((_, fail)) => fail,
+        },
+        Err((_, fail)) => SyntaxError::Expr(fail, state.pos()),
+    };
+
+    let fail_offset = fail
+        .get_region()
+        .map_or(start, |region| region.start().offset as usize);
+
+    let broken = find_broken_def(bytes, start, fail_offset);
+
+    // The defs before the broken one are fine, or have problems of their own
+    let mut defs = if broken.def_start > start {
+        parse_defs_recovering(
+            arena,
+            state.with_original_bytes(&bytes[..broken.def_start]),
+            defs,
+            problems,
+        )
+    } else {
+        defs
+    };
+
+    if broken.unclosed.is_empty() {
+        match find_keyword_used_as_name(bytes, broken.def_start, broken.def_end) {
+            Some((keyword, region)) => {
+                problems.push(SyntaxError::ReservedKeyword { keyword, region })
+            }
+            None => problems.push(fail),
+        }
+    } else {
+        let mut patched = bumpalo::collections::Vec::with_capacity_in(
+            broken.code_end + broken.unclosed.len() + 1,
+            arena,
+        );
+        patched.extend_from_slice(&bytes[..broken.code_end]);
+        patched.extend(broken.unclosed.iter().rev().map(|(open, _)| match open {
+            b'(' => b')',
+            b'[' => b']',
+            _ => b'}',
+        }));
+        patched.push(b'\n');
+        let patched: &'a [u8] = patched.into_bump_slice();
+
+        let def_state = if broken.def_start == start {
+            state.with_original_bytes(patched)
+        } else {
+            State::at_line_start(patched, broken.def_start)
+        };
+
+        problems.extend(broken.unclosed.iter().map(|(_, offset)| {
+            let open = Position::new(*offset as u32);
+
+            SyntaxError::UnclosedDelimiter {
+                open_region: Region::new(open, open.bump_column(1)),
+            }
+        }));
+
+        match crate::expr::parse_top_level_defs(arena, def_state, defs.clone()) {
+            Ok((_, recovered, _)) => defs = recovered,
+            Err((_, fail)) => problems.push(SyntaxError::Expr(
+                fail,
+                Position::new(broken.def_start as u32),
+            )),
+        }
+    }
+
+    if broken.def_end < bytes.len() {
+        parse_defs_recovering(
+            arena,
+            State::at_line_start(bytes, broken.def_end),
+            defs,
+            problems,
+        )
+    } else {
+        defs
+    }
+}
+
+/// The top-level def that parsing got stuck in.
+struct BrokenDef {
+    def_start: usize,
+    def_end: usize,
+    /// The end of the last bit of code in the def, i.e. excluding trailing comments and spaces
+    code_end: usize,
+    /// The delimiters that were opened but never closed, with their offsets
+    unclosed: Vec<(u8, usize)>,
+}
+
+/// Find the top-level def around `fail_offset` by scanning for lines that start in the first
+/// column. A def that has unclosed delimiters ends at the first such line, since nothing that
+/// continues a def may start in the first column.
+fn find_broken_def(bytes: &[u8], start: usize, fail_offset: usize) -> BrokenDef {
+    let mut unclosed = Vec::new();
+    let mut def_start = start;
+    let mut code_end = start;
+    let mut index = start;
+
+    while index < bytes.len() {
+        let is_line_start = index > start && bytes[index - 1] == b'\n';
+
+        if is_line_start
+            && !matches!(
+                bytes[index],
+                b' ' | b'\t' | b'\r' | b'\n' | b'#' | b')' | b']' | b'}'
+            )
+        {
+            if !unclosed.is_empty() || index > fail_offset {
+                return BrokenDef {
+                    def_start,
+                    def_end: index,
+                    code_end,
+                    unclosed,
+                };
+            }
+
+            def_start = index;
+        }
+
+        match bytes[index] {
+            b' ' | b'\t' | b'\r' | b'\n' => {
+                index += 1;
+                continue;
+            }
+            b'#' => {
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    index += 1;
+                }
+                continue;
+            }
+            b'"' | b'\'' => index = string_literal_end(bytes, index),
+            open @ (b'(' | b'[' | b'{') => {
+                unclosed.push((open, index));
+                index += 1;
+            }
+            close @ (b')' | b']' | b'}') => {
+                let expected = match close {
+                    b')' => b'(',
+                    b']' => b'[',
+                    _ => b'{',
+                };
+
+                if matches!(unclosed.last(), Some((open, _)) if *open == expected) {
+                    unclosed.pop();
+                }
+
+                index += 1;
+            }
+            _ => index += 1,
+        }
+
+        code_end = index.min(bytes.len());
+    }
+
+    BrokenDef {
+        def_start,
+        def_end: bytes.len(),
+        code_end,
+        unclosed,
+    }
+}
+
+/// The index right after the string or character literal starting at `index`
+fn string_literal_end(bytes: &[u8], mut index: usize) -> usize {
+    if bytes[index..].starts_with(b"\"\"\"") {
+        index += 3;
+        while index < bytes.len() && !bytes[index..].starts_with(b"\"\"\"") {
+            index += 1;
+        }
+        return (index + 3).min(bytes.len());
+    }
+
+    let quote = bytes[index];
+    index += 1;
+    while index < bytes.len() && bytes[index] != b'\n' {
+        match bytes[index] {
+            b'\\' => index += 2,
+            byte if byte == quote => break,
+            _ => index += 1,
+        }
+    }
+
+    (index + 1).min(bytes.len())
+}
+
+/// Find a reserved keyword that is used as a name in `bytes[start..end]`, i.e. one that is
+/// followed by the `=` of a def or the `:` of an annotation or a record field.
+fn find_keyword_used_as_name(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Option<(&'static str, Region)> {
+    let is_ident_byte = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_';
+    let mut index = start;
+
+    while index < end {
+        match bytes[index] {
+            b'#' => {
+                while index < end && bytes[index] != b'\n' {
+                    index += 1;
+                }
+            }
+            b'"' | b'\'' => index = string_literal_end(bytes, index),
+            byte if byte.is_ascii_lowercase() => {
+                let word_start = index;
+                while index < end && is_ident_byte(bytes[index]) {
+                    index += 1;
+                }
+
+                let mut next = index;
+                while next < end && matches!(bytes[next], b' ' | b'\t') {
+                    next += 1;
+                }
+
+                let used_as_name = match bytes.get(next..end) {
+                    Some([b'=', b'=', ..]) => false,
+                    Some([b'=' | b':', ..]) => true,
+                    _ => false,
+                };
+
+                // The word is plain ASCII, since that's all we collected
+                let word = std::str::from_utf8(&bytes[word_start..index]).unwrap_or_default();
+
+                if let (true, Some(keyword)) =
+                    (used_as_name, crate::keyword::reserved_keyword(word))
+                {
+                    let region = Region::new(
+                        Position::new(word_start as u32),
+                        Position::new(index as u32),
+                    );
+
+                    return Some((keyword, region));
+                }
+            }
+            byte if is_ident_byte(byte) => {
+                while index < end && is_ident_byte(bytes[index]) {
+                    index += 1;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+
+    None
+}
+
 pub fn parse_header<'a>(
     arena: &'a bumpalo::Bump,
     state: State<'a>,
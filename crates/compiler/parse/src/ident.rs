@@ -471,6 +471,8 @@ fn chomp_identifier_chain<'a>(
 
     match char::from_utf8_slice_start(&buffer[chomped..]) {
         Ok((ch, width)) => match ch {
+            // `..` is the range operator, not an accessor
+            '.' if buffer.get(1) == Some(&b'.') => return Err((0, BadIdent::Start(pos))),
             '.' => match chomp_accessor(&buffer[1..], pos) {
                 Ok(accessor) => {
                     let bytes_parsed = 1 + accessor.len();
@@ -527,7 +529,7 @@ fn chomp_identifier_chain<'a>(
         }
     }
 
-    if let Ok(('.', _)) = char::from_utf8_slice_start(&buffer[chomped..]) {
+    if buffer.get(chomped) == Some(&b'.') && buffer.get(chomped + 1) != Some(&b'.') {
         let module_name = if first_is_uppercase {
             match chomp_module_chain(&buffer[chomped..]) {
                 Ok(width) => {
@@ -676,7 +678,8 @@ fn chomp_concrete_type(buffer: &[u8]) -> Result<(&str, &str, usize), Progress> {
 fn chomp_access_chain<'a>(buffer: &'a [u8], parts: &mut Vec<'a, Accessor<'a>>) -> Result<u32, u32> {
     let mut chomped = 0;
 
-    while let Some(b'.') = buffer.get(chomped) {
+    // stop before a `..`, which is the range operator
+    while buffer.get(chomped) == Some(&b'.') && buffer.get(chomped + 1) != Some(&b'.') {
         match &buffer.get(chomped + 1..) {
             Some(slice) => match chomp_lowercase_part(slice) {
                 Ok(name) => {
@@ -25,3 +25,9 @@ pub const KEYWORDS: [&str; 12] = [
     IF, THEN, ELSE, WHEN, AS, IS, DBG, IMPORT, EXPECT, RETURN, CRASH,
     "expect!", // not itself a keyword, but it's problematic if we allow an identifier like this!
 ];
+
+/// The keyword `word` is, if it is one of [KEYWORDS]. These can never be used as a name, e.g. of
+/// a def or a record field.
+pub fn reserved_keyword(word: &str) -> Option<&'static str> {
+    KEYWORDS.iter().copied().find(|keyword| *keyword == word)
+}
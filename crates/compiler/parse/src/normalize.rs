@@ -21,10 +21,10 @@ use crate::{
     ident::{BadIdent, UppercaseIdent},
     parser::{
         EAbility, EClosure, EExpect, EExposes, EExpr, EHeader, EIf, EImport, EImportParams,
-        EImports, EInParens, EList, EPackageEntry, EPackageName, EPackages, EParams, EPattern,
-        EProvides, ERecord, ERequires, EReturn, EString, EType, ETypeAbilityImpl, ETypeApply,
-        ETypeInParens, ETypeInlineAlias, ETypeRecord, ETypeTagUnion, ETypedIdent, EWhen, PInParens,
-        PList, PRecord, SyntaxError,
+        EImports, EInParens, EList, ENumber, EPackageEntry, EPackageName, EPackages, EParams,
+        EPattern, EProvides, ERecord, ERequires, EReturn, EString, EType, ETypeAbilityImpl,
+        ETypeApply, ETypeInParens, ETypeInlineAlias, ETypeRecord, ETypeTagUnion, ETypedIdent,
+        EWhen, PInParens, PList, PRecord, SyntaxError,
     },
 };
 
@@ -920,6 +920,7 @@ impl<'a> Normalize<'a> for Pattern<'a> {
             Pattern::SpaceBefore(a, _) => a.normalize(arena),
             Pattern::SpaceAfter(a, _) => a.normalize(arena),
             Pattern::SingleQuote(a) => Pattern::SingleQuote(a),
+            Pattern::RecordRest(a) => Pattern::RecordRest(a),
             Pattern::List(pats) => Pattern::List(pats.normalize(arena)),
             Pattern::Tuple(pats) => Pattern::Tuple(pats.normalize(arena)),
             Pattern::ListRest(opt_pattern_as) => Pattern::ListRest(
@@ -1114,7 +1115,9 @@ impl<'a> Normalize<'a> for EExpr<'a> {
                 EExpr::Record(inner_err.normalize(arena), Position::zero())
             }
             EExpr::Str(inner_err, _pos) => EExpr::Str(inner_err.normalize(arena), Position::zero()),
-            EExpr::Number(inner_err, _pos) => EExpr::Number(inner_err.clone(), Position::zero()),
+            EExpr::Number(inner_err, _pos) => {
+                EExpr::Number(inner_err.normalize(arena), Position::zero())
+            }
             EExpr::List(inner_err, _pos) => {
                 EExpr::List(inner_err.normalize(arena), Position::zero())
             }
@@ -1148,6 +1151,15 @@ impl<'a> Normalize<'a> for EList<'a> {
     }
 }
 
+impl<'a> Normalize<'a> for ENumber {
+    fn normalize(&self, _arena: &'a Bump) -> Self {
+        match self {
+            ENumber::End => ENumber::End,
+            ENumber::InvalidDigitSeparator(_) => ENumber::InvalidDigitSeparator(Position::zero()),
+        }
+    }
+}
+
 impl<'a> Normalize<'a> for EString<'a> {
     fn normalize(&self, arena: &'a Bump) -> Self {
         match self {
@@ -1166,6 +1178,7 @@ impl<'a> Normalize<'a> for EString<'a> {
                 EString::Format(arena.alloc(inner.normalize(arena)), Position::zero())
             }
             EString::FormatEnd(_) => EString::FormatEnd(Position::zero()),
+            EString::EmptyInterpolation(_) => EString::EmptyInterpolation(Position::zero()),
             EString::MultilineInsufficientIndent(_) => {
                 EString::MultilineInsufficientIndent(Position::zero())
             }
@@ -1257,7 +1270,7 @@ impl<'a> Normalize<'a> for EPattern<'a> {
                 EPattern::PInParens(inner_err.normalize(arena), Position::zero())
             }
             EPattern::NumLiteral(inner_err, _) => {
-                EPattern::NumLiteral(inner_err.clone(), Position::zero())
+                EPattern::NumLiteral(inner_err.normalize(arena), Position::zero())
             }
             EPattern::IndentStart(_) => EPattern::IndentStart(Position::zero()),
             EPattern::IndentEnd(_) => EPattern::IndentEnd(Position::zero()),
@@ -1702,7 +1715,10 @@ impl<'a> Normalize<'a> for SyntaxError<'a> {
             SyntaxError::Eof(_) => SyntaxError::Eof(Region::zero()),
             SyntaxError::InvalidPattern => SyntaxError::InvalidPattern,
             SyntaxError::BadUtf8 => SyntaxError::BadUtf8,
-            SyntaxError::ReservedKeyword(_) => SyntaxError::ReservedKeyword(Region::zero()),
+            SyntaxError::ReservedKeyword { keyword, .. } => SyntaxError::ReservedKeyword {
+                keyword,
+                region: Region::zero(),
+            },
             SyntaxError::ArgumentsBeforeEquals(_) => {
                 SyntaxError::ArgumentsBeforeEquals(Region::zero())
             }
@@ -1714,6 +1730,9 @@ impl<'a> Normalize<'a> for SyntaxError<'a> {
             SyntaxError::Header(err) => SyntaxError::Header(err.normalize(arena)),
             SyntaxError::Space(inner_err) => SyntaxError::Space(*inner_err),
             SyntaxError::NotEndOfFile(_) => SyntaxError::NotEndOfFile(Position::zero()),
+            SyntaxError::UnclosedDelimiter { .. } => SyntaxError::UnclosedDelimiter {
+                open_region: Region::zero(),
+            },
         }
     }
 }
@@ -63,11 +63,16 @@ fn chomp_number_base<'a>(
     bytes: &'a [u8],
     state: State<'a>,
 ) -> ParseResult<'a, NumLiteral<'a>, ENumber> {
-    let (_is_float, chomped) = chomp_number(bytes);
+    let prefix_len = 2 + is_negative as usize;
+
+    let (_is_float, chomped) = match chomp_number(base, bytes) {
+        Ok(chomped) => chomped,
+        Err(offset) => return Err(invalid_digit_separator(&state, prefix_len + offset)),
+    };
 
     let string = unsafe { std::str::from_utf8_unchecked(&bytes[..chomped]) };
 
-    let new = state.advance(chomped + 2 + is_negative as usize);
+    let new = state.advance(chomped + prefix_len);
 
     Ok((
         Progress::MadeProgress,
@@ -85,7 +90,15 @@ fn chomp_number_dec<'a>(
     bytes: &'a [u8],
     state: State<'a>,
 ) -> ParseResult<'a, NumLiteral<'a>, ENumber> {
-    let (is_float, chomped) = chomp_number(bytes);
+    let (is_float, chomped) = match chomp_number(Base::Decimal, bytes) {
+        Ok(chomped) => chomped,
+        Err(offset) => {
+            return Err(invalid_digit_separator(
+                &state,
+                is_negative as usize + offset,
+            ))
+        }
+    };
 
     if is_negative && chomped == 0 {
         // we're probably actually looking at unary negation here
@@ -113,12 +126,37 @@ fn chomp_number_dec<'a>(
     ))
 }
 
-fn chomp_number(mut bytes: &[u8]) -> (bool, usize) {
+fn invalid_digit_separator(state: &State<'_>, offset: usize) -> (Progress, ENumber) {
+    let pos = state.pos().bump_column(offset as u32);
+
+    (Progress::MadeProgress, ENumber::InvalidDigitSeparator(pos))
+}
+
+/// An `_` digit separator must sit between two digits, so `1_000` is fine, but
+/// `1__000`, `1_` and `0x_ff` are not.
+fn is_valid_digit_separator(base: Base, before: Option<&u8>, after: Option<&u8>) -> bool {
+    let is_digit = |byte: Option<&u8>| match (base, byte) {
+        (_, None) => false,
+        (Base::Decimal, Some(byte)) => byte.is_ascii_digit(),
+        (_, Some(byte)) => byte.is_ascii_hexdigit(),
+    };
+
+    is_digit(before) && is_digit(after)
+}
+
+/// Returns whether the number is a float, and the number of bytes it takes up.
+/// If a digit separator is misplaced, returns the offset of that `_` instead.
+fn chomp_number(base: Base, mut bytes: &[u8]) -> Result<(bool, usize), usize> {
+    let original_bytes = bytes;
     let start_bytes_len = bytes.len();
     let mut is_float = false;
 
     while let Some(byte) = bytes.first() {
         match byte {
+            b'.' if bytes.get(1) == Some(&b'.') => {
+                // a `..` is the range operator, so the number ends here
+                return Ok((is_float, start_bytes_len - bytes.len()));
+            }
             b'.' => {
                 // skip, fix multiple `.`s in canonicalization
                 is_float = true;
@@ -141,7 +179,13 @@ fn chomp_number(mut bytes: &[u8]) -> (bool, usize) {
                 }
             }
             b'_' => {
-                // skip
+                let offset = start_bytes_len - bytes.len();
+                let before = offset.checked_sub(1).and_then(|i| original_bytes.get(i));
+
+                if !is_valid_digit_separator(base, before, bytes.get(1)) {
+                    return Err(offset);
+                }
+
                 bytes = &bytes[1..];
             }
             _ if byte.is_ascii_digit() || byte.is_ascii_alphabetic() => {
@@ -150,12 +194,12 @@ fn chomp_number(mut bytes: &[u8]) -> (bool, usize) {
             }
             _ => {
                 // not a valid digit; we're done
-                return (is_float, start_bytes_len - bytes.len());
+                return Ok((is_float, start_bytes_len - bytes.len()));
             }
         }
     }
 
     // if the above loop exits, we must be dealing with an empty slice
     // therefore we parsed all of the bytes in the input
-    (is_float, start_bytes_len)
+    Ok((is_float, start_bytes_len))
 }
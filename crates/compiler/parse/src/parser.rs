@@ -52,7 +52,11 @@ pub enum SyntaxError<'a> {
     Eof(Region),
     InvalidPattern,
     BadUtf8,
-    ReservedKeyword(Region),
+    /// A keyword used as a name, e.g. `when = 1`. Only reported when recovering from errors.
+    ReservedKeyword {
+        keyword: &'a str,
+        region: Region,
+    },
     ArgumentsBeforeEquals(Region),
     NotYetImplemented(String),
     Todo,
@@ -62,17 +66,22 @@ pub enum SyntaxError<'a> {
     Header(EHeader<'a>),
     Space(BadInputError),
     NotEndOfFile(Position),
+    /// A `(`, `[` or `{` that is never closed. Only reported when recovering from errors.
+    UnclosedDelimiter {
+        open_region: Region,
+    },
 }
 impl<'a> SyntaxError<'a> {
     pub fn get_region(&self) -> Option<Region> {
         match self {
             SyntaxError::Unexpected(r) => Some(*r),
             SyntaxError::Eof(r) => Some(*r),
-            SyntaxError::ReservedKeyword(r) => Some(*r),
+            SyntaxError::ReservedKeyword { region, .. } => Some(*region),
             SyntaxError::ArgumentsBeforeEquals(r) => Some(*r),
             SyntaxError::Type(e_type) => Some(e_type.get_region()),
             SyntaxError::Pattern(e_pattern) => Some(e_pattern.get_region()),
             SyntaxError::NotEndOfFile(pos) => Some(Region::from_pos(*pos)),
+            SyntaxError::UnclosedDelimiter { open_region } => Some(*open_region),
             SyntaxError::Expr(e_expr, _) => Some(e_expr.get_region()),
             SyntaxError::Header(e_header) => Some(e_header.get_region()),
             SyntaxError::NotYetImplemented(_) => None,
@@ -622,6 +631,8 @@ impl<'a> EExpr<'a> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ENumber {
     End,
+    /// A `_` digit separator that is not between two digits, like in `1__000` or `1_`
+    InvalidDigitSeparator(Position),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -640,6 +651,7 @@ pub enum EString<'a> {
     UnknownEscape(Position),
     Format(&'a EExpr<'a>, Position),
     FormatEnd(Position),
+    EmptyInterpolation(Position),
     MultilineInsufficientIndent(Position),
     ExpectedDoubleQuoteGotSingleQuote(Position),
     InvalidUnicodeCodepoint(Region),
@@ -663,6 +675,7 @@ impl<'a> EString<'a> {
             | EString::EndlessSingleQuote(p)
             | EString::UnknownEscape(p)
             | EString::FormatEnd(p)
+            | EString::EmptyInterpolation(p)
             | EString::MultilineInsufficientIndent(p)
             | EString::ExpectedDoubleQuoteGotSingleQuote(p) => Region::from_pos(*p),
             EString::InvalidUnicodeCodepoint(region) | EString::UnicodeEscapeTooLarge(region) => {
@@ -676,6 +689,8 @@ impl<'a> EString<'a> {
 pub enum ESingleQuote {
     Empty,
     TooLong,
+    /// More than one unicode scalar value, like `'ab'`
+    MultipleChars,
     InterpolationNotAllowed,
 }
 
@@ -489,13 +489,28 @@ pub fn record_pattern_fields<'a>() -> impl Parser<'a, Collection<'a, Loc<Pattern
 {
     collection_trailing_sep_e(
         byte(b'{', PRecord::Open),
-        record_pattern_field(),
+        one_of!(record_rest_pattern(), record_pattern_field()),
         byte(b',', PRecord::End),
         byte(b'}', PRecord::End),
         Pattern::SpaceBefore,
     )
 }
 
+/// The rest of a record pattern, e.g. the `..rest` in `{ name, ..rest }`.
+/// Without a name, `..` just ignores the remaining fields.
+fn record_rest_pattern<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, PRecord<'a>> {
+    loc(map(
+        skip_first(
+            two_bytes(b'.', b'.', PRecord::Field),
+            optional(specialize_err(
+                |_, pos| PRecord::Field(pos),
+                lowercase_ident(),
+            )),
+        ),
+        Pattern::RecordRest,
+    ))
+}
+
 fn record_pattern_field<'a>() -> impl Parser<'a, Loc<Pattern<'a>>, PRecord<'a>> {
     use crate::parser::Either::*;
 
@@ -133,6 +133,29 @@ impl<'a> State<'a> {
     pub fn is_at_start_of_file(&self) -> bool {
         self.offset == 0
     }
+
+    /// The same position, but in a different buffer. The new buffer must agree
+    /// with the original bytes up to the current offset.
+    pub(crate) fn with_original_bytes(&self, bytes: &'a [u8]) -> State<'a> {
+        debug_assert_eq!(&bytes[..self.offset], &self.original_bytes[..self.offset]);
+
+        State {
+            original_bytes: bytes,
+            ..self.clone()
+        }
+    }
+
+    /// A state at the start of the line beginning at `offset` in `bytes`.
+    pub(crate) fn at_line_start(bytes: &'a [u8], offset: usize) -> State<'a> {
+        match offset.checked_sub(1) {
+            None => State::new(bytes),
+            Some(newline) => {
+                debug_assert_eq!(bytes[newline], b'\n');
+
+                State::new(bytes).advance(newline).advance_newline()
+            }
+        }
+    }
 }
 
 impl<'a> fmt::Debug for State<'a> {
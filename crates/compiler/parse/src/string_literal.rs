@@ -286,14 +286,12 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         SingleQuoteLiteral::Line(new_segments.into_bump_slice())
                     };
 
-                    // Validate that the string is a valid char literal.
-                    // Note that currently, we accept anything that:
+                    // Validate that the string is a valid char literal, i.e. that it:
                     // * Is between 1 and 5 bytes long
                     //   -> utf-8 encoding is trivial to extend to 5 bytes, even tho 4 is the technical max
                     //   -> TODO: do we want to change this?
                     // * Decodes as valid UTF-8
-                    //   -> Might be a single code point, or multiple code points
-                    //   -> TODO: do we want to change this?
+                    // * Is a single code point
 
                     // Simply by decoding this, it's guaranteed to be valid utf-8
                     let text = expr.to_str_in(arena).map_err(|e| (MadeProgress, e))?;
@@ -312,6 +310,16 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         ));
                     }
 
+                    if text.chars().nth(1).is_some() {
+                        return Err((
+                            MadeProgress,
+                            EString::InvalidSingleQuote(
+                                ESingleQuote::MultipleChars,
+                                start_state.pos(),
+                            ),
+                        ));
+                    }
+
                     // Advance the state 1 to account for the closing `'`
                     return Ok((
                         MadeProgress,
@@ -452,6 +460,17 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
                         }
                     }
 
+                    // There has to be something to interpolate, so `$()` is an error
+                    let interpolation_start = state.pos();
+                    let inner = &state.bytes()[2..];
+                    let spaces = inner.iter().take_while(|&&b| b == b' ').count();
+                    if inner.get(spaces) == Some(&b')') {
+                        return Err((
+                            MadeProgress,
+                            EString::EmptyInterpolation(interpolation_start),
+                        ));
+                    }
+
                     // Advance past the `$(`
                     state.advance_mut(2);
 
@@ -18,15 +18,16 @@ extern crate roc_parse;
 mod test_parse {
     use bumpalo::collections::vec::Vec;
     use bumpalo::{self, Bump};
+    use roc_module::called_via::{BinOp, CalledVia};
     use roc_parse::ast::Expr::{self, *};
     use roc_parse::ast::StrSegment::*;
     use roc_parse::ast::{self, EscapedChar};
     use roc_parse::ast::{CommentOrNewline, StrLiteral::*};
-    use roc_parse::header::parse_module_defs;
+    use roc_parse::header::{parse_module_defs, parse_module_defs_with_recovery};
     use roc_parse::parser::SyntaxError;
     use roc_parse::state::State;
     use roc_parse::test_helpers::parse_expr_with;
-    use roc_region::all::{Loc, Region};
+    use roc_region::all::{Loc, Position, Region};
     use std::{f64, i64};
 
     fn assert_parses_to<'a>(input: &'a str, expected_expr: Expr<'a>) {
@@ -268,6 +269,59 @@ mod test_parse {
         });
     }
 
+    #[test]
+    fn string_with_nested_interpolation() {
+        assert_segments(r#""a $("b $(c)")""#, |arena| {
+            let inner = arena.alloc(Var {
+                module_name: "",
+                ident: "c",
+            });
+            let inner_segments =
+                arena.alloc([Plaintext("b "), Interpolated(Loc::new(10, 11, inner))]);
+            let outer = arena.alloc(Expr::Str(Line(inner_segments)));
+
+            bumpalo::vec![in arena;
+                 Plaintext("a "),
+                 Interpolated(Loc::new(5, 13, outer))
+            ]
+        });
+    }
+
+    #[test]
+    fn string_with_empty_interpolation() {
+        use roc_parse::parser::{EExpr, EString};
+
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, r#""abc $() def""#);
+
+        assert!(matches!(
+            actual,
+            Err(SyntaxError::Expr(
+                EExpr::Str(EString::EmptyInterpolation(pos), _),
+                _
+            )) if pos.offset == 5
+        ));
+    }
+
+    #[test]
+    fn block_string_strips_indentation_of_opening_quotes() {
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "(\"\"\"\n foo\n   bar\n \"\"\")");
+        let lines: &[&[ast::StrSegment]] = &[&[Plaintext("foo\n"), Plaintext("  bar")]];
+        let expected = ParensAround(arena.alloc(Str(Block(lines))));
+
+        assert_eq!(Ok(expected), actual);
+    }
+
+    #[test]
+    fn block_string_with_embedded_quote_runs() {
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "\"\"\"\n\"a\" \"\" b\n\"\"\"");
+
+        // A single segment collapses to a plain line
+        assert_eq!(Ok(Str(PlainLine(r#""a" "" b"#))), actual);
+    }
+
     #[test]
     fn string_with_non_interpolation_dollar_signs() {
         assert_segments(
@@ -319,12 +373,156 @@ mod test_parse {
         assert_parses_to(float_string.as_str(), Float(float_string.as_str()));
     }
 
+    #[test]
+    fn minus_directly_before_digit_is_part_of_literal() {
+        assert_parses_to("-5", Num("-5"));
+    }
+
+    #[test]
+    fn minus_between_operands_is_subtraction() {
+        for (input, minus_offset) in [("x-5", 1), ("x - 5", 2)] {
+            let arena = Bump::new();
+            let x = Loc::new(
+                0,
+                1,
+                Var {
+                    module_name: "",
+                    ident: "x",
+                },
+            );
+            let minus = Loc::new(minus_offset, minus_offset + 1, BinOp::Minus);
+            let five = Loc::new(input.len() as u32 - 1, input.len() as u32, Num("5"));
+            let expected = BinOps(arena.alloc([(x, minus)]), arena.alloc(five));
+
+            assert_parses_to(input, expected);
+        }
+    }
+
+    #[test]
+    fn spaced_minus_before_digit_is_a_negative_argument() {
+        let arena = Bump::new();
+        let f = Loc::new(
+            0,
+            1,
+            Var {
+                module_name: "",
+                ident: "f",
+            },
+        );
+        let minus_five = Loc::new(2, 4, Num("-5"));
+        let expected = Apply(
+            arena.alloc(f),
+            arena.alloc([&*arena.alloc(minus_five)]),
+            CalledVia::Space,
+        );
+
+        assert_parses_to("f -5", expected);
+    }
+
     // SINGLE QUOTE LITERAL
     #[test]
     fn single_quote() {
         assert_parses_to("'b'", Expr::SingleQuote("b"));
     }
 
+    #[test]
+    fn single_quote_escaped_char() {
+        assert_parses_to(r"'\n'", Expr::SingleQuote("\n"));
+        assert_parses_to(r"'\''", Expr::SingleQuote("'"));
+    }
+
+    #[test]
+    fn single_quote_unicode_escape() {
+        assert_parses_to(r"'\u(1F600)'", Expr::SingleQuote("\u{1F600}"));
+    }
+
+    #[test]
+    fn single_quote_with_multiple_chars() {
+        use roc_parse::parser::{EExpr, ESingleQuote, EString};
+
+        let arena = Bump::new();
+        let actual = parse_expr_with(&arena, "'ab'");
+
+        assert!(
+            matches!(
+                actual,
+                Err(SyntaxError::Expr(
+                    EExpr::Str(
+                        EString::InvalidSingleQuote(ESingleQuote::MultipleChars, _),
+                        _
+                    ),
+                    _
+                ))
+            ),
+            "{actual:?}"
+        );
+    }
+
+    #[test]
+    fn single_trailing_comma_is_allowed() {
+        let arena = Bump::new();
+
+        for src in ["[1, 2, 3,]", "{ a: 1, b: 2, }", "\\a, b, -> a"] {
+            let actual = parse_expr_with(&arena, src);
+
+            assert!(actual.is_ok(), "{src}: {actual:?}");
+        }
+    }
+
+    #[test]
+    fn doubled_comma_is_an_error() {
+        let arena = Bump::new();
+
+        for src in [
+            "[1, 2,, 3]",
+            "[1, 2, 3,,]",
+            "{ a: 1,, b: 2 }",
+            "{ a: 1, b: 2,, }",
+            "\\a,, b -> a",
+            "\\a, b,, -> a",
+        ] {
+            let actual = parse_expr_with(&arena, src);
+
+            assert!(actual.is_err(), "{src}: {actual:?}");
+        }
+    }
+
+    #[test]
+    fn exclusive_range() {
+        let arena = Bump::new();
+        let one = Loc::new(0, 1, Num("1"));
+        let op = Loc::new(1, 3, BinOp::DoubleDot);
+        let ten = Loc::new(3, 5, Num("10"));
+        let expected = BinOps(arena.alloc([(one, op)]), arena.alloc(ten));
+
+        assert_parses_to("1..10", expected);
+    }
+
+    #[test]
+    fn inclusive_range_of_variables() {
+        let arena = Bump::new();
+        let start = Loc::new(
+            0,
+            5,
+            Var {
+                module_name: "",
+                ident: "start",
+            },
+        );
+        let op = Loc::new(6, 9, BinOp::DoubleDotEquals);
+        let end = Loc::new(
+            10,
+            13,
+            Var {
+                module_name: "",
+                ident: "end",
+            },
+        );
+        let expected = BinOps(arena.alloc([(start, op)]), arena.alloc(end));
+
+        assert_parses_to("start ..= end", expected);
+    }
+
     #[test]
     fn repro_keyword_bug() {
         // Reproducing this bug requires a bizarre set of things to all be true:
@@ -381,6 +579,236 @@ mod test_parse {
         }
     }
 
+    fn body_names<'a>(defs: &ast::Defs<'a>) -> std::vec::Vec<&'a str> {
+        defs.value_defs
+            .iter()
+            .filter_map(|def| match def {
+                ast::ValueDef::Body(pattern, _) => match pattern.value {
+                    ast::Pattern::Identifier { ident } => Some(ident),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn unclosed_at(offset: u32) -> SyntaxError<'static> {
+        let open = Position::new(offset);
+
+        SyntaxError::UnclosedDelimiter {
+            open_region: Region::new(open, open.bump_column(1)),
+        }
+    }
+
+    #[test]
+    fn recover_from_unclosed_list() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r"
+            x = [1, 2
+
+            y = 3
+            "
+        );
+
+        let (defs, problems) = parse_module_defs_with_recovery(
+            arena,
+            State::new(src.as_bytes()),
+            ast::Defs::default(),
+        );
+
+        assert_eq!(problems, vec![unclosed_at(4)]);
+        assert_eq!(body_names(&defs), vec!["x", "y"]);
+    }
+
+    #[test]
+    fn recover_from_several_unclosed_delimiters() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r"
+            x = [1, (2, 3
+
+            y = 3
+
+            z = { a: 1 # the end
+            "
+        );
+
+        let (defs, problems) = parse_module_defs_with_recovery(
+            arena,
+            State::new(src.as_bytes()),
+            ast::Defs::default(),
+        );
+
+        assert_eq!(
+            problems,
+            vec![unclosed_at(4), unclosed_at(8), unclosed_at(26)]
+        );
+        assert_eq!(body_names(&defs), vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn recovery_skips_def_with_other_problem() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r#"
+            x = "unterminated
+
+            y = 3
+            "#
+        );
+
+        let (defs, problems) = parse_module_defs_with_recovery(
+            arena,
+            State::new(src.as_bytes()),
+            ast::Defs::default(),
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(matches!(problems[0], SyntaxError::Expr(..)));
+        assert_eq!(body_names(&defs), vec!["y"]);
+    }
+
+    #[test]
+    fn recover_from_keyword_as_def_name() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r"
+            when = 1
+
+            y = 2
+            "
+        );
+
+        let (defs, problems) = parse_module_defs_with_recovery(
+            arena,
+            State::new(src.as_bytes()),
+            ast::Defs::default(),
+        );
+
+        assert_eq!(
+            problems,
+            vec![SyntaxError::ReservedKeyword {
+                keyword: "when",
+                region: Region::new(Position::new(0), Position::new(4)),
+            }]
+        );
+        assert_eq!(body_names(&defs), vec!["y"]);
+    }
+
+    #[test]
+    fn recover_from_keyword_as_field_name() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r#"
+            x = "if: not a field"
+
+            point = { if: 1 }
+
+            y = 2
+            "#
+        );
+
+        let (defs, problems) = parse_module_defs_with_recovery(
+            arena,
+            State::new(src.as_bytes()),
+            ast::Defs::default(),
+        );
+
+        assert_eq!(
+            problems,
+            vec![SyntaxError::ReservedKeyword {
+                keyword: "if",
+                region: Region::new(Position::new(33), Position::new(35)),
+            }]
+        );
+        assert_eq!(body_names(&defs), vec!["x", "y"]);
+    }
+
+    #[test]
+    fn recover_from_statement_keyword_as_field_name() {
+        let arena = &Bump::new();
+        let src = indoc!(
+            r"
+            point = { crash: 1 }
+
+            y = 2
+            "
+        );
+
+        let (defs, problems) = parse_module_defs_with_recovery(
+            arena,
+            State::new(src.as_bytes()),
+            ast::Defs::default(),
+        );
+
+        assert_eq!(
+            problems,
+            vec![SyntaxError::ReservedKeyword {
+                keyword: "crash",
+                region: Region::new(Position::new(10), Position::new(15)),
+            }]
+        );
+        assert_eq!(body_names(&defs), vec!["y"]);
+    }
+
+    fn doc_comments(src: &str) -> std::vec::Vec<Option<String>> {
+        let arena = &Bump::new();
+        let defs =
+            parse_module_defs(arena, State::new(src.as_bytes()), ast::Defs::default()).unwrap();
+
+        (0..defs.len())
+            .map(|index| defs.doc_comment(index))
+            .collect()
+    }
+
+    #[test]
+    fn single_line_doc_comment() {
+        let src = indoc!(
+            r"
+            ## Adds one.
+            inc = \n -> n + 1
+            "
+        );
+
+        assert_eq!(doc_comments(src), vec![Some("Adds one.\n".to_string())]);
+    }
+
+    #[test]
+    fn multi_line_doc_comment() {
+        let src = indoc!(
+            r"
+            x = 1
+
+            ## Adds one.
+            ##
+            ## Works on any number.
+            inc = \n -> n + 1
+            "
+        );
+
+        assert_eq!(
+            doc_comments(src),
+            vec![
+                None,
+                Some("Adds one.\n\nWorks on any number.\n".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn blank_line_detaches_doc_comment() {
+        let src = indoc!(
+            r"
+            ## Not about inc.
+
+            inc = \n -> n + 1
+            "
+        );
+
+        assert_eq!(doc_comments(src), vec![None]);
+    }
+
     #[test]
     fn parse_expr_size() {
         assert_eq!(std::mem::size_of::<roc_parse::ast::Expr>(), 40);
@@ -32,11 +32,47 @@ pub enum ShadowKind {
     Ability(Symbol),
 }
 
-/// Problems that can occur in the course of canonicalization.
+/// Non-fatal issues found in the course of canonicalization.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub enum Problem {
+pub enum Warning {
+    /// A binding that was allowed to shadow one that was already in scope
+    Shadowing {
+        original_region: Region,
+        shadow: Loc<Ident>,
+    },
     UnusedDef(Symbol, Region),
     UnusedImport(Symbol, Region),
+    UnusedAlias(Symbol, Region),
+    /// An import replaces a builtin type that is in scope by default, like `Dict`
+    ShadowsBuiltin {
+        region: Region,
+        new_symbol: Symbol,
+        builtin_symbol: Symbol,
+    },
+    /// A binding like `_name` was referenced, even though the underscore marks it as unused
+    UsedUnderscoreBinding {
+        name: Lowercase,
+        region: Region,
+        declaration_region: Region,
+    },
+}
+
+impl Warning {
+    pub fn region(&self) -> Region {
+        match self {
+            Warning::Shadowing { shadow, .. } => shadow.region,
+            Warning::UnusedDef(_, region)
+            | Warning::UnusedImport(_, region)
+            | Warning::UnusedAlias(_, region)
+            | Warning::ShadowsBuiltin { region, .. }
+            | Warning::UsedUnderscoreBinding { region, .. } => *region,
+        }
+    }
+}
+
+/// Problems that can occur in the course of canonicalization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
     UnusedModuleImport(ModuleId, Region),
     ExposedButNotDefined(Symbol),
     ImportNameConflict {
@@ -94,6 +130,12 @@ pub enum Problem {
         field_region: Region,
         replaced_region: Region,
     },
+    DuplicateRecordFieldPattern {
+        field_name: Lowercase,
+        record_region: Region,
+        field_region: Region,
+        replaced_region: Region,
+    },
     InvalidOptionalValue {
         field_name: Lowercase,
         record_region: Region,
@@ -207,6 +249,12 @@ pub enum Problem {
     MultipleListRestPattern {
         region: Region,
     },
+    MultipleRecordRestPattern {
+        region: Region,
+    },
+    RecordRestPatternNotLast {
+        region: Region,
+    },
     BadTypeArguments {
         symbol: Symbol,
         region: Region,
@@ -270,8 +318,6 @@ impl Problem {
         use Severity::{Fatal, RuntimeError, Warning};
 
         match self {
-            Problem::UnusedDef(_, _) => Warning,
-            Problem::UnusedImport(_, _) => Warning,
             Problem::UnusedModuleImport(_, _) => Warning,
             Problem::ImportNameConflict { .. } => RuntimeError,
             Problem::ExplicitBuiltinImport(_, _) => Warning,
@@ -292,6 +338,7 @@ impl Problem {
             Problem::UnderscoreNotAllowed { .. } => RuntimeError,
             Problem::DuplicateRecordFieldValue { .. } => Warning,
             Problem::DuplicateRecordFieldType { .. } => RuntimeError,
+            Problem::DuplicateRecordFieldPattern { .. } => RuntimeError,
             Problem::InvalidOptionalValue { .. } => RuntimeError,
             Problem::DuplicateTag { .. } => RuntimeError,
             Problem::RuntimeError(_) => RuntimeError,
@@ -328,6 +375,8 @@ impl Problem {
             // TODO: sometimes this can just be a warning, e.g. if you have [1, .., .., 2] but we
             // don't catch that yet.
             Problem::MultipleListRestPattern { .. } => RuntimeError,
+            Problem::MultipleRecordRestPattern { .. } => RuntimeError,
+            Problem::RecordRestPatternNotLast { .. } => RuntimeError,
             Problem::BadTypeArguments { .. } => RuntimeError,
             // TODO: this can be a warning instead if we recover the program by
             // injecting a crash message
@@ -356,12 +405,10 @@ impl Problem {
     /// on their Region being outside the expression currently being evaluated.
     pub fn region(&self) -> Option<Region> {
         match self {
-            Problem::UnusedDef(_, region)
-            | Problem::Shadowing {
+            Problem::Shadowing {
                 original_region: region,
                 ..
             }
-            | Problem::UnusedImport(_, region)
             | Problem::UnusedModuleImport(_, region)
             | Problem::ImportNameConflict {
                 new_import_region: region,
@@ -400,6 +447,10 @@ impl Problem {
                 record_region: region,
                 ..
             }
+            | Problem::DuplicateRecordFieldPattern {
+                record_region: region,
+                ..
+            }
             | Problem::InvalidOptionalValue {
                 record_region: region,
                 ..
@@ -421,6 +472,7 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::LookupNotInScope {
                 loc_name: Loc { region, .. },
                 suggestion_options: _,
+                closest_suggestions: _,
                 underscored_suggestion_region: _,
             })
             | Problem::RuntimeError(RuntimeError::OpaqueNotDefined {
@@ -433,6 +485,24 @@ impl Problem {
             })
             | Problem::RuntimeError(RuntimeError::OpaqueNotApplied(Loc { region, .. }))
             | Problem::RuntimeError(RuntimeError::OpaqueAppliedToMultipleArgs(region))
+            | Problem::RuntimeError(RuntimeError::OpaqueArityMismatch { region, .. })
+            | Problem::RuntimeError(RuntimeError::DuplicateAlias {
+                duplicate_region: region,
+                ..
+            })
+            | Problem::RuntimeError(RuntimeError::DuplicateTypeVariable {
+                duplicate_region: region,
+                ..
+            })
+            | Problem::RuntimeError(RuntimeError::DuplicatePatternBinding {
+                second_region: region,
+                ..
+            })
+            | Problem::RuntimeError(RuntimeError::RecursiveAliasWithoutRecursionVar {
+                region,
+                ..
+            })
+            | Problem::RuntimeError(RuntimeError::UnboundTypeVariablesInAlias { region, .. })
             | Problem::RuntimeError(RuntimeError::ValueNotExposed { region, .. })
             | Problem::RuntimeError(RuntimeError::ModuleNotImported { region, .. })
             | Problem::RuntimeError(RuntimeError::InvalidPrecedence(_, region))
@@ -501,6 +571,8 @@ impl Problem {
                 ..
             }
             | Problem::MultipleListRestPattern { region }
+            | Problem::MultipleRecordRestPattern { region }
+            | Problem::RecordRestPatternNotLast { region }
             | Problem::BadTypeArguments { region, .. }
             | Problem::UnnecessaryOutputWildcard { region }
             | Problem::OverAppliedCrash { region }
@@ -526,6 +598,7 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::VoidValue)
             | Problem::RuntimeError(RuntimeError::ExposedButNotDefined(_))
             | Problem::RuntimeError(RuntimeError::NoImplementationNamed { .. })
+            | Problem::RuntimeError(RuntimeError::OverlappingSpecialization { .. })
             | Problem::FileProblem { .. }
             | Problem::ExposedButNotDefined(_) => None,
         }
@@ -619,6 +692,8 @@ pub enum RuntimeError {
         loc_name: Loc<Ident>,
         /// All of the names in scope (for the error message)
         suggestion_options: MutSet<Box<str>>,
+        /// The names in scope that are most likely what the user meant, closest first
+        closest_suggestions: Vec<Box<str>>,
         /// If the unfound variable is `name` and there's an ignored variable called `_name`,
         /// this is the region where `_name` is defined (for the error message)
         underscored_suggestion_region: Option<Region>,
@@ -635,6 +710,44 @@ pub enum RuntimeError {
     },
     OpaqueNotApplied(Loc<Ident>),
     OpaqueAppliedToMultipleArgs(Region),
+    /// An opaque reference applied to a different number of type arguments than the opaque
+    /// type declares
+    OpaqueArityMismatch {
+        opaque: Symbol,
+        expected: usize,
+        got: usize,
+        region: Region,
+    },
+    /// The same alias symbol was added to the scope twice
+    DuplicateAlias {
+        alias: Symbol,
+        original_region: Region,
+        duplicate_region: Region,
+    },
+    /// A type alias declares the same type variable more than once, e.g. `Pair a a : ...`
+    DuplicateTypeVariable {
+        name: Lowercase,
+        first_region: Region,
+        duplicate_region: Region,
+    },
+    /// A single pattern binds the same name more than once, e.g. `(x, x)` or `{ a: x, b: x }`
+    DuplicatePatternBinding {
+        name: Ident,
+        first_region: Region,
+        second_region: Region,
+    },
+    /// A structural alias that refers to itself, without a recursion variable to make that
+    /// reference finite, e.g. `Foo : { x : Foo }`
+    RecursiveAliasWithoutRecursionVar {
+        symbol: Symbol,
+        region: Region,
+    },
+    /// A type alias whose body mentions type variables that are not bound anywhere
+    UnboundTypeVariablesInAlias {
+        alias: Symbol,
+        region: Region,
+        unbound: Vec<Lowercase>,
+    },
     ValueNotExposed {
         module_name: ModuleName,
         ident: Ident,
@@ -705,6 +818,13 @@ pub enum RuntimeError {
 
     ExposedButNotDefined(Symbol),
 
+    /// A type was given a second specialization of the same ability member
+    OverlappingSpecialization {
+        ability: Symbol,
+        member: Symbol,
+        for_type: Symbol,
+    },
+
     /// where ''
     EmptySingleQuote(Region),
     /// where 'aa'
@@ -747,6 +867,21 @@ impl RuntimeError {
                 ..
             }
             | RuntimeError::OpaqueAppliedToMultipleArgs(region)
+            | RuntimeError::OpaqueArityMismatch { region, .. }
+            | RuntimeError::DuplicateAlias {
+                duplicate_region: region,
+                ..
+            }
+            | RuntimeError::DuplicateTypeVariable {
+                duplicate_region: region,
+                ..
+            }
+            | RuntimeError::DuplicatePatternBinding {
+                second_region: region,
+                ..
+            }
+            | RuntimeError::RecursiveAliasWithoutRecursionVar { region, .. }
+            | RuntimeError::UnboundTypeVariablesInAlias { region, .. }
             | RuntimeError::ValueNotExposed { region, .. }
             | RuntimeError::ModuleNotImported { region, .. }
             | RuntimeError::InvalidPrecedence(_, region)
@@ -778,7 +913,8 @@ impl RuntimeError {
             RuntimeError::NoImplementationNamed { .. }
             | RuntimeError::NoImplementation
             | RuntimeError::VoidValue
-            | RuntimeError::ExposedButNotDefined(_) => Region::zero(),
+            | RuntimeError::ExposedButNotDefined(_)
+            | RuntimeError::OverlappingSpecialization { .. } => Region::zero(),
         }
     }
 }
@@ -794,5 +930,8 @@ pub enum MalformedPatternProblem {
     EmptySingleQuote,
     MultipleCharsInSingleQuote,
     DuplicateListRestPattern,
+    DuplicateRecordField,
+    DuplicateRecordRestPattern,
+    RecordRestPatternNotLast,
     CantApplyPattern,
 }
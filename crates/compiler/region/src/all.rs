@@ -30,10 +30,24 @@ impl Region {
         self.start == self.end
     }
 
+    /// Whether this is the placeholder region given to things the compiler generated, rather
+    /// than a region of actual source code.
+    pub fn is_synthetic(&self) -> bool {
+        *self == Region::zero()
+    }
+
     pub const fn len(&self) -> u32 {
         self.end.offset - self.start.offset
     }
 
+    /// The smallest region that covers both `self` and `other`, and anything in between
+    pub fn merge(&self, other: &Region) -> Self {
+        Region {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
     pub fn span_across(start: &Region, end: &Region) -> Self {
         Region {
             start: start.start,
@@ -222,6 +236,11 @@ impl LineColumnRegion {
         self.end.line == self.start.line && self.start.column == self.end.column
     }
 
+    /// Whether this was converted from a synthetic [Region], see [Region::is_synthetic].
+    pub fn is_synthetic(&self) -> bool {
+        *self == LineColumnRegion::zero()
+    }
+
     pub fn span_across(start: &LineColumnRegion, end: &LineColumnRegion) -> Self {
         LineColumnRegion {
             start: start.start,
@@ -416,6 +435,83 @@ impl LineInfo {
     }
 }
 
+/// Converts between byte offsets and line/column pairs, like [LineInfo], but counts columns in
+/// Unicode scalar values rather than bytes. This is what editors expect, e.g. the `á` in
+/// `uránia` takes up two bytes but only one column.
+///
+/// Lines and columns are one-based, matching how editors and compiler messages number them
+/// (unlike [LineInfo], whose [LineColumn]s are zero-based). Both `\n` and `\r\n` end a line,
+/// and the line terminator itself is not counted as part of the line's columns.
+#[derive(Debug, Clone)]
+pub struct LineColumnIndex<'a> {
+    src: &'a str,
+    line_offsets: Vec<u32>,
+}
+
+impl<'a> LineColumnIndex<'a> {
+    pub fn new(src: &'a str) -> Self {
+        let mut line_offsets = vec![0];
+        line_offsets.extend(src.match_indices('\n').map(|(offset, _)| offset as u32 + 1));
+
+        LineColumnIndex { src, line_offsets }
+    }
+
+    pub fn offset_to_line_column(&self, offset: u32) -> LineColumn {
+        let offset = offset.min(self.src.len() as u32);
+
+        let line = match self.line_offsets.binary_search(&offset) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+
+        let line_start = self.line_offsets[line] as usize;
+
+        // count the scalar values that start between the beginning of the line and the offset,
+        // a byte starts a scalar value unless it is a continuation byte (0b10xx_xxxx)
+        let column = self.src.as_bytes()[line_start..offset as usize]
+            .iter()
+            .filter(|byte| (**byte as i8) >= -0x40)
+            .count();
+
+        LineColumn {
+            line: line as u32 + 1,
+            column: column as u32 + 1,
+        }
+    }
+
+    /// The inverse of [LineColumnIndex::offset_to_line_column]. Lines past the end of the source
+    /// and columns past the end of their line are clamped to the last valid offset; a line or
+    /// column of 0 is treated as 1.
+    pub fn line_column_to_offset(&self, lc: LineColumn) -> u32 {
+        let line_index = lc.line.saturating_sub(1) as usize;
+        let column_index = lc.column.saturating_sub(1) as usize;
+
+        let Some(&line_start) = self.line_offsets.get(line_index) else {
+            return self.src.len() as u32;
+        };
+
+        let line_end = self
+            .line_offsets
+            .get(line_index + 1)
+            .map(|next_line| *next_line as usize - 1)
+            .unwrap_or(self.src.len());
+        let line = &self.src[line_start as usize..line_end];
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        let column_offset = line
+            .char_indices()
+            .nth(column_index)
+            .map(|(offset, _)| offset)
+            .unwrap_or(line.len());
+
+        line_start + column_offset as u32
+    }
+
+    pub fn num_lines(&self) -> u32 {
+        self.line_offsets.len() as u32
+    }
+}
+
 #[test]
 fn test_line_info() {
     fn char_at_line<'a>(lines: &[&'a str], line_column: LineColumn) -> &'a str {
@@ -476,3 +572,102 @@ fn test_line_info() {
 
     check_correctness(&["", ""]);
 }
+
+#[test]
+fn test_region_merge() {
+    let region = |start, end| Region::new(Position::new(start), Position::new(end));
+
+    // adjacent
+    assert_eq!(region(0, 3).merge(&region(3, 5)), region(0, 5));
+    assert_eq!(region(3, 5).merge(&region(0, 3)), region(0, 5));
+
+    // overlapping
+    assert_eq!(region(0, 4).merge(&region(2, 6)), region(0, 6));
+    assert_eq!(region(0, 6).merge(&region(2, 4)), region(0, 6));
+
+    // disjoint
+    assert_eq!(region(8, 9).merge(&region(1, 2)), region(1, 9));
+}
+
+#[test]
+fn test_region_contains() {
+    let region = |start, end| Region::new(Position::new(start), Position::new(end));
+
+    // adjacent
+    assert!(!region(0, 3).contains(&region(3, 5)));
+    assert!(region(0, 3).contains_pos(Position::new(3)));
+    assert!(!region(0, 3).contains_pos(Position::new(4)));
+
+    // overlapping
+    assert!(!region(0, 4).contains(&region(2, 6)));
+    assert!(region(0, 6).contains(&region(2, 4)));
+    assert!(region(2, 4).contains(&region(2, 4)));
+
+    // disjoint
+    assert!(!region(8, 9).contains(&region(1, 2)));
+    assert!(!region(1, 2).contains_pos(Position::new(8)));
+
+    // a merged region contains both of its parts
+    let merged = region(8, 9).merge(&region(1, 2));
+    assert!(merged.contains(&region(8, 9)));
+    assert!(merged.contains(&region(1, 2)));
+}
+
+#[test]
+fn test_region_is_synthetic() {
+    assert!(Region::zero().is_synthetic());
+    assert!(LineColumnRegion::zero().is_synthetic());
+
+    assert!(!Region::new(Position::new(0), Position::new(1)).is_synthetic());
+    assert!(!Region::new(Position::new(3), Position::new(3)).is_synthetic());
+}
+
+#[test]
+fn test_line_column_index() {
+    let src = "uránia\r\nJárnak = 1\n\nÅngström";
+    let index = LineColumnIndex::new(src);
+
+    let lc = |line, column| LineColumn { line, column };
+
+    assert_eq!(index.num_lines(), 4);
+
+    // `á` is two bytes, but a single column
+    assert_eq!(index.offset_to_line_column(0), lc(1, 1));
+    assert_eq!(index.offset_to_line_column(4), lc(1, 4));
+    assert_eq!(index.offset_to_line_column(7), lc(1, 7));
+
+    // the `\r\n` ends the first line
+    assert_eq!(index.offset_to_line_column(9), lc(2, 1));
+    assert_eq!(index.offset_to_line_column(12), lc(2, 3));
+
+    // empty line, then a line starting with a multi-byte character
+    assert_eq!(index.offset_to_line_column(21), lc(3, 1));
+    assert_eq!(index.offset_to_line_column(22), lc(4, 1));
+    assert_eq!(index.offset_to_line_column(24), lc(4, 2));
+    assert_eq!(
+        index.offset_to_line_column(src.len() as u32),
+        lc(4, "Ångström".chars().count() as u32 + 1)
+    );
+
+    // every character boundary survives a round trip
+    for (offset, _) in src.char_indices() {
+        let offset = offset as u32;
+        let line_column = index.offset_to_line_column(offset);
+
+        if src[offset as usize..].starts_with('\n') && src[..offset as usize].ends_with('\r') {
+            // the `\n` of a `\r\n` is not part of the line, so it maps back to the `\r`
+            continue;
+        }
+
+        assert_eq!(
+            index.line_column_to_offset(line_column),
+            offset,
+            "{line_column:?}"
+        );
+    }
+
+    // out-of-range columns and lines are clamped
+    assert_eq!(index.line_column_to_offset(lc(1, 100)), 7);
+    assert_eq!(index.line_column_to_offset(lc(100, 1)), src.len() as u32);
+    assert_eq!(index.line_column_to_offset(lc(0, 0)), 0);
+}
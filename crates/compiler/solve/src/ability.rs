@@ -779,6 +779,8 @@ trait DerivableVisitor {
                         }
                     }
                     EmptyRecord => Self::visit_empty_record(var)?,
+                    // Only ever the extension of a closed tuple, whose elements we've visited.
+                    EmptyTuple => {}
                     EmptyTagUnion => Self::visit_empty_tag_union(var)?,
                     EffectfulFunc => {
                         return Err(NotDerivable {
@@ -5,7 +5,7 @@ use roc_derive::SharedDerivedModule;
 use roc_types::subs::{Content, Descriptor, Mark, OptVariable, Rank, Subs, Variable};
 use roc_unify::Env as UEnv;
 
-use crate::{FunctionKind, Pools};
+use crate::{solve::GeneralizationTrace, FunctionKind, Pools};
 
 pub struct DerivedEnv<'a> {
     pub derived_module: &'a SharedDerivedModule,
@@ -31,6 +31,12 @@ pub struct InferenceEnv<'a> {
     pub derived_env: &'a DerivedEnv<'a>,
     pub subs: &'a mut Subs,
     pub pools: &'a mut Pools,
+    /// Present when generalization is being traced; see [GeneralizationTrace].
+    pub generalization_trace: Option<Vec<GeneralizationTrace>>,
+    /// The number of unifications after which solving gives up, if any.
+    pub step_budget: Option<u64>,
+    /// The number of times [InferenceEnv::uenv] was called so far.
+    pub unifications: u64,
     #[cfg(debug_assertions)]
     pub checkmate: Option<roc_checkmate::Collector>,
 }
@@ -99,14 +105,22 @@ impl<'a> InferenceEnv<'a> {
         var
     }
 
-    /// Retrieves an environment for unification.
+    /// Retrieves an environment for unification, counting it against the step budget.
     pub fn uenv(&mut self) -> UEnv {
+        self.unifications += 1;
+
         with_checkmate!({
             on => UEnv::new(self.subs, self.checkmate.as_mut()),
             off => UEnv::new(self.subs),
         })
     }
 
+    /// The step budget, if solving has used all of it.
+    pub fn out_of_steps(&self) -> Option<u64> {
+        self.step_budget
+            .filter(|budget| self.unifications >= *budget)
+    }
+
     pub fn as_solve_env(&mut self) -> SolveEnv {
         SolveEnv {
             arena: self.arena,
@@ -78,6 +78,17 @@ pub struct SolveConfig<'a> {
     pub derived_module: SharedDerivedModule,
     /// Symbols that are exposed to the host which might need special treatment.
     pub host_exposed_symbols: Option<&'a VecSet<Symbol>>,
+    /// Whether to record what happened to the variables of each generalized let-binding.
+    /// See [solve::GeneralizationTrace].
+    pub trace_generalization: bool,
+    /// Give up after this many unifications, reporting [TypeError::BudgetExceeded].
+    /// Counting unifications rather than time keeps the outcome deterministic, so that editors
+    /// can bound the time spent on a pathological module.
+    ///
+    /// Only the unifications that solving starts count, not the steps inside each of them, and
+    /// the budget is checked between constraints. So a single large unification always runs to
+    /// completion, and solving can overshoot the budget by the unifications of one constraint.
+    pub step_budget: Option<u64>,
 
     #[cfg(debug_assertions)]
     /// The checkmate collector for this module.
@@ -125,6 +136,7 @@ pub fn run_solve(
     let RunSolveOutput {
         solved,
         scope,
+        generalizations: _,
         #[cfg(debug_assertions)]
         checkmate,
     } = solve::run(
@@ -35,7 +35,9 @@ use roc_types::subs::{
     self, Content, FlatType, GetSubsSlice, Mark, OptVariable, Rank, Subs, TagExt, UlsOfVar,
     Variable,
 };
-use roc_types::types::{Category, Polarity, Reason, RecordField, Type, TypeExtension, Types, Uls};
+use roc_types::types::{
+    Category, MismatchDetails, Polarity, Reason, RecordField, Type, TypeExtension, Types, Uls,
+};
 use roc_unify::unify::{
     unify, unify_introduced_ability_specialization, Obligated, SpecializationLsetCollector,
     Unified::*,
@@ -103,6 +105,8 @@ struct State {
 pub struct RunSolveOutput {
     pub solved: Solved<Subs>,
     pub scope: Scope,
+    /// One entry per generalized let-binding, if [SolveConfig::trace_generalization] was set.
+    pub generalizations: Vec<GeneralizationTrace>,
 
     #[cfg(debug_assertions)]
     pub checkmate: Option<roc_checkmate::Collector>,
@@ -138,6 +142,8 @@ fn run_help(
         module_params,
         module_params_vars,
         host_exposed_symbols,
+        trace_generalization,
+        step_budget,
         ..
     } = config;
 
@@ -161,6 +167,9 @@ fn run_help(
         derived_env: &derived_env,
         subs,
         pools: &mut pools,
+        generalization_trace: trace_generalization.then(Vec::new),
+        step_budget,
+        unifications: 0,
         #[cfg(debug_assertions)]
         checkmate: config.checkmate,
     };
@@ -197,6 +206,7 @@ fn run_help(
 
     RunSolveOutput {
         scope: state.scope,
+        generalizations: env.generalization_trace.unwrap_or_default(),
         #[cfg(debug_assertions)]
         checkmate: env.checkmate,
         solved: Solved(owned_subs),
@@ -270,6 +280,12 @@ fn solve(
     };
 
     while let Some(work_item) = stack.pop() {
+        if let Some(budget) = env.out_of_steps() {
+            // Keep what we solved so far, so it can still be reported on
+            problems.push(TypeError::BudgetExceeded(budget));
+            break;
+        }
+
         let (scope, rank, constraint) = match work_item {
             Work::Constraint {
                 scope,
@@ -414,7 +430,13 @@ fn solve(
                 // next rank. The variables introduced in the let-binding that are still at
                 // that rank (intuitively, they did not "escape" into the lower level
                 // before or after the let-binding) now get to be generalized.
-                generalize(env, young_mark, visit_mark, rank.next());
+                generalize(
+                    env,
+                    young_mark,
+                    visit_mark,
+                    rank.next(),
+                    local_def_vars.iter().map(|(symbol, _)| *symbol),
+                );
                 debug_assert!(env.pools.get(rank.next()).is_empty(), "variables left over in let-binding scope, but they should all be in a lower scope or generalized now");
 
                 // check that things went well
@@ -559,7 +581,7 @@ fn solve(
 
                         state
                     }
-                    Failure(vars, actual_type, expected_type, _bad_impls) => {
+                    Failure(vars, actual_type, expected_type, _bad_impls, details) => {
                         env.introduce(rank, &vars);
 
                         let problem = TypeError::BadExpr(
@@ -567,6 +589,7 @@ fn solve(
                             category.clone(),
                             actual_type,
                             expectation.replace_ref(expected_type),
+                            details,
                         );
 
                         problems.push(problem);
@@ -675,7 +698,7 @@ fn solve(
                                 state
                             }
 
-                            Failure(vars, actual_type, expected_type, _bad_impls) => {
+                            Failure(vars, actual_type, expected_type, _bad_impls, details) => {
                                 env.introduce(rank, &vars);
 
                                 let problem = TypeError::BadExpr(
@@ -683,6 +706,7 @@ fn solve(
                                     Category::Lookup(*symbol),
                                     actual_type,
                                     expectation.replace_ref(expected_type),
+                                    details,
                                 );
 
                                 problems.push(problem);
@@ -777,7 +801,7 @@ fn solve(
 
                         state
                     }
-                    Failure(vars, actual_type, expected_type, _bad_impls) => {
+                    Failure(vars, actual_type, expected_type, _bad_impls, _) => {
                         env.introduce(rank, &vars);
 
                         let problem = TypeError::BadPattern(
@@ -988,7 +1012,7 @@ fn solve(
 
                         state
                     }
-                    Failure(vars, actual_type, _expected_type, _bad_impls) => {
+                    Failure(vars, actual_type, _expected_type, _bad_impls, _) => {
                         env.introduce(rank, &vars);
 
                         let problem = TypeError::InvalidTryTarget(*region, actual_type, *kind);
@@ -1199,7 +1223,7 @@ fn solve(
 
                         state
                     }
-                    Failure(vars, actual_type, expected_to_include_type, _bad_impls) => {
+                    Failure(vars, actual_type, expected_to_include_type, _bad_impls, _) => {
                         env.introduce(rank, &vars);
 
                         let problem = TypeError::BadPattern(
@@ -1369,7 +1393,7 @@ fn solve(
                                 UnificationMode::EQ,
                                 cond_polarity,
                             ) {
-                                Failure(vars, actual_type, expected_type, _bad_impls) => {
+                                Failure(vars, actual_type, expected_type, _bad_impls, details) => {
                                     env.introduce(rank, &vars);
 
                                     // Figure out the problem - it might be pattern or value
@@ -1384,6 +1408,7 @@ fn solve(
                                                 real_category,
                                                 actual_type,
                                                 expected.replace_ref(expected_type),
+                                                details,
                                             )
                                         }
 
@@ -1610,7 +1635,7 @@ fn solve(
 
                             state
                         }
-                        Failure(vars, actual_type, _, _) => {
+                        Failure(vars, actual_type, _, _, _) => {
                             env.introduce(rank, &vars);
 
                             let problem = TypeError::IngestedFileUnsupportedType(
@@ -1678,7 +1703,7 @@ fn solve(
                                 state
                             }
 
-                            Failure(vars, actual_type, expected_type, _) => {
+                            Failure(vars, actual_type, expected_type, _, _) => {
                                 env.introduce(rank, &vars);
 
                                 problems.push(TypeError::ModuleParamsMismatch(
@@ -2092,6 +2117,7 @@ fn check_ability_specialization(
                             Category::AbilityMemberSpecialization(ability_member),
                             actual_type,
                             Expected::ForReason(reason, expected_type, symbol_loc_var.region),
+                            MismatchDetails::default(),
                         );
 
                         problems.push(problem);
@@ -2101,7 +2127,7 @@ fn check_ability_specialization(
                 }
             }
 
-            Failure(vars, expected_type, actual_type, unimplemented_abilities) => {
+            Failure(vars, expected_type, actual_type, unimplemented_abilities, _) => {
                 env.subs.commit_snapshot(snapshot);
                 env.introduce(rank, &vars);
 
@@ -2116,6 +2142,7 @@ fn check_ability_specialization(
                     Category::AbilityMemberSpecialization(ability_member),
                     actual_type,
                     Expected::ForReason(reason, expected_type, symbol_loc_var.region),
+                    MismatchDetails::default(),
                 );
 
                 problems.push(problem);
@@ -2271,16 +2298,41 @@ fn circular_error(
     problems.push(problem);
 }
 
+/// What [generalize] did with the variables of a single let-binding.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeneralizationTrace {
+    /// The symbols defined by the let-binding.
+    pub symbols: Vec<Symbol>,
+    /// Variables that were promoted to [Rank::GENERALIZED].
+    pub generalized: Vec<Variable>,
+    /// Variables that escaped into a lower scope, and the rank they were left at.
+    pub kept: Vec<(Variable, Rank)>,
+}
+
 /// Generalizes variables at the `young_rank`, which did not escape a let-binding
 /// into a lower scope.
 ///
 /// Ensures that variables introduced at the `young_rank`, but that should be
 /// stuck at a lower level, are marked at that level and not generalized at the
 /// present `young_rank`. See [adjust_rank].
-fn generalize(env: &mut InferenceEnv, young_mark: Mark, visit_mark: Mark, young_rank: Rank) {
+fn generalize(
+    env: &mut InferenceEnv,
+    young_mark: Mark,
+    visit_mark: Mark,
+    young_rank: Rank,
+    symbols: impl Iterator<Item = Symbol>,
+) {
     let subs = &mut env.subs;
     let pools = &mut env.pools;
 
+    let mut trace = env
+        .generalization_trace
+        .is_some()
+        .then(|| GeneralizationTrace {
+            symbols: symbols.collect(),
+            ..Default::default()
+        });
+
     let young_vars = std::mem::take(pools.get_mut(young_rank));
     let rank_table = pool_to_rank_table(subs, young_mark, young_rank, young_vars);
 
@@ -2301,6 +2353,10 @@ fn generalize(env: &mut InferenceEnv, young_mark: Mark, visit_mark: Mark, young_
             let rank = subs.get_rank(var);
 
             pools.get_mut(rank).push(var);
+
+            if let Some(trace) = trace.as_mut() {
+                trace.kept.push((var, rank));
+            }
         }
     }
 
@@ -2311,11 +2367,23 @@ fn generalize(env: &mut InferenceEnv, young_mark: Mark, visit_mark: Mark, young_
 
         if desc_rank < young_rank {
             pools.get_mut(desc_rank).push(var);
+
+            if let Some(trace) = trace.as_mut() {
+                trace.kept.push((var, desc_rank));
+            }
         } else {
             subs.set_rank(var, Rank::GENERALIZED);
+
+            if let Some(trace) = trace.as_mut() {
+                trace.generalized.push(var);
+            }
         }
     }
 
+    if let (Some(traces), Some(trace)) = (env.generalization_trace.as_mut(), trace) {
+        traces.push(trace);
+    }
+
     // re-use the last_vector (which likely has a good capacity for future runs)
     debug_assert!(last_pool.is_empty());
     *pools.get_mut(young_rank) = last_pool;
@@ -2482,6 +2550,9 @@ fn adjust_rank_content(
                     group_rank
                 }
 
+                // THEORY: an empty tuple never needs to get generalized
+                EmptyTuple => Rank::toplevel(),
+
                 // THEORY: an empty tag never needs to get generalized
                 EmptyTagUnion => Rank::toplevel(),
 
@@ -820,7 +820,7 @@ pub(crate) fn type_to_var_help(
                             .drain()
                             .all(|(_, vals)| vals.is_empty()));
                     }
-                    Unified::Failure(_vars, actual_type, expected_type, _bad_impls) => {
+                    Unified::Failure(_vars, actual_type, expected_type, _bad_impls, details) => {
                         // No introduction needed
 
                         let problem = TypeError::BadExpr(
@@ -828,6 +828,7 @@ pub(crate) fn type_to_var_help(
                             category,
                             actual_type,
                             Expected::NoExpectation(expected_type),
+                            details,
                         );
 
                         problems.push(problem);
@@ -9,13 +9,19 @@ extern crate bumpalo;
 mod solve_expr {
     use roc_load::LoadedModule;
     use roc_solve::FunctionKind;
-    use test_solve_helpers::{format_problems, run_load_and_infer};
+    use roc_solve_problem::TypeError;
+    use test_solve_helpers::{
+        format_problems, run_load_and_infer, run_load_and_infer_with_step_budget,
+    };
 
-    use roc_types::pretty_print::{name_and_print_var, DebugPrint};
+    use roc_types::pretty_print::{name_and_print_var_with_aliases, Coloring, DebugPrint};
 
     // HELPERS
 
-    fn infer_eq_help(src: &str) -> Result<(String, String, String), std::io::Error> {
+    fn infer_eq_help(
+        src: &str,
+        debug_print: DebugPrint,
+    ) -> Result<(String, String, String), std::io::Error> {
         let (
             LoadedModule {
                 module_id: home,
@@ -24,6 +30,7 @@ mod solve_expr {
                 interns,
                 mut solved,
                 mut exposed_to_host,
+                exposed_aliases,
                 abilities_store,
                 ..
             },
@@ -33,15 +40,10 @@ mod solve_expr {
         let mut can_problems = can_problems.remove(&home).unwrap_or_default();
         let type_problems = type_problems.remove(&home).unwrap_or_default();
 
-        // Disregard UnusedDef problems, because those are unavoidable when
-        // returning a function from the test expression.
-        can_problems.retain(|prob| {
-            !matches!(
-                prob,
-                roc_problem::can::Problem::UnusedDef(_, _)
-                    | roc_problem::can::Problem::UnusedBranchDef(..)
-            )
-        });
+        // Disregard UnusedBranchDef problems, because those are unavoidable when
+        // returning a function from the test expression. Unused defs are reported as
+        // warnings, which are not checked here.
+        can_problems.retain(|prob| !matches!(prob, roc_problem::can::Problem::UnusedBranchDef(..)));
 
         let (can_problems, type_problems) =
             format_problems(&src, home, &interns, can_problems, type_problems);
@@ -52,13 +54,24 @@ mod solve_expr {
 
         debug_assert!(exposed_to_host.len() == 1, "{exposed_to_host:?}");
         let (_symbol, variable) = exposed_to_host.into_iter().next().unwrap();
-        let actual_str = name_and_print_var(variable, subs, home, &interns, DebugPrint::NOTHING);
+        let actual_str = name_and_print_var_with_aliases(
+            variable,
+            subs,
+            home,
+            &interns,
+            debug_print,
+            &exposed_aliases,
+        );
 
         Ok((type_problems, can_problems, actual_str))
     }
 
     fn infer_eq(src: &str, expected: &str) {
-        let (_, can_problems, actual) = infer_eq_help(src).unwrap();
+        infer_eq_with_debug_print(src, DebugPrint::NOTHING, expected)
+    }
+
+    fn infer_eq_with_debug_print(src: &str, debug_print: DebugPrint, expected: &str) {
+        let (_, can_problems, actual) = infer_eq_help(src, debug_print).unwrap();
 
         assert!(
             can_problems.is_empty(),
@@ -69,7 +82,8 @@ mod solve_expr {
     }
 
     fn infer_eq_without_problem(src: &str, expected: &str) {
-        let (type_problems, can_problems, actual) = infer_eq_help(src).unwrap();
+        let (type_problems, can_problems, actual) =
+            infer_eq_help(src, DebugPrint::NOTHING).unwrap();
 
         assert!(
             can_problems.is_empty(),
@@ -1059,6 +1073,82 @@ mod solve_expr {
         );
     }
 
+    // RANGES
+
+    #[test]
+    fn exclusive_range() {
+        infer_eq_without_problem(
+            indoc!(
+                r"
+                    1..10
+                "
+            ),
+            "List (Int *)",
+        );
+    }
+
+    #[test]
+    fn inclusive_range() {
+        infer_eq_without_problem(
+            indoc!(
+                r"
+                    \n -> 1..=n
+                "
+            ),
+            "Int a -> List (Int a)",
+        );
+    }
+
+    #[test]
+    fn descending_range() {
+        infer_eq_without_problem(
+            indoc!(
+                r"
+                    10..1
+                "
+            ),
+            "List (Int *)",
+        );
+    }
+
+    #[test]
+    fn range_of_non_numbers() {
+        let (type_problems, can_problems, _) = infer_eq_help(
+            indoc!(
+                r#"
+                    "a".."z"
+                "#
+            ),
+            DebugPrint::NOTHING,
+        )
+        .unwrap();
+
+        assert!(can_problems.is_empty(), "{can_problems}");
+        assert!(
+            type_problems.contains("TYPE MISMATCH"),
+            "unexpected problems: {type_problems}"
+        );
+    }
+
+    #[test]
+    fn range_of_fractions() {
+        let (type_problems, can_problems, _) = infer_eq_help(
+            indoc!(
+                r"
+                    1.5..3
+                "
+            ),
+            DebugPrint::NOTHING,
+        )
+        .unwrap();
+
+        assert!(can_problems.is_empty(), "{can_problems}");
+        assert!(
+            type_problems.contains("TYPE MISMATCH"),
+            "unexpected problems: {type_problems}"
+        );
+    }
+
     // RECORDS
 
     #[test]
@@ -1098,7 +1188,7 @@ mod solve_expr {
 
     #[test]
     fn tuple_literal_ty() {
-        infer_eq("(5, 3.14 )", "( Num *, Frac * )*");
+        infer_eq("(5, 3.14 )", "( Num *, Frac * )");
     }
 
     #[test]
@@ -1123,6 +1213,62 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn three_tuple_literal_ty() {
+        infer_eq("(1, \"a\", {})", "( Num *, Str, {} )");
+    }
+
+    #[test]
+    fn nested_tuple_literal_ty() {
+        infer_eq("((1, \"a\"), 3.14)", "( ( Num *, Str ), Frac * )");
+    }
+
+    #[test]
+    fn tuple_destructure_in_when() {
+        infer_eq_without_problem(
+            indoc!(
+                r"
+                \t ->
+                    when t is
+                        (x, y) -> { x, y }
+                "
+            ),
+            "( a, b )* -> { x : a, y : b }",
+        );
+    }
+
+    #[test]
+    fn three_tuple_destructure_in_when() {
+        infer_eq_without_problem(
+            indoc!(
+                r"
+                \t ->
+                    when t is
+                        (x, _, z) -> [x, z]
+                "
+            ),
+            "( a, *, a )* -> List a",
+        );
+    }
+
+    #[test]
+    fn mismatch_tuple_arity() {
+        infer_eq(
+            indoc!(
+                r"
+                pair : (U8, U8)
+                pair = (1, 2)
+
+                triple : (U8, U8, U8)
+                triple = (1, 2, 3)
+
+                [pair, triple]
+                "
+            ),
+            "List <type mismatch>",
+        );
+    }
+
     #[test]
     fn record_arg() {
         infer_eq("\\rec -> rec.x", "{ x : a }* -> a");
@@ -1255,6 +1401,53 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn record_update_multiple_fields() {
+        infer_eq(
+            indoc!(
+                r#"
+                    person = { name: "Sam", age: 30, town: "Oslo" }
+
+                    { person & age: 31, town: "Bergen" }
+                "#
+            ),
+            "{ age : Num *, name : Str, town : Str }",
+        );
+    }
+
+    #[test]
+    fn record_update_result_has_base_type() {
+        infer_eq(
+            indoc!(
+                r"
+                    \person -> { person & age: 31 }
+                "
+            ),
+            "{ age : Num a }b -> { age : Num a }b",
+        );
+    }
+
+    #[test]
+    fn record_update_of_missing_field_is_type_error() {
+        let (type_problems, can_problems, _) = infer_eq_help(
+            indoc!(
+                r#"
+                    person = { name: "Sam" }
+
+                    { person & age: 31 }
+                "#
+            ),
+            DebugPrint::NOTHING,
+        )
+        .unwrap();
+
+        assert!(can_problems.is_empty(), "{can_problems}");
+        assert!(
+            type_problems.contains("doesn’t have a `age` field"),
+            "unexpected problems: {type_problems}"
+        );
+    }
+
     #[test]
     fn bare_tag() {
         infer_eq(
@@ -2594,6 +2787,216 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn type_variables_named_past_z() {
+        infer_eq_without_problem(
+            "\\v1, v2, v3, v4, v5, v6, v7, v8, v9, v10, v11, v12, v13, v14, v15, v16, v17, v18, v19, v20, v21, v22, v23, v24, v25, v26, v27 -> (v27, v26, v25, v24, v23, v22, v21, v20, v19, v18, v17, v16, v15, v14, v13, v12, v11, v10, v9, v8, v7, v6, v5, v4, v3, v2, v1)",
+            "a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r, s, t, u, v, w, x, y, z, a1 -> ( a1, z, y, x, w, v, u, t, s, r, q, p, o, n, m, l, k, j, i, h, g, f, e, d, c, b, a )",
+        );
+    }
+
+    #[test]
+    fn recursion_variable_named_in_order_of_appearance() {
+        infer_eq_without_problem(
+            indoc!(
+                r"
+                    last = \list ->
+                        when list is
+                            Nil -> list
+                            Cons _ Nil -> list
+                            Cons _ rest -> last rest
+
+                    last
+                       "
+            ),
+            "[Cons a b, Nil] as b -> [Cons a b, Nil] as b",
+        );
+    }
+
+    fn nested_record(depth: usize) -> String {
+        format!("{}1{}", "{ a: ".repeat(depth), " }".repeat(depth))
+    }
+
+    #[test]
+    fn deeply_nested_record_without_limits() {
+        infer_eq(
+            &nested_record(50),
+            &format!("{}Num *{}", "{ a : ".repeat(50), " }".repeat(50)),
+        );
+    }
+
+    #[test]
+    fn deeply_nested_record_cut_off_at_max_depth() {
+        infer_eq_with_debug_print(
+            &nested_record(50),
+            DebugPrint {
+                max_depth: Some(3),
+                ..DebugPrint::NOTHING
+            },
+            "{ a : { a : { a : … } } }",
+        );
+    }
+
+    #[test]
+    fn deeply_nested_record_cut_off_at_max_length() {
+        infer_eq_with_debug_print(
+            &nested_record(50),
+            DebugPrint {
+                max_length: Some(20),
+                ..DebugPrint::NOTHING
+            },
+            "{ a : { a : { a : { …",
+        );
+    }
+
+    #[test]
+    fn recursion_variables_are_printed_within_max_depth() {
+        infer_eq_with_debug_print(
+            indoc!(
+                r"
+                    map = \peano ->
+                            when peano is
+                                Z -> Z
+                                S rest ->
+                                    map rest |> S
+
+
+                    map
+                "
+            ),
+            DebugPrint {
+                max_depth: Some(2),
+                ..DebugPrint::NOTHING
+            },
+            "[S a, Z] as a -> [S b, Z] as b",
+        );
+    }
+
+    fn print_with_coloring(coloring: Coloring) -> String {
+        let (_, _, actual) = infer_eq_help(
+            indoc!(
+                r"
+                    \record, n -> { name: record.name, count: n + 1u8 }
+                "
+            ),
+            DebugPrint {
+                coloring,
+                ..DebugPrint::NOTHING
+            },
+        )
+        .unwrap();
+
+        actual
+    }
+
+    #[test]
+    fn always_coloring_emits_escape_sequences() {
+        let actual = print_with_coloring(Coloring::Always);
+
+        // the constructor, the record fields and the type variables are colored
+        assert!(actual.contains("\u{1b}[36mU8\u{1b}[0m"), "{actual:?}");
+        assert!(actual.contains("\u{1b}[33mname\u{1b}[0m"), "{actual:?}");
+        assert!(actual.contains("\u{1b}[35ma\u{1b}[0m"), "{actual:?}");
+    }
+
+    #[test]
+    fn never_coloring_emits_no_escape_sequences() {
+        let actual = print_with_coloring(Coloring::Never);
+
+        assert!(!actual.contains('\u{1b}'), "{actual:?}");
+        assert_eq!(actual, "{ name : a }*, U8 -> { count : U8, name : a }");
+    }
+
+    #[test]
+    fn max_length_ignores_escape_sequences() {
+        let (_, _, actual) = infer_eq_help(
+            &nested_record(50),
+            DebugPrint {
+                max_length: Some(20),
+                coloring: Coloring::Always,
+                ..DebugPrint::NOTHING
+            },
+        )
+        .unwrap();
+
+        let without_colors = actual.replace("\u{1b}[33m", "").replace("\u{1b}[0m", "");
+
+        assert_eq!(without_colors, "{ a : { a : { a : { …");
+    }
+
+    const FOLD_ALIASES: DebugPrint = DebugPrint {
+        fold_aliases: true,
+        ..DebugPrint::NOTHING
+    };
+
+    #[test]
+    fn inferred_record_folds_into_alias() {
+        infer_eq_with_debug_print(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Model : { name : Str, count : Str }
+
+                main = { name: "Bob", count: "one" }
+                "#
+            ),
+            FOLD_ALIASES,
+            "Model",
+        );
+    }
+
+    #[test]
+    fn inferred_record_folds_into_alias_with_arguments() {
+        infer_eq_with_debug_print(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Named a : { name : Str, value : a }
+
+                main = [{ name: "Bob", value: Bool.true }]
+                "#
+            ),
+            FOLD_ALIASES,
+            "List (Named Bool)",
+        );
+    }
+
+    #[test]
+    fn inferred_builtin_folds_into_alias_with_arguments() {
+        infer_eq_with_debug_print(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Table a : Dict Str a
+
+                main = Dict.single "one" (Num.toI64 1)
+                "#
+            ),
+            FOLD_ALIASES,
+            "Table I64",
+        );
+    }
+
+    #[test]
+    fn record_with_other_fields_does_not_fold_into_alias() {
+        infer_eq_with_debug_print(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Model : { name : Str }
+
+                main = { name: "Bob", count: "one" }
+                "#
+            ),
+            FOLD_ALIASES,
+            "{ count : Str, name : Str }",
+        );
+    }
+
     #[test]
     fn typecheck_linked_list_map() {
         infer_eq_without_problem(
@@ -3509,6 +3912,42 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn guard_sees_pattern_bindings() {
+        infer_eq_without_problem(
+            indoc!(
+                r"
+                \x ->
+                    when x is
+                        n if n > 0 -> n
+                        _ -> 0
+                "
+            ),
+            "Num a -> Num a",
+        );
+    }
+
+    #[test]
+    fn guard_on_pattern_binding_must_be_bool() {
+        let (type_problems, can_problems, _) = infer_eq_help(
+            indoc!(
+                r#"
+                    when "hello" is
+                        s if s -> 1
+                        _ -> 0
+                "#
+            ),
+            DebugPrint::NOTHING,
+        )
+        .unwrap();
+
+        assert!(can_problems.is_empty(), "{can_problems}");
+        assert!(
+            type_problems.contains("guard condition needs to be a Bool"),
+            "unexpected problems: {type_problems}"
+        );
+    }
+
     #[test]
     fn sorting() {
         // based on https://github.com/elm/compiler/issues/2057
@@ -5140,4 +5579,171 @@ mod solve_expr {
             r"{ bi128 : I128 -> I128, bi16 : I16 -> I16, bi32 : I32 -> I32, bi64 : I64 -> I64, bi8 : I8 -> I8, bu128 : U128 -> U128, bu16 : U16 -> U16, bu32 : U32 -> U32, bu64 : U64 -> U64, bu8 : U8 -> U8, dec : Dec -> Dec, f32 : F32 -> F32, f64 : F64 -> F64, fdec : Dec -> Dec, ff32 : F32 -> F32, ff64 : F64 -> F64, i128 : I128 -> I128, i16 : I16 -> I16, i32 : I32 -> I32, i64 : I64 -> I64, i8 : I8 -> I8, u128 : U128 -> U128, u16 : U16 -> U16, u32 : U32 -> U32, u64 : U64 -> U64, u8 : U8 -> U8 }",
         )
     }
+
+    #[test]
+    fn hover_type_of_innermost_expression() {
+        use roc_can::traverse::{type_at_offset, typed_regions};
+
+        let (
+            LoadedModule {
+                module_id: home,
+                interns,
+                mut solved,
+                mut declarations_by_id,
+                ..
+            },
+            src,
+        ) = run_load_and_infer("\\x -> x + 1", [], false, FunctionKind::LambdaSet).unwrap();
+
+        let decls = declarations_by_id.remove(&home).unwrap();
+        let table = typed_regions(&decls);
+        let subs = solved.inner_mut();
+
+        let body = src.find("x + 1").unwrap() as u32;
+
+        // the `x` in the body
+        let (region, x_type) = type_at_offset(subs, home, &interns, &table, body).unwrap();
+        assert_eq!((region.start().offset, region.len()), (body, 1));
+        assert_eq!(x_type, "Num *");
+
+        // the space after `x` is only covered by the whole body
+        let (region, body_type) = type_at_offset(subs, home, &interns, &table, body + 1).unwrap();
+        assert_eq!((region.start().offset, region.len()), (body, 5));
+        assert_eq!(body_type, "Num *");
+    }
+
+    #[test]
+    fn debug_dump_of_solved_identity() {
+        let dump = || {
+            let (
+                LoadedModule {
+                    mut solved,
+                    mut exposed_to_host,
+                    ..
+                },
+                _,
+            ) = run_load_and_infer(
+                indoc!(
+                    r"
+                    id = \x -> x
+
+                    id
+                    "
+                ),
+                [],
+                false,
+                FunctionKind::LambdaSet,
+            )
+            .unwrap();
+
+            let (_symbol, var) = exposed_to_host.drain().next().unwrap();
+
+            solved.inner_mut().debug_dump_from([var])
+        };
+
+        let first = dump();
+        let mut lines = first.lines();
+
+        // the argument and the result are the same root
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("v0 = Func([v1], v2, v1, v3) at "),
+            "{first}"
+        );
+        assert!(
+            lines.next().unwrap().starts_with("v1 = Flex at "),
+            "{first}"
+        );
+
+        assert_eq!(first, dump());
+    }
+
+    #[test]
+    fn crash_has_polymorphic_type() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    crash "boom"
+                "#
+            ),
+            "*",
+        );
+    }
+
+    #[test]
+    fn crash_takes_type_of_surrounding_branch() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                    \b -> if b then "ok" else crash "boom"
+                "#
+            ),
+            "Bool -> Str",
+        );
+    }
+
+    #[test]
+    fn crash_message_must_be_str() {
+        let (type_problems, can_problems, _) = infer_eq_help(
+            indoc!(
+                r"
+                    crash 42
+                "
+            ),
+            DebugPrint::NOTHING,
+        )
+        .unwrap();
+
+        assert!(can_problems.is_empty(), "{can_problems}");
+        assert!(
+            type_problems.contains("is not a string"),
+            "unexpected problems: {type_problems}"
+        );
+    }
+
+    // STEP BUDGET
+
+    /// `[[[ ... [1] ... ]]]`, nested `depth` lists deep
+    fn nested_list(depth: usize) -> String {
+        format!("{}1{}", "[".repeat(depth), "]".repeat(depth))
+    }
+
+    fn type_problems_with_step_budget(src: &str, step_budget: u64) -> Vec<TypeError> {
+        let (
+            LoadedModule {
+                module_id: home,
+                mut type_problems,
+                ..
+            },
+            _,
+        ) = run_load_and_infer_with_step_budget(
+            src,
+            [],
+            false,
+            FunctionKind::LambdaSet,
+            Some(step_budget),
+        )
+        .unwrap();
+
+        type_problems.remove(&home).unwrap_or_default()
+    }
+
+    #[test]
+    fn solve_gives_up_when_out_of_steps() {
+        let type_problems = type_problems_with_step_budget(&nested_list(64), 4);
+
+        assert!(
+            type_problems.contains(&TypeError::BudgetExceeded(4)),
+            "{type_problems:?}"
+        );
+    }
+
+    #[test]
+    fn solve_within_step_budget() {
+        let type_problems = type_problems_with_step_budget(&nested_list(64), 1_000_000);
+
+        assert!(type_problems.is_empty(), "{type_problems:?}");
+    }
 }
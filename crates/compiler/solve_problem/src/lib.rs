@@ -14,11 +14,17 @@ use roc_module::{
 use roc_problem::{can::CycleEntry, Severity};
 use roc_region::all::Region;
 
-use roc_types::types::{Category, ErrorType, PatternCategory};
+use roc_types::types::{Category, ErrorType, MismatchDetails, PatternCategory};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeError {
-    BadExpr(Region, Category, ErrorType, Expected<ErrorType>),
+    BadExpr(
+        Region,
+        Category,
+        ErrorType,
+        Expected<ErrorType>,
+        MismatchDetails,
+    ),
     BadPattern(Region, PatternCategory, ErrorType, PExpected<ErrorType>),
     CircularType(Region, Symbol, ErrorType),
     CircularDef(Vec<CycleEntry>),
@@ -50,6 +56,8 @@ pub enum TypeError {
     UnsuffixedEffectfulFunction(Region, FxSuffixKind),
     SuffixedPureFunction(Region, FxSuffixKind),
     InvalidTryTarget(Region, ErrorType, TryKind),
+    /// Solving gave up after this many unifications; see `SolveConfig::step_budget`.
+    BudgetExceeded(u64),
 }
 
 impl TypeError {
@@ -80,6 +88,7 @@ impl TypeError {
             TypeError::UnsuffixedEffectfulFunction(_, _) => Warning,
             TypeError::SuffixedPureFunction(_, _) => Warning,
             TypeError::InvalidTryTarget(_, _, _) => RuntimeError,
+            TypeError::BudgetExceeded(_) => Fatal,
         }
     }
 
@@ -107,6 +116,7 @@ impl TypeError {
             TypeError::CircularDef(c) => c.first().map(|ce| ce.symbol_region),
             TypeError::IngestedFileBadUtf8(_, _) => None,
             TypeError::IngestedFileUnsupportedType(_, _) => None,
+            TypeError::BudgetExceeded(_) => None,
         }
     }
 }
@@ -42,15 +42,10 @@ fn specialize_expr<'a>(
     let mut can_problems = can_problems.remove(&home).unwrap_or_default();
     let type_problems = type_problems.remove(&home).unwrap_or_default();
 
-    // Disregard UnusedDef problems, because those are unavoidable when
-    // returning a function from the test expression.
-    can_problems.retain(|prob| {
-        !matches!(
-            prob,
-            roc_problem::can::Problem::UnusedDef(_, _)
-                | roc_problem::can::Problem::UnusedBranchDef(..)
-        )
-    });
+    // Disregard UnusedBranchDef problems, because those are unavoidable when
+    // returning a function from the test expression. Unused defs are reported as
+    // warnings, which are not checked here.
+    can_problems.retain(|prob| !matches!(prob, roc_problem::can::Problem::UnusedBranchDef(..)));
 
     let (can_problems, type_problems) =
         format_problems(&src, home, &interns, can_problems, type_problems);
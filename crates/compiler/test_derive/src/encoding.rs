@@ -277,12 +277,12 @@ fn two_field_record() {
 fn two_field_tuple() {
     derive_test(ToEncoder, v!((v!(U8), v!(STR),)), |golden| {
         assert_snapshot!(golden, @r###"
-        # derived for ( U8, Str )*
-        # ( val, val1 )* -[[toEncoder_(arity:2)(0)]]-> Encoder fmt where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding
-        # ( val, val1 )a -[[toEncoder_(arity:2)(0)]]-> (List U8, fmt -[[custom(2) ( val, val1 )a]]-> List U8) where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding
+        # derived for ( U8, Str )
+        # ( val, val1 ) -[[toEncoder_(arity:2)(0)]]-> Encoder fmt where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding
+        # ( val, val1 ) -[[toEncoder_(arity:2)(0)]]-> (List U8, fmt -[[custom(2) ( val, val1 )]]-> List U8) where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding
         # Specialization lambda sets:
         #   @<1>: [[toEncoder_(arity:2)(0)]]
-        #   @<2>: [[custom(2) ( val, val1 )*]] where val implements Encoding, val1 implements Encoding
+        #   @<2>: [[custom(2) ( val, val1 )]] where val implements Encoding, val1 implements Encoding
         #Derived.toEncoder_(arity:2) =
           \#Derived.tup ->
             custom
@@ -366,11 +366,11 @@ fn tag_two_labels() {
         |golden| {
             assert_snapshot!(golden, @r###"
             # derived for [A U8 Str U16, B Str]
-            # [A val val1 val1, B val1] -[[toEncoder_[A 3,B 1](0)]]-> Encoder fmt where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding
-            # [A val val1 val1, B val1] -[[toEncoder_[A 3,B 1](0)]]-> (List U8, fmt -[[custom(6) [A val val1 val1, B val1]]]-> List U8) where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding
+            # [A val val1 val2, B val3] -[[toEncoder_[A 3,B 1](0)]]-> Encoder fmt where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding, val2 implements Encoding, val3 implements Encoding
+            # [A val val1 val2, B val3] -[[toEncoder_[A 3,B 1](0)]]-> (List U8, fmt -[[custom(6) [A val val1 val2, B val3]]]-> List U8) where fmt implements EncoderFormatting, val implements Encoding, val1 implements Encoding, val2 implements Encoding, val3 implements Encoding
             # Specialization lambda sets:
             #   @<1>: [[toEncoder_[A 3,B 1](0)]]
-            #   @<2>: [[custom(6) [A val val1 val1, B val1]]] where val implements Encoding, val1 implements Encoding
+            #   @<2>: [[custom(6) [A val val1 val2, B val3]]] where val implements Encoding, val1 implements Encoding, val2 implements Encoding, val3 implements Encoding
             #Derived.toEncoder_[A 3,B 1] =
               \#Derived.tag ->
                 custom
@@ -440,6 +440,8 @@ fn check_derived_typechecks_and_golden(
         module_params: None,
         module_params_vars: imported_param_vars,
         host_exposed_symbols: None,
+        trace_generalization: false,
+        step_budget: None,
 
         #[cfg(debug_assertions)]
         checkmate: None,
@@ -58,6 +58,7 @@ fn roc_function<'a, 'b>(
         mode: LlvmBackendMode::GenTest,
         ignore_problems: false,
         emit_debug_info: true,
+        overflow_checks: true,
         opt_level: OptLevel::Optimize,
     };
 
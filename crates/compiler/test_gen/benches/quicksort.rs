@@ -87,6 +87,7 @@ fn roc_function<'a>(
         mode: LlvmBackendMode::GenTest,
         ignore_problems: false,
         emit_debug_info: true,
+        overflow_checks: true,
         opt_level: OptLevel::Optimize,
     };
 
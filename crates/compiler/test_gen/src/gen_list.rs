@@ -4044,6 +4044,15 @@ fn list_range_length_overflow() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn range_operators() {
+    assert_evals_to!("1..5", RocList::from_slice(&[1, 2, 3, 4]), RocList<i64>);
+    assert_evals_to!("1..=5", RocList::from_slice(&[1, 2, 3, 4, 5]), RocList<i64>);
+    assert_evals_to!("5..1", RocList::from_slice(&[5, 4, 3, 2]), RocList<i64>);
+    assert_evals_to!("5..=1", RocList::from_slice(&[5, 4, 3, 2, 1]), RocList<i64>);
+}
+
 mod pattern_match {
     #[allow(unused_imports)]
     use crate::helpers::with_larger_debug_stack;
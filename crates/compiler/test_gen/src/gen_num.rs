@@ -1946,6 +1946,48 @@ fn int_add_overflow() {
     assert_evals_to!("9_223_372_036_854_775_807 + 1", 0, i64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+#[should_panic(expected = r#"Roc failed with message: "Integer addition overflowed!"#)]
+fn max_i32_add_overflow() {
+    assert_evals_to!("Num.maxI32 + 1", 0, i32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn max_i32_add_wrap() {
+    assert_evals_to!("Num.addWrap Num.maxI32 1", i32::MIN, i32);
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn i32_arithmetic_wraps_without_overflow_checks() {
+    use crate::helpers::llvm::{identity, llvm_evals_to_with_config, HelperConfig, OPT_LEVEL};
+    use roc_gen_llvm::llvm::build::LlvmBackendMode;
+
+    let config = HelperConfig {
+        mode: LlvmBackendMode::GenTest,
+        ignore_problems: false,
+        emit_debug_info: false,
+        overflow_checks: false,
+        opt_level: OPT_LEVEL,
+    };
+
+    for (src, expected) in [
+        ("Num.maxI32 + 1", i32::MIN),
+        ("Num.minI32 - 1", i32::MAX),
+        ("Num.maxI32 * 2", -2),
+    ] {
+        llvm_evals_to_with_config(
+            src,
+            expected,
+            identity::<i32>,
+            config,
+            roc_load::FunctionKind::LambdaSet,
+        );
+    }
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn int_add_checked_ok() {
@@ -17,6 +17,105 @@ fn basic_int() {
     assert_evals_to!("123", 123, i64);
 }
 
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn emit_llvm_ir_of_main() {
+    let ir = crate::helpers::llvm::llvm_ir("123", false);
+
+    assert!(ir.contains("define"), "{ir}");
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn emit_debug_info_only_when_requested() {
+    // the stripping itself happens in roc_build::llvm_passes::optimize_llvm_ir
+    let with_debug_info = crate::helpers::llvm::llvm_ir("123", true);
+    let without_debug_info = crate::helpers::llvm::llvm_ir("123", false);
+
+    assert!(
+        with_debug_info.contains("!llvm.dbg.cu"),
+        "{with_debug_info}"
+    );
+    assert!(
+        !without_debug_info.contains("!llvm.dbg.cu"),
+        "{without_debug_info}"
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn debug_info_points_procedures_at_their_source_line() {
+    let ir = crate::helpers::llvm::llvm_ir(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            double = \x -> x * 2
+
+            main = double 21
+            "#
+        ),
+        true,
+    );
+
+    let double = ir
+        .lines()
+        .find(|line| line.contains("!DISubprogram(") && line.contains("_double_"))
+        .unwrap_or_else(|| panic!("no subprogram for `double` in\n\n{ir}"));
+
+    assert!(double.contains(" line: 3,"), "{double}");
+    assert!(ir.contains(r#"!DIFile(filename: "Test.roc""#), "{ir}");
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn llvm_ir_is_deterministic() {
+    let src = indoc!(
+        r#"
+        double = \x -> x * 2
+        triple = \x -> x * 3
+        greet = \name -> Str.concat "Hello, " name
+
+        List.map [double 1, triple 2] Num.toStr
+        |> List.append (greet "World")
+        |> Str.joinWith ", "
+        "#
+    );
+
+    let first = crate::helpers::llvm::llvm_ir(src, false);
+    let second = crate::helpers::llvm::llvm_ir(src, false);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg(feature = "gen-wasm")]
+fn trivial_program_compiles_to_valid_wasm() {
+    let arena = bumpalo::Bump::new();
+    let bytes =
+        crate::helpers::wasm::compile_to_wasm_bytes(&arena, "1", std::marker::PhantomData::<i64>);
+
+    assert_eq!(&bytes[..4], b"\0asm");
+
+    let require_relocatable = false;
+    roc_wasm_module::WasmModule::preload(&arena, &bytes, require_relocatable).unwrap();
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn trivial_program_compiles_to_wasm_object() {
+    use roc_gen_llvm::llvm::build::CodegenTarget;
+
+    let bytes = crate::helpers::llvm::object_file("1", CodegenTarget::Wasm32);
+
+    assert_eq!(&bytes[..4], b"\0asm");
+
+    // LLVM emits a relocatable object, to be linked with the host
+    let arena = bumpalo::Bump::new();
+    let require_relocatable = true;
+    roc_wasm_module::WasmModule::preload(&arena, &bytes, require_relocatable).unwrap();
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn basic_float() {
@@ -3577,6 +3676,27 @@ fn list_map2_conslist() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn self_recursive_tail_call_runs_in_constant_stack() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [ main ] to "./platform"
+
+            sumFrom = \list, index, total ->
+                when List.get list index is
+                    Ok x -> sumFrom list (index + 1) (total + x)
+                    Err _ -> total
+
+            main = sumFrom (List.repeat 1 1_000_000) 0 0
+            "#
+        ),
+        1_000_000,
+        i64
+    )
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn mutual_recursion_top_level_defs() {
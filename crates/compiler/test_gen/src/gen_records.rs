@@ -205,6 +205,21 @@ fn when_record_with_guard_pattern() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn when_record_with_rest_pattern() {
+    assert_evals_to!(
+        indoc!(
+            r"
+                when { x: 0x2, y: 3, z: 4 } is
+                    { x, ..rest } -> x + rest.y + rest.z
+                "
+        ),
+        9,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn let_with_record_pattern() {
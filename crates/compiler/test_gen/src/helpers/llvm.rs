@@ -7,16 +7,19 @@ use libloading::Library;
 use roc_build::link::llvm_module_to_dylib;
 use roc_collections::all::MutSet;
 use roc_command_utils::zig;
+use roc_gen_llvm::llvm::build::{
+    emit_llvm_ir, verify_module, CodegenTarget, LlvmBackendMode, SourceFiles,
+};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
-use roc_gen_llvm::{llvm::build::LlvmBackendMode, run_roc::RocCallResult};
+use roc_gen_llvm::run_roc::RocCallResult;
 use roc_load::{
-    EntryPoint, ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, Threading,
+    EntryPoint, ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, ShadowPolicy,
+    Threading,
 };
 use roc_mono::ir::{CrashTag, OptLevel, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
 use roc_region::all::LineInfo;
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
-use roc_target::Target;
 
 #[cfg(feature = "gen-llvm-wasm")]
 use crate::helpers::from_wasm32_memory::FromWasm32Memory;
@@ -52,9 +55,10 @@ fn create_llvm_module<'a>(
     src: &str,
     config: HelperConfig,
     context: &'a inkwell::context::Context,
-    target: Target,
+    codegen_target: CodegenTarget,
     function_kind: FunctionKind,
 ) -> (&'static str, String, &'a Module<'a>) {
+    let target = codegen_target.target();
     let filename = PathBuf::from("Test.roc");
     let src_dir = PathBuf::from("fake/test/path");
 
@@ -72,6 +76,8 @@ fn create_llvm_module<'a>(
     let load_config = LoadConfig {
         target,
         function_kind,
+        step_budget: None,
+        shadow_policy: ShadowPolicy::Disallow,
         render: RenderTarget::ColorTerminal,
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
@@ -112,7 +118,7 @@ fn create_llvm_module<'a>(
     // errors whose reporting we delay (so we can see that code gen generates runtime errors)
     let mut delayed_errors = Vec::new();
 
-    for (home, (module_path, src)) in loaded.sources {
+    for (&home, (module_path, src)) in loaded.sources.iter() {
         use roc_reporting::report::{can_problem, type_problem, RocDocAllocator};
 
         let can_problems = loaded.can_problems.remove(&home).unwrap_or_default();
@@ -124,7 +130,7 @@ fn create_llvm_module<'a>(
             continue;
         }
 
-        let line_info = LineInfo::new(&src);
+        let line_info = LineInfo::new(src);
         let src_lines: Vec<&str> = src.split('\n').collect();
         let palette = DEFAULT_PALETTE;
 
@@ -135,8 +141,7 @@ fn create_llvm_module<'a>(
         for problem in can_problems.into_iter() {
             match problem {
                 // Ignore "unused" problems
-                UnusedDef(_, _)
-                | UnusedArgument(_, _, _, _)
+                UnusedArgument(_, _, _, _)
                 | UnusedModuleImport(_, _)
                 | RuntimeError(_)
                 | UnsupportedPattern(_, _)
@@ -229,6 +234,12 @@ fn create_llvm_module<'a>(
         mode: config.mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        overflow_checks: config.overflow_checks,
+        source_files: if config.emit_debug_info {
+            SourceFiles::new(&loaded.sources)
+        } else {
+            SourceFiles::default()
+        },
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
@@ -280,12 +291,11 @@ fn create_llvm_module<'a>(
 
     let ll_file_path = std::env::temp_dir().join("test.ll");
     let opt_level = OptLevel::Development;
-    let emit_debug_info = true;
     roc_build::llvm_passes::optimize_llvm_ir(
         &env,
         target,
         opt_level,
-        emit_debug_info,
+        config.emit_debug_info,
         &ll_file_path,
     );
 
@@ -306,6 +316,7 @@ pub struct HelperConfig {
     pub mode: LlvmBackendMode,
     pub ignore_problems: bool,
     pub emit_debug_info: bool,
+    pub overflow_checks: bool,
     pub opt_level: OptLevel,
 }
 
@@ -318,24 +329,99 @@ pub fn helper<'a>(
     context: &'a inkwell::context::Context,
     function_kind: FunctionKind,
 ) -> (&'static str, String, Library) {
-    let target = target_lexicon::Triple::host().into();
+    let codegen_target = CodegenTarget::Native;
 
     let (main_fn_name, delayed_errors, module) =
-        create_llvm_module(arena, src, config, context, target, function_kind);
+        create_llvm_module(arena, src, config, context, codegen_target, function_kind);
 
     // for debugging:
     //module.print_to_file(std::path::Path::new("/home/username/roc/llvm_ir.ll")).unwrap();
 
-    if !config.emit_debug_info {
-        module.strip_debug_info();
-    }
-    let res_lib = llvm_module_to_dylib(module, target, config.opt_level);
+    let res_lib = llvm_module_to_dylib(module, codegen_target.target(), config.opt_level);
 
     let lib = res_lib.expect("Error loading compiled dylib for test");
 
     (main_fn_name, delayed_errors, lib)
 }
 
+/// Compile `src` and return the textual LLVM IR of the resulting module, after checking that
+/// LLVM's verifier accepts it.
+#[allow(dead_code)]
+pub fn llvm_ir(src: &str, emit_debug_info: bool) -> String {
+    let arena = bumpalo::Bump::new();
+    let context = inkwell::context::Context::create();
+    let config = HelperConfig {
+        mode: LlvmBackendMode::GenTest,
+        ignore_problems: false,
+        emit_debug_info,
+        overflow_checks: true,
+        opt_level: OPT_LEVEL,
+    };
+
+    let (_main_fn_name, delayed_errors, module) = create_llvm_module(
+        &arena,
+        src,
+        config,
+        &context,
+        CodegenTarget::Native,
+        FunctionKind::LambdaSet,
+    );
+
+    assert!(delayed_errors.is_empty(), "{delayed_errors}");
+
+    if let Err(error) = verify_module(module) {
+        panic!("LLVM rejected the module:\n\n{}", error.message);
+    }
+
+    emit_llvm_ir(module)
+}
+
+/// Compile `src` for `codegen_target` and return the contents of the resulting object file.
+#[allow(dead_code)]
+pub fn object_file(src: &str, codegen_target: CodegenTarget) -> Vec<u8> {
+    use roc_build::target::{convert_opt_level, write_object_file};
+
+    let arena = bumpalo::Bump::new();
+    let context = inkwell::context::Context::create();
+    let config = HelperConfig {
+        mode: match codegen_target {
+            CodegenTarget::Native => LlvmBackendMode::GenTest,
+            CodegenTarget::Wasm32 => LlvmBackendMode::WasmGenTest,
+        },
+        ignore_problems: false,
+        emit_debug_info: false,
+        overflow_checks: true,
+        opt_level: OPT_LEVEL,
+    };
+
+    let (_main_fn_name, delayed_errors, module) = create_llvm_module(
+        &arena,
+        src,
+        config,
+        &context,
+        codegen_target,
+        FunctionKind::LambdaSet,
+    );
+
+    assert!(delayed_errors.is_empty(), "{delayed_errors}");
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir
+        .path()
+        .join("app")
+        .with_extension(codegen_target.object_file_extension());
+
+    write_object_file(
+        module,
+        codegen_target.target(),
+        convert_opt_level(config.opt_level),
+        &path,
+    )
+    .unwrap();
+
+    std::fs::read(path).unwrap()
+}
+
 #[allow(dead_code)]
 fn write_final_wasm() -> bool {
     #[allow(unused_imports)]
@@ -360,10 +446,14 @@ fn compile_to_wasm_bytes<'a>(
     static TEMP_DIR: OnceLock<tempfile::TempDir> = OnceLock::new();
     let temp_dir = TEMP_DIR.get_or_init(|| tempfile::tempdir().unwrap());
 
-    let target = Target::Wasm32;
-
-    let (_main_fn_name, _delayed_errors, llvm_module) =
-        create_llvm_module(arena, src, config, context, target, function_kind);
+    let (_main_fn_name, _delayed_errors, llvm_module) = create_llvm_module(
+        arena,
+        src,
+        config,
+        context,
+        CodegenTarget::Wasm32,
+        function_kind,
+    );
 
     let content_hash = crate::helpers::src_hash(src);
     let wasm_file = llvm_module_to_wasm_file(temp_dir, content_hash, llvm_module);
@@ -465,6 +555,7 @@ where
     let config = HelperConfig {
         mode: LlvmBackendMode::WasmGenTest,
         emit_debug_info: false,
+        overflow_checks: true,
         ignore_problems,
         opt_level: OPT_LEVEL,
     };
@@ -536,19 +627,35 @@ pub(crate) fn llvm_evals_to<T, U, F>(
     U: PartialEq + std::fmt::Debug,
     F: FnOnce(T) -> U,
 {
-    use bumpalo::Bump;
-    use inkwell::context::Context;
-
-    let arena = Bump::new();
-    let context = Context::create();
-
     let config = crate::helpers::llvm::HelperConfig {
         mode: LlvmBackendMode::GenTest,
         emit_debug_info: false,
+        overflow_checks: true,
         ignore_problems,
         opt_level: crate::helpers::llvm::OPT_LEVEL,
     };
 
+    llvm_evals_to_with_config(src, expected, transform, config, function_kind)
+}
+
+/// Like [llvm_evals_to], but with a custom [HelperConfig]
+#[allow(dead_code)]
+pub(crate) fn llvm_evals_to_with_config<T, U, F>(
+    src: &str,
+    expected: U,
+    transform: F,
+    config: HelperConfig,
+    function_kind: FunctionKind,
+) where
+    U: PartialEq + std::fmt::Debug,
+    F: FnOnce(T) -> U,
+{
+    use bumpalo::Bump;
+    use inkwell::context::Context;
+
+    let arena = Bump::new();
+    let context = Context::create();
+
     let (main_fn_name, errors, lib) =
         crate::helpers::llvm::helper(&arena, config, src, &context, function_kind);
 
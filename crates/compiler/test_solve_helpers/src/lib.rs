@@ -49,6 +49,16 @@ pub fn run_load_and_infer<'a>(
     dependencies: impl IntoIterator<Item = (&'a str, &'a str)>,
     no_promote: bool,
     function_kind: FunctionKind,
+) -> Result<(LoadedModule, String), std::io::Error> {
+    run_load_and_infer_with_step_budget(src, dependencies, no_promote, function_kind, None)
+}
+
+pub fn run_load_and_infer_with_step_budget<'a>(
+    src: &str,
+    dependencies: impl IntoIterator<Item = (&'a str, &'a str)>,
+    no_promote: bool,
+    function_kind: FunctionKind,
+    step_budget: Option<u64>,
 ) -> Result<(LoadedModule, String), std::io::Error> {
     use tempfile::tempdir;
 
@@ -82,6 +92,7 @@ pub fn run_load_and_infer<'a>(
             None,
             roc_target::Target::LinuxX64,
             function_kind,
+            step_budget,
             roc_reporting::report::RenderTarget::Generic,
             RocCacheDir::Disallowed,
             roc_reporting::report::DEFAULT_PALETTE,
@@ -262,6 +273,7 @@ pub struct InferOptions {
     pub print_only_under_alias: bool,
     pub print_ranks: bool,
     pub print_variables: bool,
+    pub hide_lambda_sets: bool,
     pub no_promote: bool,
 }
 
@@ -469,12 +481,13 @@ impl<'a> QueryCtx<'a> {
             self.home,
             self.interns,
             DebugPrint {
-                print_lambda_sets: true,
+                print_lambda_sets: !self.options.hide_lambda_sets,
                 print_only_under_alias: self.options.print_only_under_alias,
                 print_ranks: self.options.print_ranks,
                 print_variables: self.options.print_variables,
                 ignore_polarity: true,
                 print_weakened_vars: true,
+                ..DebugPrint::NOTHING
             },
         );
 
@@ -206,17 +206,19 @@ impl<'a> Output<'a> {
                 // rules multiple times unnecessarily.
                 let loc_expr = desugar::desugar_expr(&mut env, &mut scope, loc_expr);
 
-                scope.add_alias(
-                    Symbol::NUM_INT,
-                    Region::zero(),
-                    vec![Loc::at_zero(AliasVar::unbound(
-                        "a".into(),
-                        Variable::EMPTY_RECORD,
-                    ))],
-                    vec![],
-                    Type::EmptyRec,
-                    roc_types::types::AliasKind::Structural,
-                );
+                scope
+                    .add_alias(
+                        Symbol::NUM_INT,
+                        Region::zero(),
+                        vec![Loc::at_zero(AliasVar::unbound(
+                            "a".into(),
+                            Variable::EMPTY_RECORD,
+                        ))],
+                        vec![],
+                        Type::EmptyRec,
+                        roc_types::types::AliasKind::Structural,
+                    )
+                    .unwrap();
 
                 let (_loc_expr, _output) = canonicalize_expr(
                     &mut env,
@@ -0,0 +1 @@
+0x_ff
\ No newline at end of file
@@ -0,0 +1 @@
+1__23
\ No newline at end of file
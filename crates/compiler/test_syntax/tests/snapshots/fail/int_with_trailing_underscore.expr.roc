@@ -0,0 +1 @@
+1_000_
\ No newline at end of file
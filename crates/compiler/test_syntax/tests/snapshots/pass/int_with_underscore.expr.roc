@@ -1 +1 @@
-1__23
\ No newline at end of file
+1_000_000
\ No newline at end of file
@@ -4,15 +4,20 @@ extern crate indoc;
 #[cfg(test)]
 mod test_fmt {
     use bumpalo::Bump;
+    use roc_fmt::annotation::Formattable;
     use roc_fmt::def::fmt_defs;
     use roc_fmt::header::fmt_header;
-    use roc_fmt::{Buf, MigrationFlags};
-    use roc_parse::ast::{Defs, Header, SpacesBefore};
-    use roc_parse::header::{self, parse_module_defs};
+    use roc_fmt::{format_ast, format_range, Buf, FormatOptions, MigrationFlags};
+    use roc_parse::ast::{
+        Collection, CommentOrNewline, Defs, Expr, FullAst, Header, Pattern, Spaced, SpacesBefore,
+        ValueDef,
+    };
+    use roc_parse::header::{self, parse_module_defs, ExposedName, ModuleHeader};
     use roc_parse::state::State;
+    use roc_region::all::{Loc, Position, Region};
     use roc_test_utils::assert_multiline_str_eq;
     use roc_test_utils_dir::workspace_root;
-    use test_syntax::test_helpers::Input;
+    use test_syntax::test_helpers::{Input, Output};
 
     fn check_formatting(expected: &'_ str) -> impl Fn(Input) + '_ {
         let expected = expected.trim();
@@ -37,6 +42,69 @@ mod test_fmt {
         )
     }
 
+    /// The `#` comments in `src`, in order. Only meant for test inputs without `#` in strings.
+    fn comments(src: &str) -> Vec<&str> {
+        src.lines()
+            .filter_map(|line| line.find('#').map(|start| line[start..].trim_end()))
+            .collect()
+    }
+
+    /// Check that formatting keeps every comment, in the same order, and that formatting the
+    /// output again doesn't change it, without pinning down the exact layout.
+    fn expr_formats_keeping_comments(input: &str) {
+        let input = input.trim();
+        let expected_comments = comments(input);
+
+        Input::Expr(input).check_invariants(
+            |output| assert_eq!(expected_comments, comments(output.as_str())),
+            true,
+            Some(false),
+        )
+    }
+
+    /// Format the expression `input` with the given `max_width`, and check that the result is
+    /// `expected` and then stays the same when formatted with the default options.
+    fn expr_formats_with_max_width(input: &str, max_width: usize, expected: &str) {
+        let arena = Bump::new();
+        let expected = expected.trim();
+
+        let expr = match Input::Expr(input.trim()).parse_in(&arena) {
+            Ok(Output::Expr(expr)) => expr,
+            other => panic!("Expected an expression, got {other:?}"),
+        };
+
+        let options = FormatOptions {
+            max_width,
+            ..FormatOptions::default()
+        };
+        let mut buf = Buf::new_in_with_options(&arena, MigrationFlags::new(false), options);
+        expr.format(&mut buf, 0);
+
+        assert_multiline_str_eq!(expected, buf.as_str());
+
+        expr_formats_same(expected);
+    }
+
+    /// Range-format the module `src` with the first occurrence of `selection` selected, and
+    /// return the whole module with the replacement applied.
+    fn format_selection(src: &str, selection: &str) -> String {
+        let arena = Bump::new();
+        let start = src.find(selection).expect("selection not found in src");
+        let region = Region::new(
+            Position::new(start as u32),
+            Position::new((start + selection.len()) as u32),
+        );
+
+        let formatted = format_range(&arena, src, region, MigrationFlags::new(false))
+            .unwrap_or_else(|err| panic!("Unexpected parse failure: {err:?}"))
+            .expect("selection doesn't overlap any def");
+
+        let start = formatted.region.start().offset as usize;
+        let end = formatted.region.end().offset as usize;
+
+        format!("{}{}{}", &src[..start], formatted.text, &src[end..])
+    }
+
     fn fmt_module_and_defs<'a>(
         arena: &Bump,
         src: &str,
@@ -140,6 +208,27 @@ mod test_fmt {
         module_formats_to(input, input);
     }
 
+    /// Format the module `src` with the given `options`, and check that the result is
+    /// `expected` and then stays the same when formatted with those options again.
+    fn module_formats_with_options(src: &str, options: FormatOptions, expected: &str) {
+        let arena = Bump::new();
+        let expected = expected.trim();
+
+        let format = |src: &str| {
+            let (header, state) = header::parse_header(&arena, State::new(src.as_bytes()))
+                .unwrap_or_else(|err| panic!("Unexpected parse failure: {err:?}"));
+            let mut buf = Buf::new_in_with_options(&arena, MigrationFlags::new(false), options);
+
+            fmt_module_and_defs(&arena, src, &header, state, &mut buf);
+
+            buf.as_str().trim().to_string()
+        };
+
+        let output = format(src.trim());
+        assert_multiline_str_eq!(expected, output.as_str());
+        assert_multiline_str_eq!(expected, format(expected).as_str());
+    }
+
     // STRING LITERALS
 
     #[test]
@@ -1260,6 +1349,33 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn binary_int() {
+        expr_formats_same("0b1010");
+        expr_formats_same("-0b1010u8");
+    }
+
+    #[test]
+    fn octal_int() {
+        expr_formats_same("0o755");
+        expr_formats_same("-0o755i64");
+    }
+
+    #[test]
+    fn hex_int() {
+        expr_formats_same("0xDEAD_beef");
+        expr_formats_same("-0x1fu32");
+    }
+
+    #[test]
+    fn non_base10_int_with_underscores() {
+        expr_formats_same(indoc!(
+            r"
+            (0xffff_0000, 0b1000_0000u8, 0o7_55)
+            "
+        ));
+    }
+
     #[test]
     fn multi_arg_closure() {
         expr_formats_same(indoc!(
@@ -1544,6 +1660,33 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn record_rest_destructuring() {
+        expr_formats_same(indoc!(
+            r"
+                when foo is
+                    { x, ..rest } ->
+                        rest
+            "
+        ));
+
+        expr_formats_same(indoc!(
+            r"
+                when foo is
+                    { x, .. } ->
+                        x
+            "
+        ));
+
+        expr_formats_same(indoc!(
+            r"
+                { x, ..rest } = foo
+
+                rest
+            "
+        ));
+    }
+
     #[test]
     fn lambda_returns_record() {
         expr_formats_same(indoc!(
@@ -2236,6 +2379,56 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn trailing_comma_removed_in_single_line_collections() {
+        expr_formats_to("[1, 2, 3,]", "[1, 2, 3]");
+        expr_formats_to("{ a: 1, b: 2, }", "{ a: 1, b: 2 }");
+        expr_formats_to("\\a, b, -> a", "\\a, b -> a");
+    }
+
+    #[test]
+    fn trailing_comma_added_in_multiline_collections() {
+        expr_formats_to(
+            indoc!(
+                r"
+                [
+                    1,
+                    2,
+                    3
+                ]
+                "
+            ),
+            indoc!(
+                r"
+                [
+                    1,
+                    2,
+                    3,
+                ]
+                "
+            ),
+        );
+
+        expr_formats_to(
+            indoc!(
+                r"
+                {
+                    a: 1,
+                    b: 2
+                }
+                "
+            ),
+            indoc!(
+                r"
+                {
+                    a: 1,
+                    b: 2,
+                }
+                "
+            ),
+        );
+    }
+
     #[test]
     fn multiline_type_definition() {
         expr_formats_same(indoc!(
@@ -3617,6 +3810,227 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn comment_heavy_when_is_stable() {
+        expr_formats_keeping_comments(indoc!(
+            r"
+            when b is
+                # before the first branch
+                1 # after the first pattern
+                | 2 -> # after the arrow
+                    # before the body
+                    1
+
+                # before the last branch
+                _ ->
+                    # before the last body
+                    2
+            "
+        ));
+    }
+
+    #[test]
+    fn comment_heavy_record_is_stable() {
+        expr_formats_keeping_comments(indoc!(
+            r"
+            {
+                # before the first field
+                a: {
+                    # inside a nested record
+                    b: 1, # after a nested field
+                },
+                c: 2, # after a field
+                # before the last field
+                d: 3 # after the last field
+                # at the end
+            }
+            "
+        ));
+    }
+
+    #[test]
+    fn record_respects_max_width() {
+        let input = r#"{ name: "Ada Lovelace", born: 1815, country: "England" }"#;
+
+        expr_formats_with_max_width(input, 120, input);
+        expr_formats_with_max_width(
+            input,
+            40,
+            indoc!(
+                r#"
+                {
+                    name: "Ada Lovelace",
+                    born: 1815,
+                    country: "England",
+                }
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn apply_respects_max_width() {
+        let input = r#"Str.concat "a rather long string" "another long string""#;
+
+        expr_formats_with_max_width(input, 120, input);
+        expr_formats_with_max_width(
+            input,
+            40,
+            indoc!(
+                r#"
+                Str.concat
+                    "a rather long string"
+                    "another long string"
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn pipeline_respects_max_width() {
+        let input = r#"list |> List.map Num.toStr |> Str.joinWith ", ""#;
+
+        expr_formats_with_max_width(input, 120, input);
+        expr_formats_with_max_width(
+            input,
+            40,
+            indoc!(
+                r#"
+                list
+                |> List.map Num.toStr
+                |> Str.joinWith ", "
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn format_range_single_def() {
+        let src = indoc!(
+            r"
+            module [a, b, c]
+
+            a =   1
+
+
+            b =    {   x:   1,    y: 2 }
+            c = [  1,2 ]
+            "
+        );
+
+        assert_multiline_str_eq!(
+            indoc!(
+                r"
+                module [a, b, c]
+
+                a =   1
+
+
+                b = { x: 1, y: 2 }
+                c = [  1,2 ]
+                "
+            ),
+            format_selection(src, "x:").as_str()
+        );
+    }
+
+    #[test]
+    fn format_ast_of_hand_built_module() {
+        let arena = Bump::new();
+        let exposes = [
+            Loc::at_zero(Spaced::Item(ExposedName::new("answer"))),
+            Loc::at_zero(Spaced::Item(ExposedName::new("main"))),
+        ];
+        let header = SpacesBefore {
+            before: &[],
+            item: Header::Module(ModuleHeader {
+                after_keyword: &[],
+                params: None,
+                exposes: Collection::with_items(&exposes),
+                interface_imports: None,
+            }),
+        };
+
+        let mut defs = Defs::default();
+        defs.push_value_def(
+            ValueDef::Body(
+                arena.alloc(Loc::at_zero(Pattern::Identifier { ident: "answer" })),
+                arena.alloc(Loc::at_zero(Expr::Num("42"))),
+            ),
+            Region::zero(),
+            &[CommentOrNewline::Newline, CommentOrNewline::Newline],
+            &[],
+        );
+        defs.push_value_def(
+            ValueDef::Body(
+                arena.alloc(Loc::at_zero(Pattern::Identifier { ident: "main" })),
+                arena.alloc(Loc::at_zero(Expr::Var {
+                    module_name: "",
+                    ident: "answer",
+                })),
+            ),
+            Region::zero(),
+            &[],
+            &[],
+        );
+
+        let ast = FullAst { header, defs };
+
+        assert_multiline_str_eq!(
+            indoc!(
+                r"
+                module [answer, main]
+
+                answer = 42
+                main = answer
+                "
+            ),
+            format_ast(&ast, MigrationFlags::new(false), FormatOptions::default()).as_str()
+        );
+    }
+
+    #[test]
+    fn format_range_multiple_defs() {
+        let src = indoc!(
+            r"
+            module [a, b, c]
+
+            a =   1
+
+            b =    {   x:   1 }
+            # about c
+            c = [  1,2 ]
+            "
+        );
+
+        assert_multiline_str_eq!(
+            indoc!(
+                r"
+                module [a, b, c]
+
+                a =   1
+
+                b = { x: 1 }
+                # about c
+                c = [1, 2]
+                "
+            ),
+            format_selection(src, "1 }\n# about c\nc").as_str()
+        );
+    }
+
+    #[test]
+    fn format_range_outside_defs() {
+        let arena = Bump::new();
+        let src = "module [a]\n\na =   1\n";
+        let region = Region::new(Position::new(0), Position::new(6));
+
+        assert_eq!(
+            format_range(&arena, src, region, MigrationFlags::new(false)),
+            Ok(None)
+        );
+    }
+
     #[test]
     fn when_with_integer_comments() {
         expr_formats_same(indoc!(
@@ -4239,6 +4653,14 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn range_operators() {
+        expr_formats_same("1 .. 10");
+        expr_formats_same("start ..= end");
+        expr_formats_to("1..10", "1 .. 10");
+        expr_formats_to("(n + 1)..=(n * 2)", "(n + 1) ..= (n * 2)");
+    }
+
     #[test]
     fn binop_parens() {
         expr_formats_same(indoc!(
@@ -4847,6 +5269,69 @@ mod test_fmt {
         ));
     }
 
+    fn sorted_exposing() -> FormatOptions {
+        FormatOptions {
+            sort_exposing: true,
+            ..FormatOptions::default()
+        }
+    }
+
+    #[test]
+    fn import_exposing_keeps_source_order_by_default() {
+        module_formats_same(indoc!(
+            r"
+                module [main]
+
+                import Json exposing [encode, Json, decode, Decoder]"
+        ));
+    }
+
+    #[test]
+    fn import_exposing_sorted() {
+        module_formats_with_options(
+            indoc!(
+                r"
+                module [main]
+
+                import Json exposing [encode, Json, decode, Decoder]"
+            ),
+            sorted_exposing(),
+            indoc!(
+                r"
+                module [main]
+
+                import Json exposing [Decoder, Json, decode, encode]"
+            ),
+        );
+    }
+
+    #[test]
+    fn import_exposing_sorted_keeps_comments_with_their_name() {
+        module_formats_with_options(
+            indoc!(
+                r"
+                module [main]
+
+                import Json exposing [
+                    # turns bytes into values
+                    decode,
+                    Json,
+                ]"
+            ),
+            sorted_exposing(),
+            indoc!(
+                r"
+                module [main]
+
+                import Json exposing [
+                    Json,
+                    # turns bytes into values
+                    decode,
+                ]"
+            ),
+        );
+    }
+
     #[test]
     fn old_style_app_header_is_upgraded() {
         module_formats_to(
@@ -6303,6 +6788,21 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn preserve_relative_indentation_in_multiline_string() {
+        expr_formats_same(indoc!(
+            r#"
+            x =
+                """
+                foo
+                  "bar" ""
+                baz
+                """
+            x
+            "#
+        ));
+    }
+
     // this is a parse error atm
     //    #[test]
     //    fn multiline_apply() {
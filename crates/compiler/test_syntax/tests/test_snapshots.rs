@@ -204,6 +204,7 @@ mod test_snapshots {
         fail/exposed_type_bang.header,
         fail/expr_to_pattern_fail.expr,
         fail/expression_indentation_end.expr,
+        fail/hex_int_with_leading_underscore.expr,
         fail/if_guard_without_condition.expr,
         fail/if_missing_else.expr,
         fail/if_outdented_else_branch.expr,
@@ -212,6 +213,8 @@ mod test_snapshots {
         fail/import_with_lowercase_alias.moduledefs,
         fail/imports_missing_comma.header,
         fail/inline_hastype.expr,
+        fail/int_with_double_underscore.expr,
+        fail/int_with_trailing_underscore.expr,
         fail/invalid_operator.expr,
         fail/lambda_double_comma.expr,
         fail/lambda_extra_comma.expr,
@@ -775,13 +778,6 @@ mod test_snapshots {
                 true
             }
 
-            "parens_newlines_before_as" | "tag_union_ann_with_as" => {
-                // Found unbound type variables {66}
-                //  in type alias `Test.IdentId(0)` [] [] : <66>
-                // Location: crates/compiler/can/src/scope.rs:541:13
-                cfg!(debug_assertions)
-            }
-
             // When adding new snapshot tests, strongly prefer fixing any canonicalization panics
             // they may run into rather than adding them to this list.
             _ => false,
@@ -1,6 +1,6 @@
 use roc_module::symbol::Symbol;
 
-use crate::subs::Variable;
+use crate::subs::{Content, Subs, Variable};
 
 /// A bound placed on a number because of its literal value.
 /// e.g. `-5` cannot be unsigned, and 300 does not fit in a U8
@@ -469,3 +469,58 @@ const SIGNED_INT_VARIABLES: &[Variable] = &[
     Variable::I64,
     Variable::I128,
 ];
+
+/// The concrete number type that an otherwise unconstrained number variable is compiled as:
+/// `Num *`, `Int *` and integer literals default to `I64` (or the smallest type they fit in),
+/// and `Frac *` defaults to `Dec`. Mirrors the defaulting done when laying out numbers, so that
+/// tooling like `roc check` can report it.
+///
+/// Returns `None` if `var` is not a number, already has a concrete type, or is generalized; a
+/// generalized number is polymorphic and will get its type from each use.
+pub fn number_default(subs: &Subs, var: Variable) -> Option<Symbol> {
+    if subs.get_rank(var).is_generalized() {
+        return None;
+    }
+
+    number_default_help(subs, var)
+}
+
+fn number_default_help(subs: &Subs, var: Variable) -> Option<Symbol> {
+    use Content::*;
+
+    let is_unresolved = |var| {
+        matches!(
+            subs.get_content_without_compacting(var),
+            FlexVar(..) | RigidVar(..) | FlexAbleVar(..) | RigidAbleVar(..)
+        )
+    };
+    let is_any_float_range = |var| {
+        matches!(
+            subs.get_content_without_compacting(var),
+            RangedNumber(
+                NumericRange::NumAtLeastEitherSign(..) | NumericRange::NumAtLeastSigned(..)
+            )
+        )
+    };
+
+    match subs.get_content_without_compacting(var) {
+        Alias(symbol, _, actual, _) => match *symbol {
+            Symbol::NUM_NUM | Symbol::NUM_INT | Symbol::NUM_INTEGER if is_unresolved(*actual) => {
+                Some(Symbol::NUM_I64)
+            }
+            Symbol::NUM_FRAC | Symbol::NUM_FLOATINGPOINT
+                if is_unresolved(*actual) || is_any_float_range(*actual) =>
+            {
+                Some(Symbol::NUM_DEC)
+            }
+            Symbol::NUM_NUM
+            | Symbol::NUM_INT
+            | Symbol::NUM_INTEGER
+            | Symbol::NUM_FRAC
+            | Symbol::NUM_FLOATINGPOINT => number_default_help(subs, *actual),
+            _ => None,
+        },
+        RangedNumber(range) => Some(range.default_compilation_width().symbol()),
+        _ => None,
+    }
+}
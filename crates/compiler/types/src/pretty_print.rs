@@ -5,7 +5,8 @@ use crate::subs::{
     UnsortedUnionLabels, Variable,
 };
 use crate::types::{
-    name_type_var, name_type_var_with_hint, AbilitySet, Polarity, RecordField, Uls,
+    gather_fields, gather_tags_slices, name_type_var, name_type_var_with_hint, AbilitySet, Alias,
+    AliasCommon, AliasKind, Polarity, RecordField, RecordStructure, Type, TypeExtension, Uls,
 };
 use roc_collections::all::MutMap;
 use roc_collections::VecSet;
@@ -14,8 +15,15 @@ use roc_module::symbol::{Interns, ModuleId, Symbol};
 
 pub static WILDCARD: &str = "*";
 static EMPTY_RECORD: &str = "{}";
+static EMPTY_TUPLE: &str = "()";
 static EMPTY_TAG_UNION: &str = "[]";
 static EFFECTFUL_FUNC: &str = "! : ... => ?";
+static ELLIPSIS: &str = "…";
+
+static CONSTRUCTOR_COLOR: &str = "\u{001b}[36m";
+static VARIABLE_COLOR: &str = "\u{001b}[35m";
+static FIELD_COLOR: &str = "\u{001b}[33m";
+static RESET_COLOR: &str = "\u{001b}[0m";
 
 /// Requirements for parentheses.
 ///
@@ -53,6 +61,27 @@ macro_rules! write_parens {
     };
 }
 
+/// Whether printed types contain ANSI color codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Coloring {
+    Always,
+    Never,
+    /// Color only when stdout is a terminal.
+    Auto,
+}
+
+impl Coloring {
+    fn is_enabled(self) -> bool {
+        use std::io::IsTerminal;
+
+        match self {
+            Coloring::Always => true,
+            Coloring::Never => false,
+            Coloring::Auto => std::io::stdout().is_terminal(),
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct DebugPrint {
     pub print_lambda_sets: bool,
@@ -61,6 +90,16 @@ pub struct DebugPrint {
     pub ignore_polarity: bool,
     pub print_weakened_vars: bool,
     pub print_variables: bool,
+    /// Structure nested deeper than this is printed as `…`. Type variables, including recursion
+    /// variables, are always printed.
+    pub max_depth: Option<usize>,
+    /// The printed type is cut off after this many characters, followed by `…`.
+    pub max_length: Option<usize>,
+    /// Structural types that match the definition of one of the aliases given to
+    /// [`name_and_print_var_with_aliases`] are printed as that alias.
+    pub fold_aliases: bool,
+    /// Type constructors, type variables and record fields are colored differently.
+    pub coloring: Coloring,
 }
 
 impl DebugPrint {
@@ -71,6 +110,10 @@ impl DebugPrint {
         ignore_polarity: false,
         print_weakened_vars: false,
         print_variables: false,
+        max_depth: None,
+        max_length: None,
+        fold_aliases: false,
+        coloring: Coloring::Never,
     };
 }
 
@@ -78,6 +121,20 @@ struct Env<'a> {
     home: ModuleId,
     interns: &'a Interns,
     debug: DebugPrint,
+    aliases: Vec<(Symbol, &'a Alias)>,
+    color: bool,
+}
+
+impl Env<'_> {
+    fn push_colored(&self, buf: &mut String, color: &str, text: &str) {
+        if self.color {
+            buf.push_str(color);
+            buf.push_str(text);
+            buf.push_str(RESET_COLOR);
+        } else {
+            buf.push_str(text);
+        }
+    }
 }
 
 /// How many times a root variable appeared in Subs.
@@ -412,6 +469,7 @@ fn find_names_needed(
         }
         Error
         | Structure(EmptyRecord)
+        | Structure(EmptyTuple)
         | Structure(EmptyTagUnion)
         | Pure
         | Effectful
@@ -511,21 +569,21 @@ fn name_root(
             let name_hint = &subs[*name];
             if name_hint.as_str() == "*" {
                 // Give a proper name to named wildcards!
-                name_type_var(prefix, letters_used, &mut taken.keys(), |var, str| {
+                name_type_var(prefix, letters_used, taken.keys(), |var, str| {
                     var.as_str() == str
                 })
             } else {
                 let generated = name_type_var_with_hint(
                     prefix,
                     name_hint.as_str(),
-                    &mut taken.keys(),
+                    taken.keys(),
                     |var, str| var.as_str() == str,
                 );
 
                 (generated, letters_used)
             }
         }
-        _ => name_type_var(prefix, letters_used, &mut taken.keys(), |var, str| {
+        _ => name_type_var(prefix, letters_used, taken.keys(), |var, str| {
             var.as_str() == str
         }),
     };
@@ -580,6 +638,7 @@ fn set_root_name(root: Variable, name: Lowercase, subs: &mut Subs) {
 struct Context<'a> {
     able_variables: Vec<(&'a str, AbilitySet)>,
     recursion_structs_to_expand: VecSet<Variable>,
+    depth: usize,
 }
 
 fn variable_to_string(
@@ -590,16 +649,20 @@ fn variable_to_string(
     named_result: NamedResult,
     debug_print: DebugPrint,
     pol: Polarity,
+    aliases: Vec<(Symbol, &Alias)>,
 ) -> String {
     let mut buf = String::new();
     let env = Env {
         home,
         interns,
         debug: debug_print,
+        aliases,
+        color: debug_print.coloring.is_enabled(),
     };
     let mut ctx = Context {
         able_variables: vec![],
         recursion_structs_to_expand: named_result.recursion_structs_to_expand,
+        depth: 0,
     };
 
     write_content(
@@ -622,7 +685,7 @@ fn variable_to_string(
             buf.push(',');
         }
         buf.push(' ');
-        buf.push_str(var);
+        env.push_colored(&mut buf, VARIABLE_COLOR, var);
         buf.push(' ');
         buf.push_str(roc_parse::keyword::IMPLEMENTS);
         for (i, ability) in abilities.into_sorted_iter().enumerate() {
@@ -634,17 +697,66 @@ fn variable_to_string(
         }
     }
 
+    if let Some(max_length) = debug_print.max_length {
+        if let Some(cutoff) = nth_visible_char_index(&buf, max_length) {
+            buf.truncate(cutoff);
+            if env.color {
+                // we may have cut off a colored name before its reset
+                buf.push_str(RESET_COLOR);
+            }
+            buf.push_str(ELLIPSIS);
+        }
+    }
+
     buf
 }
 
+/// The byte index of the `n`th character of `text`, not counting ANSI color codes.
+fn nth_visible_char_index(text: &str, n: usize) -> Option<usize> {
+    let mut visible = 0;
+    let mut in_color_code = false;
+
+    for (index, c) in text.char_indices() {
+        if in_color_code {
+            in_color_code = c != 'm';
+        } else if c == '\u{001b}' {
+            in_color_code = true;
+        } else if visible == n {
+            return Some(index);
+        } else {
+            visible += 1;
+        }
+    }
+
+    None
+}
+
 pub fn name_and_print_var(
     var: Variable,
     subs: &mut Subs,
     home: ModuleId,
     interns: &Interns,
     debug_print: DebugPrint,
+) -> String {
+    name_and_print_var_with_aliases(var, subs, home, interns, debug_print, [])
+}
+
+/// Like [`name_and_print_var`], but with [`DebugPrint::fold_aliases`] set, types matching one of
+/// the `aliases` (e.g. the aliases in scope) are printed as that alias.
+pub fn name_and_print_var_with_aliases<'a>(
+    var: Variable,
+    subs: &mut Subs,
+    home: ModuleId,
+    interns: &Interns,
+    debug_print: DebugPrint,
+    aliases: impl IntoIterator<Item = (&'a Symbol, &'a Alias)>,
 ) -> String {
     let named_result = name_all_type_vars(var, subs, debug_print);
+    let aliases = aliases
+        .into_iter()
+        .map(|(symbol, alias)| (*symbol, alias))
+        .collect();
+
     variable_to_string(
         var,
         subs,
@@ -653,6 +765,7 @@ pub fn name_and_print_var(
         named_result,
         debug_print,
         Polarity::Pos,
+        aliases,
     )
 }
 
@@ -687,15 +800,47 @@ fn write_content<'a>(
         ));
     }
 
-    match subs.get_content_without_compacting(var) {
+    let content = subs.get_content_without_compacting(var);
+
+    if let Some(max_depth) = env.debug.max_depth {
+        if ctx.depth >= max_depth
+            && matches!(
+                content,
+                Structure(_) | Alias(..) | LambdaSet(_) | RangedNumber(_)
+            )
+        {
+            buf.push_str(ELLIPSIS);
+            return;
+        }
+    }
+
+    ctx.depth += 1;
+
+    if let Some((symbol, args)) = find_folded_alias(env, subs, var) {
+        let write_parens = parens == Parens::InTypeParam && !args.is_empty();
+
+        write_parens!(write_parens, buf, {
+            write_symbol(env, symbol, buf);
+
+            for var in args {
+                buf.push(' ');
+                write_content(env, ctx, var, subs, buf, Parens::InTypeParam, pol);
+            }
+        });
+
+        ctx.depth -= 1;
+        return;
+    }
+
+    match content {
         FlexVar(Some(name_index)) => {
             let name = &subs.field_names[name_index.index()];
-            buf.push_str(name.as_str())
+            env.push_colored(buf, VARIABLE_COLOR, name.as_str())
         }
-        FlexVar(None) => buf.push_str(WILDCARD),
+        FlexVar(None) => env.push_colored(buf, VARIABLE_COLOR, WILDCARD),
         RigidVar(name_index) => {
             let name = &subs.field_names[name_index.index()];
-            buf.push_str(name.as_str())
+            env.push_colored(buf, VARIABLE_COLOR, name.as_str())
         }
         FlexAbleVar(opt_name_index, abilities) => {
             let name = opt_name_index
@@ -703,13 +848,13 @@ fn write_content<'a>(
                 .unwrap_or(WILDCARD);
             let abilities = AbilitySet::from_iter(subs.get_subs_slice(*abilities).iter().copied());
             ctx.able_variables.push((name, abilities));
-            buf.push_str(name);
+            env.push_colored(buf, VARIABLE_COLOR, name);
         }
         RigidAbleVar(name_index, abilities) => {
             let name = subs.field_names[name_index.index()].as_str();
             let abilities = AbilitySet::from_iter(subs.get_subs_slice(*abilities).iter().copied());
             ctx.able_variables.push((name, abilities));
-            buf.push_str(name);
+            env.push_colored(buf, VARIABLE_COLOR, name);
         }
         RecursionVar {
             opt_name,
@@ -723,7 +868,7 @@ fn write_content<'a>(
                     ctx.recursion_structs_to_expand.insert(structure_root);
                 } else {
                     let name = &subs.field_names[name_index.index()];
-                    buf.push_str(name.as_str())
+                    env.push_colored(buf, VARIABLE_COLOR, name.as_str())
                 }
             }
             None => {
@@ -763,13 +908,15 @@ fn write_content<'a>(
                             ),
 
                             _ => write_parens!(write_parens, buf, {
-                                buf.push_str("Num ");
+                                env.push_colored(buf, CONSTRUCTOR_COLOR, "Num");
+                                buf.push(' ');
                                 write_content(env, ctx, content, subs, buf, parens, pol);
                             }),
                         },
 
                         _ => write_parens!(write_parens, buf, {
-                            buf.push_str("Num ");
+                            env.push_colored(buf, CONSTRUCTOR_COLOR, "Num");
+                            buf.push(' ');
                             write_content(env, ctx, content, subs, buf, parens, pol);
                         }),
                     }
@@ -903,6 +1050,8 @@ fn write_content<'a>(
         }
         Error => buf.push_str("<type mismatch>"),
     }
+
+    ctx.depth -= 1;
 }
 
 fn write_float<'a>(
@@ -917,11 +1066,12 @@ fn write_float<'a>(
 ) {
     use crate::subs::Content::*;
     match subs.get_content_without_compacting(var) {
-        Alias(Symbol::NUM_BINARY32, _, _, _) => buf.push_str("F32"),
-        Alias(Symbol::NUM_BINARY64, _, _, _) => buf.push_str("F64"),
-        Alias(Symbol::NUM_DECIMAL, _, _, _) => buf.push_str("Dec"),
+        Alias(Symbol::NUM_BINARY32, _, _, _) => env.push_colored(buf, CONSTRUCTOR_COLOR, "F32"),
+        Alias(Symbol::NUM_BINARY64, _, _, _) => env.push_colored(buf, CONSTRUCTOR_COLOR, "F64"),
+        Alias(Symbol::NUM_DECIMAL, _, _, _) => env.push_colored(buf, CONSTRUCTOR_COLOR, "Dec"),
         _ => write_parens!(write_parens, buf, {
-            buf.push_str("Frac ");
+            env.push_colored(buf, CONSTRUCTOR_COLOR, "Frac");
+            buf.push(' ');
             write_content(env, ctx, var, subs, buf, parens, pol);
         }),
     }
@@ -944,7 +1094,7 @@ fn write_integer<'a>(
             match subs.get_content_without_compacting(var) {
                 $(
                 &Alias($tag, _, _, _) => {
-                    buf.push_str($lit)
+                    env.push_colored(buf, CONSTRUCTOR_COLOR, $lit)
                 },
                 )*
                 _ => {
@@ -952,7 +1102,8 @@ fn write_integer<'a>(
                         write_parens,
                         buf,
                         {
-                            buf.push_str("Int ");
+                            env.push_colored(buf, CONSTRUCTOR_COLOR, "Int");
+                            buf.push(' ');
                             write_content(env, ctx, var, subs, buf, parens, pol);
                         }
                     )
@@ -986,6 +1137,7 @@ impl ExtContent {
         match content {
             Content::Structure(FlatType::EmptyTagUnion) => ExtContent::Empty,
             Content::Structure(FlatType::EmptyRecord) => ExtContent::Empty,
+            Content::Structure(FlatType::EmptyTuple) => ExtContent::Empty,
 
             Content::FlexVar(None) | Content::FlexAbleVar(None, _)
                 if pol.is_pos() && !debug_flags.ignore_polarity =>
@@ -1128,6 +1280,7 @@ fn write_flat_type<'a>(
             pol,
         ),
         EmptyRecord => buf.push_str(EMPTY_RECORD),
+        EmptyTuple => buf.push_str(EMPTY_TUPLE),
         EmptyTagUnion => buf.push_str(EMPTY_TAG_UNION),
         Func(args, closure, ret, fx) => write_fn(
             env,
@@ -1143,8 +1296,6 @@ fn write_flat_type<'a>(
         ),
         EffectfulFunc => buf.push_str(EFFECTFUL_FUNC),
         Record(fields, ext_var) => {
-            use crate::types::{gather_fields, RecordStructure};
-
             // If the `ext` has concrete fields (e.g. { foo : I64}{ bar : Bool }), merge them
             let RecordStructure {
                 fields: sorted_fields,
@@ -1170,7 +1321,7 @@ fn write_flat_type<'a>(
                     } else {
                         any_written_yet = true;
                     }
-                    buf.push_str(label.as_str());
+                    env.push_colored(buf, FIELD_COLOR, label.as_str());
 
                     match record_field {
                         Optional(_) | RigidOptional(_) => buf.push_str(" ? "),
@@ -1236,12 +1387,19 @@ fn write_flat_type<'a>(
 
             buf.push_str(" )");
 
-            // This is an open tuple, so print the variable
-            // right after the ')'
-            //
-            // e.g. the "*" at the end of `( I64, I64 )*`
-            // or the "r" at the end of `( I64, I64 )r`
-            write_content(env, ctx, ext_var, subs, buf, parens, pol)
+            match subs.get_content_without_compacting(ext_var) {
+                Content::Structure(EmptyTuple) => {
+                    // This is a closed tuple. We're done!
+                }
+                _ => {
+                    // This is an open tuple, so print the variable
+                    // right after the ')'
+                    //
+                    // e.g. the "*" at the end of `( I64, I64 )*`
+                    // or the "r" at the end of `( I64, I64 )r`
+                    write_content(env, ctx, ext_var, subs, buf, parens, pol)
+                }
+            }
         }
         TagUnion(tags, ext_var) => {
             buf.push('[');
@@ -1398,7 +1556,7 @@ fn write_apply<'a>(
     // Hardcoded type aliases
     match symbol {
         Symbol::STR_STR => {
-            buf.push_str("Str");
+            env.push_colored(buf, CONSTRUCTOR_COLOR, "Str");
         }
         Symbol::NUM_NUM => {
             let arg = args
@@ -1422,7 +1580,8 @@ fn write_apply<'a>(
                     Parens::InTypeParam,
                     pol,
                 );
-                buf.push_str("Num ");
+                env.push_colored(buf, CONSTRUCTOR_COLOR, "Num");
+                buf.push(' ');
                 buf.push_str(&arg_param);
 
                 if write_parens {
@@ -1433,10 +1592,10 @@ fn write_apply<'a>(
             match &arg_content {
                 Content::Structure(FlatType::Apply(symbol, nested_args)) => match *symbol {
                     Symbol::NUM_INTEGER if nested_args.len() == 1 => {
-                        buf.push_str("I64");
+                        env.push_colored(buf, CONSTRUCTOR_COLOR, "I64");
                     }
                     Symbol::NUM_FLOATINGPOINT if nested_args.len() == 1 => {
-                        buf.push_str("F64");
+                        env.push_colored(buf, CONSTRUCTOR_COLOR, "F64");
                     }
                     _ => default_case(subs, *arg),
                 },
@@ -1510,6 +1669,217 @@ fn write_fn<'a>(
     }
 }
 
+/// Find an alias whose definition matches the structural type of `var`, and the variables its
+/// type parameters are bound to. Opaque types never match, since they are nominal.
+fn find_folded_alias(env: &Env, subs: &Subs, var: Variable) -> Option<(Symbol, Vec<Variable>)> {
+    if !env.debug.fold_aliases
+        || !matches!(
+            subs.get_content_without_compacting(var),
+            Content::Structure(_) | Content::Alias(..)
+        )
+    {
+        return None;
+    }
+
+    env.aliases.iter().find_map(|(symbol, alias)| {
+        // An alias of a bare type variable would match every type
+        if alias.kind == AliasKind::Opaque || matches!(alias.typ, Type::Variable(_)) {
+            return None;
+        }
+
+        let mut bound: Vec<(Variable, Option<Variable>)> = alias
+            .type_variables
+            .iter()
+            .map(|loc_var| (loc_var.value.var, None))
+            .collect();
+
+        if !type_matches(subs, &alias.typ, var, &mut bound) {
+            return None;
+        }
+
+        // Parameters that don't appear in the definition can't be recovered
+        let args = bound
+            .into_iter()
+            .map(|(_, arg)| arg)
+            .collect::<Option<_>>()?;
+
+        Some((*symbol, args))
+    })
+}
+
+/// Whether `var` has the shape of `typ`, binding the alias parameters in `bound` along the way.
+fn type_matches(
+    subs: &Subs,
+    typ: &Type,
+    var: Variable,
+    bound: &mut [(Variable, Option<Variable>)],
+) -> bool {
+    use crate::subs::Content::Structure;
+    use crate::subs::FlatType::*;
+
+    let content = subs.get_content_without_compacting(var);
+
+    match typ {
+        Type::Variable(type_var) => match bound.iter_mut().find(|(param, _)| param == type_var) {
+            Some((_, Some(bound_var))) => subs.equivalent_without_compacting(*bound_var, var),
+            Some((_, unbound @ None)) => {
+                *unbound = Some(var);
+                true
+            }
+            // e.g. a lambda set variable, which does not constrain the match
+            None => true,
+        },
+        Type::EmptyRec => matches!(content, Structure(EmptyRecord)),
+        Type::EmptyTagUnion => matches!(content, Structure(EmptyTagUnion)),
+        Type::Apply(symbol, args, _) => match content {
+            Structure(Apply(subs_symbol, subs_args)) => {
+                let subs_args = subs.get_subs_slice(*subs_args);
+
+                symbol == subs_symbol
+                    && args.len() == subs_args.len()
+                    && args
+                        .iter()
+                        .zip(subs_args)
+                        .all(|(arg, subs_arg)| type_matches(subs, &arg.value, *subs_arg, bound))
+            }
+            // builtin types like `Dict` are applied by name, but are aliases in `Subs`
+            _ => {
+                let args: Vec<_> = args.iter().map(|arg| &arg.value).collect();
+
+                alias_matches(subs, *symbol, &args, content, bound)
+            }
+        },
+        Type::DelayedAlias(AliasCommon {
+            symbol,
+            type_arguments,
+            ..
+        }) => {
+            let type_arguments: Vec<_> = type_arguments.iter().map(|arg| &arg.value.typ).collect();
+
+            alias_matches(subs, *symbol, &type_arguments, content, bound)
+        }
+        Type::Alias {
+            symbol,
+            type_arguments,
+            ..
+        } => {
+            let type_arguments: Vec<_> = type_arguments.iter().map(|arg| &arg.typ).collect();
+
+            alias_matches(subs, *symbol, &type_arguments, content, bound)
+        }
+        Type::Function(args, _closure, ret, _fx) => match content {
+            Structure(Func(subs_args, _closure, subs_ret, _fx)) => {
+                let subs_args = subs.get_subs_slice(*subs_args);
+
+                args.len() == subs_args.len()
+                    && args
+                        .iter()
+                        .zip(subs_args)
+                        .all(|(arg, subs_arg)| type_matches(subs, arg, *subs_arg, bound))
+                    && type_matches(subs, ret, *subs_ret, bound)
+            }
+            _ => false,
+        },
+        Type::Record(fields, ext) => match content {
+            Structure(Record(subs_fields, subs_ext)) => {
+                let Ok(RecordStructure {
+                    fields: subs_fields,
+                    ext: subs_ext,
+                }) = gather_fields(subs, *subs_fields, *subs_ext)
+                else {
+                    return false;
+                };
+
+                fields.len() == subs_fields.len()
+                    && subs_fields
+                        .iter()
+                        .all(|(label, subs_field)| match fields.get(label) {
+                            Some(field) => {
+                                field.is_optional() == subs_field.is_optional()
+                                    && type_matches(
+                                        subs,
+                                        field.as_inner(),
+                                        *subs_field.as_inner(),
+                                        bound,
+                                    )
+                            }
+                            None => false,
+                        })
+                    && ext_matches(subs, ext, subs_ext, bound)
+            }
+            _ => false,
+        },
+        Type::TagUnion(tags, ext) => match content {
+            Structure(TagUnion(subs_tags, subs_ext)) => {
+                let Ok((subs_tags, subs_ext)) = gather_tags_slices(subs, *subs_tags, *subs_ext)
+                else {
+                    return false;
+                };
+
+                tags.len() == subs_tags.len()
+                    && subs_tags.iter().all(|(tag_name, subs_args)| {
+                        let subs_args = subs.get_subs_slice(*subs_args);
+
+                        match tags.iter().find(|(name, _)| name == tag_name) {
+                            Some((_, args)) => {
+                                args.len() == subs_args.len()
+                                    && args.iter().zip(subs_args).all(|(arg, subs_arg)| {
+                                        type_matches(subs, arg, *subs_arg, bound)
+                                    })
+                            }
+                            None => false,
+                        }
+                    })
+                    && ext_matches(subs, ext, subs_ext.var(), bound)
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn alias_matches(
+    subs: &Subs,
+    symbol: Symbol,
+    type_arguments: &[&Type],
+    content: &Content,
+    bound: &mut [(Variable, Option<Variable>)],
+) -> bool {
+    match content {
+        Content::Alias(subs_symbol, subs_args, _actual, _kind) => {
+            let subs_args: Vec<_> = subs_args
+                .named_type_arguments()
+                .map(|index| subs[index])
+                .collect();
+
+            symbol == *subs_symbol
+                && type_arguments.len() == subs_args.len()
+                && type_arguments
+                    .iter()
+                    .zip(subs_args)
+                    .all(|(arg, subs_arg)| type_matches(subs, arg, subs_arg, bound))
+        }
+        _ => false,
+    }
+}
+
+fn ext_matches(
+    subs: &Subs,
+    ext: &TypeExtension,
+    var: Variable,
+    bound: &mut [(Variable, Option<Variable>)],
+) -> bool {
+    match ext {
+        TypeExtension::Closed => matches!(
+            subs.get_content_without_compacting(var),
+            Content::Structure(
+                FlatType::EmptyRecord | FlatType::EmptyTuple | FlatType::EmptyTagUnion
+            )
+        ),
+        TypeExtension::Open(ext_type, _) => type_matches(subs, ext_type, var, bound),
+    }
+}
+
 fn write_symbol(env: &Env, symbol: Symbol, buf: &mut String) {
     let interns = &env.interns;
     let ident_str = symbol.as_str(interns);
@@ -1518,9 +1888,9 @@ fn write_symbol(env: &Env, symbol: Symbol, buf: &mut String) {
     // Don't qualify the symbol if it's in our home module,
     // or if it's a builtin (since all their types are always in scope)
     if module_id != env.home && !module_id.is_builtin() {
-        buf.push_str(module_id.to_ident_str(interns).as_str());
-        buf.push('.');
+        let qualified = format!("{}.{}", module_id.to_ident_str(interns), ident_str);
+        env.push_colored(buf, CONSTRUCTOR_COLOR, &qualified);
+    } else {
+        env.push_colored(buf, CONSTRUCTOR_COLOR, ident_str);
     }
-
-    buf.push_str(ident_str);
 }
@@ -832,6 +832,7 @@ fn subs_fmt_flat_type(this: &FlatType, subs: &Subs, f: &mut fmt::Formatter) -> f
             write!(f, "]<{new_ext:?}> as <{rec:?}>")
         }
         FlatType::EmptyRecord => write!(f, "EmptyRecord"),
+        FlatType::EmptyTuple => write!(f, "EmptyTuple"),
         FlatType::EmptyTagUnion => write!(f, "EmptyTagUnion"),
         FlatType::EffectfulFunc => write!(f, "EffectfulFunc"),
     }
@@ -1601,6 +1602,10 @@ impl Subs {
             Variable::EMPTY_RECORD,
             Content::Structure(FlatType::EmptyRecord),
         );
+        subs.set_content(
+            Variable::EMPTY_TUPLE,
+            Content::Structure(FlatType::EmptyTuple),
+        );
         subs.set_content(
             Variable::EMPTY_TAG_UNION,
             Content::Structure(FlatType::EmptyTagUnion),
@@ -1788,6 +1793,29 @@ impl Subs {
         self.fresh(Descriptor::from(unnamed_flex_var()))
     }
 
+    /// A new unnamed flex var at `rank`, with the same mark and copy that `solve` gives the
+    /// variables it registers. Unlike registering, this doesn't add the variable to a pool.
+    pub fn fresh_flex_var(&mut self, rank: Rank) -> Variable {
+        self.fresh(Descriptor {
+            content: unnamed_flex_var(),
+            rank,
+            mark: Mark::NONE,
+            copy: OptVariable::NONE,
+        })
+    }
+
+    /// A new rigid var called `name` at `rank`; see [Subs::fresh_flex_var].
+    pub fn fresh_rigid_var(&mut self, name: Lowercase, rank: Rank) -> Variable {
+        let name_index = self.push_field_name(name);
+
+        self.fresh(Descriptor {
+            content: Content::RigidVar(name_index),
+            rank,
+            mark: Mark::NONE,
+            copy: OptVariable::NONE,
+        })
+    }
+
     pub fn rigid_var(&mut self, var: Variable, name: Lowercase) {
         let name_index = self.push_field_name(name);
         let content = Content::RigidVar(name_index);
@@ -1871,6 +1899,202 @@ impl Subs {
         self.utable.root_key_without_compacting(key)
     }
 
+    /// Follows `var` to its root and returns a read-only view of what it resolves to.
+    ///
+    /// Unlike [Subs::get], this never compacts the union-find paths, so it is safe to call
+    /// from tooling and tests that only hold a shared reference.
+    pub fn explain_variable(&self, var: Variable) -> ResolvedContent<'_> {
+        let root = self.get_root_key_without_compacting(var);
+        let (rank, mark) = self.get_rank_mark(root);
+
+        ResolvedContent {
+            root,
+            content: self.get_content_without_compacting(root),
+            rank,
+            mark,
+        }
+    }
+
+    /// A textual dump of every variable in these subs; see [Subs::debug_dump_from].
+    pub fn debug_dump(&self) -> String {
+        self.debug_dump_from((0..self.len()).map(|i| Variable(i as u32)))
+    }
+
+    /// A textual dump of the contents and ranks of `vars` and of every variable they refer to,
+    /// for snapshotting the solved state of a program.
+    ///
+    /// Only the roots of equivalence classes are printed, one per line, and they're numbered
+    /// `v0`, `v1`, ... in the order they're first reached. The dump therefore doesn't depend on
+    /// how many variables were allocated beforehand, or on how the unification table happens
+    /// to link them.
+    pub fn debug_dump_from(&self, vars: impl IntoIterator<Item = Variable>) -> String {
+        let mut names = DumpNames::default();
+
+        for var in vars {
+            names.name(self, var);
+        }
+
+        let mut dump = String::new();
+        let mut next = 0;
+
+        while let Some(&root) = names.roots.get(next) {
+            let content = self.dump_content(self.get_content_without_compacting(root), &mut names);
+            let rank = self.get_rank(root);
+
+            dump.push_str(&format!("v{next} = {content} at {rank}\n"));
+            next += 1;
+        }
+
+        dump
+    }
+
+    fn dump_content(&self, content: &Content, names: &mut DumpNames) -> String {
+        let name_index = |index: &SubsIndex<Lowercase>| self[*index].as_str().to_string();
+
+        match content {
+            Content::FlexVar(None) => "Flex".to_string(),
+            Content::FlexVar(Some(name)) => format!("Flex({})", name_index(name)),
+            Content::FlexAbleVar(name, abilities) => format!(
+                "FlexAble({}, {:?})",
+                name.as_ref().map_or("_".to_string(), name_index),
+                self.get_subs_slice(*abilities)
+            ),
+            Content::RigidVar(name) => format!("Rigid({})", name_index(name)),
+            Content::RigidAbleVar(name, abilities) => format!(
+                "RigidAble({}, {:?})",
+                name_index(name),
+                self.get_subs_slice(*abilities)
+            ),
+            Content::RecursionVar { structure, .. } => {
+                format!("Recursion({})", names.name(self, *structure))
+            }
+            Content::Structure(flat_type) => self.dump_flat_type(flat_type, names),
+            Content::Alias(symbol, arguments, actual, kind) => {
+                let wrap = match kind {
+                    AliasKind::Structural => "Alias",
+                    AliasKind::Opaque => "Opaque",
+                };
+                let arguments =
+                    self.dump_vars(self.get_subs_slice(arguments.all_variables()), names);
+
+                format!(
+                    "{wrap}({symbol:?}, [{arguments}], {})",
+                    names.name(self, *actual)
+                )
+            }
+            Content::LambdaSet(LambdaSet {
+                solved,
+                recursion_var,
+                unspecialized,
+                ambient_function,
+            }) => {
+                let mut lambdas = Vec::new();
+                for (symbol, captures) in solved.iter_from_subs(self) {
+                    let captures = self.dump_vars(captures, names);
+                    lambdas.push(format!("{symbol:?} [{captures}]"));
+                }
+
+                let mut dump = format!("LambdaSet([{}]", lambdas.join(", "));
+                if let Some(rec_var) = recursion_var.into_variable() {
+                    dump.push_str(&format!(" as {}", names.name(self, rec_var)));
+                }
+                for Uls(var, member, region) in self.get_subs_slice(*unspecialized) {
+                    let var = names.name(self, *var);
+                    dump.push_str(&format!(" + ({var}:{member:?}:{region})"));
+                }
+                dump.push_str(&format!(", ^{})", names.name(self, *ambient_function)));
+
+                dump
+            }
+            Content::ErasedLambda => "ErasedLambda".to_string(),
+            Content::Pure => "Pure".to_string(),
+            Content::Effectful => "Effectful".to_string(),
+            Content::RangedNumber(range) => format!("RangedNumber({range:?})"),
+            Content::Error => "Error".to_string(),
+        }
+    }
+
+    fn dump_vars(&self, vars: &[Variable], names: &mut DumpNames) -> String {
+        let named: Vec<_> = vars.iter().map(|var| names.name(self, *var)).collect();
+
+        named.join(", ")
+    }
+
+    fn dump_flat_type(&self, flat_type: &FlatType, names: &mut DumpNames) -> String {
+        let dump_tags = |tags: &UnionTags, names: &mut DumpNames| {
+            let mut dumped = Vec::new();
+            for (tag, payload) in tags.iter_from_subs(self) {
+                let mut dump = tag.0.as_str().to_string();
+                for var in payload {
+                    dump.push(' ');
+                    dump.push_str(&names.name(self, *var));
+                }
+                dumped.push(dump);
+            }
+
+            dumped.join(", ")
+        };
+
+        match flat_type {
+            FlatType::Apply(symbol, arguments) => {
+                let arguments = self.dump_vars(self.get_subs_slice(*arguments), names);
+
+                format!("Apply({symbol:?}, [{arguments}])")
+            }
+            FlatType::Func(arguments, lambda_set, ret, fx) => {
+                let arguments = self.dump_vars(self.get_subs_slice(*arguments), names);
+                let lambda_set = names.name(self, *lambda_set);
+                let ret = names.name(self, *ret);
+                let fx = names.name(self, *fx);
+
+                format!("Func([{arguments}], {lambda_set}, {ret}, {fx})")
+            }
+            FlatType::EffectfulFunc => "EffectfulFunc".to_string(),
+            FlatType::Record(fields, ext) => {
+                let mut dumped = Vec::new();
+                for (name_index, var_index, field_index) in fields.iter_all() {
+                    let separator = match self[field_index] {
+                        RecordField::Optional(_) => "?",
+                        RecordField::RigidOptional(_) => "r?",
+                        RecordField::Required(_) | RecordField::Demanded(_) => ":",
+                        RecordField::RigidRequired(_) => "r:",
+                    };
+                    let var = names.name(self, self[var_index]);
+                    dumped.push(format!("{} {separator} {var}", self[name_index].as_str()));
+                }
+
+                format!("{{ {} }}{}", dumped.join(", "), names.name(self, *ext))
+            }
+            FlatType::Tuple(elems, ext) => {
+                let mut dumped = Vec::new();
+                for (index, var_index) in elems.iter_all() {
+                    let var = names.name(self, self[var_index]);
+                    dumped.push(format!("{}: {var}", self[index]));
+                }
+
+                format!("( {} ){}", dumped.join(", "), names.name(self, *ext))
+            }
+            FlatType::TagUnion(tags, ext) => {
+                let tags = dump_tags(tags, names);
+                format!("[{tags}]{}", names.name(self, ext.var()))
+            }
+            FlatType::FunctionOrTagUnion(tag_names, symbols, ext) => format!(
+                "FunctionOrTagUnion({:?}, {:?}, {})",
+                self.get_subs_slice(*tag_names),
+                self.get_subs_slice(*symbols),
+                names.name(self, ext.var())
+            ),
+            FlatType::RecursiveTagUnion(rec, tags, ext) => {
+                let tags = dump_tags(tags, names);
+                let ext = names.name(self, ext.var());
+                format!("[{tags}]{ext} as {}", names.name(self, *rec))
+            }
+            FlatType::EmptyRecord => "{}".to_string(),
+            FlatType::EmptyTuple => "()".to_string(),
+            FlatType::EmptyTagUnion => "[]".to_string(),
+        }
+    }
+
     #[inline(always)]
     pub fn set(&mut self, key: Variable, r_value: Descriptor) {
         let l_key = self.utable.root_key(key);
@@ -1961,6 +2185,25 @@ impl Subs {
         result
     }
 
+    /// Looks for `needle` within the type rooted at `haystack`, e.g. to check whether a
+    /// variable is mentioned by a type without unifying anything.
+    ///
+    /// Returns the root variables on the way from `haystack` down to `needle`, both included,
+    /// or [None] if `needle` doesn't occur. Unlike [Subs::occurs], this takes `subs` by shared
+    /// reference, and it follows [Content::RecursionVar]s to the structure they stand for;
+    /// every variable is visited at most once, so recursive types are fine.
+    pub fn occurs_in(&self, needle: Variable, haystack: Variable) -> Option<Vec<Variable>> {
+        let needle = self.get_root_key_without_compacting(needle);
+        let mut visited = MutSet::default();
+        let mut path = Vec::new();
+
+        if occurs_in_help(self, needle, haystack, &mut visited, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
     /// Returns the new recursion variable, which should be introduced to the environment as
     /// appropriate.
     #[must_use]
@@ -2093,6 +2336,8 @@ impl Subs {
         (var.index() as usize) < self.len()
     }
 
+    /// Save the current state of the unification table, so that speculative unifications can
+    /// later be undone with [`Subs::rollback_to`].
     pub fn snapshot(&mut self) -> SubsSnapshot {
         SubsSnapshot {
             utable_snapshot: self.utable.snapshot(),
@@ -2252,6 +2497,36 @@ impl From<usize> for Rank {
     }
 }
 
+/// The canonical numbering of the roots printed by [Subs::debug_dump_from].
+#[derive(Default)]
+struct DumpNames {
+    numbers: FnvMap<Variable, usize>,
+    /// Roots in the order they were first reached; each one's number is its index.
+    roots: Vec<Variable>,
+}
+
+impl DumpNames {
+    fn name(&mut self, subs: &Subs, var: Variable) -> String {
+        let root = subs.get_root_key_without_compacting(var);
+        let number = *self.numbers.entry(root).or_insert_with(|| {
+            self.roots.push(root);
+            self.roots.len() - 1
+        });
+
+        format!("v{number}")
+    }
+}
+
+/// What a [Variable] resolves to, as returned by [Subs::explain_variable].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ResolvedContent<'a> {
+    /// The root of the variable's equivalence class.
+    pub root: Variable,
+    pub content: &'a Content,
+    pub rank: Rank,
+    pub mark: Mark,
+}
+
 #[derive(Clone, Copy)]
 pub struct Descriptor {
     pub content: Content,
@@ -2598,6 +2873,7 @@ pub enum FlatType {
 
     RecursiveTagUnion(Variable, UnionTags, TagExt),
     EmptyRecord,
+    EmptyTuple,
     EmptyTagUnion,
 }
 
@@ -3495,7 +3771,7 @@ fn occurs(
 
                     short_circuit_help(subs, root_var, ctx, ext_var)
                 }
-                EmptyRecord | EmptyTagUnion | EffectfulFunc => Ok(()),
+                EmptyRecord | EmptyTuple | EmptyTagUnion | EffectfulFunc => Ok(()),
             },
             Alias(_, args, _, _) => {
                 // THEORY: we only need to explore the args, as that is the surface of all
@@ -3536,6 +3812,89 @@ fn occurs(
     }
 }
 
+fn occurs_in_help(
+    subs: &Subs,
+    needle: Variable,
+    var: Variable,
+    visited: &mut MutSet<Variable>,
+    path: &mut Vec<Variable>,
+) -> bool {
+    use self::Content::*;
+    use self::FlatType::*;
+
+    let root_var = subs.get_root_key_without_compacting(var);
+
+    if !visited.insert(root_var) {
+        return false;
+    }
+
+    path.push(root_var);
+
+    if root_var == needle {
+        return true;
+    }
+
+    let mut children = Vec::new();
+    let push_union = |children: &mut Vec<Variable>, slices: SubsSlice<VariableSubsSlice>| {
+        for slice_index in slices {
+            children.extend(subs.get_subs_slice(subs[slice_index]));
+        }
+    };
+
+    match subs.get_content_without_compacting(root_var) {
+        FlexVar(_)
+        | RigidVar(_)
+        | FlexAbleVar(_, _)
+        | RigidAbleVar(_, _)
+        | ErasedLambda
+        | Pure
+        | Effectful
+        | RangedNumber(_)
+        | Error => {}
+        RecursionVar { structure, .. } => children.push(*structure),
+        Structure(flat_type) => match flat_type {
+            Apply(_, args) => children.extend(subs.get_subs_slice(*args)),
+            Func(arg_vars, closure_var, ret_var, fx_var) => {
+                children.extend(subs.get_subs_slice(*arg_vars));
+                children.extend([*closure_var, *ret_var, *fx_var]);
+            }
+            Record(vars_by_field, ext) => {
+                let slice =
+                    VariableSubsSlice::new(vars_by_field.variables_start, vars_by_field.length);
+                children.extend(subs.get_subs_slice(slice));
+                children.push(*ext);
+            }
+            Tuple(vars_by_elem, ext) => {
+                let slice =
+                    VariableSubsSlice::new(vars_by_elem.variables_start, vars_by_elem.length);
+                children.extend(subs.get_subs_slice(slice));
+                children.push(*ext);
+            }
+            TagUnion(tags, ext) | RecursiveTagUnion(_, tags, ext) => {
+                push_union(&mut children, tags.variables());
+                children.push(ext.var());
+            }
+            FunctionOrTagUnion(_, _, ext) => children.push(ext.var()),
+            EmptyRecord | EmptyTuple | EmptyTagUnion | EffectfulFunc => {}
+        },
+        Alias(_, args, real_var, _) => {
+            children.extend(args.into_iter().map(|var_index| subs[var_index]));
+            children.push(*real_var);
+        }
+        LambdaSet(self::LambdaSet { solved, .. }) => push_union(&mut children, solved.variables()),
+    }
+
+    for child in children {
+        if occurs_in_help(subs, needle, child, visited, path) {
+            return true;
+        }
+    }
+
+    path.pop();
+
+    false
+}
+
 #[inline(always)]
 fn occurs_union<L: Label>(
     subs: &mut Subs,
@@ -3689,7 +4048,7 @@ fn explicit_substitute(
                         subs.set_content(in_var, Structure(Tuple(vars_by_elem, new_ext)));
                     }
 
-                    EmptyRecord | EmptyTagUnion | EffectfulFunc => {}
+                    EmptyRecord | EmptyTuple | EmptyTagUnion | EffectfulFunc => {}
                 }
 
                 in_var
@@ -3875,9 +4234,10 @@ fn get_var_names(
                     accum
                 }
 
-                FlatType::EmptyRecord | FlatType::EmptyTagUnion | FlatType::EffectfulFunc => {
-                    taken_names
-                }
+                FlatType::EmptyRecord
+                | FlatType::EmptyTuple
+                | FlatType::EmptyTagUnion
+                | FlatType::EffectfulFunc => taken_names,
 
                 FlatType::Record(vars_by_field, ext) => {
                     let mut accum = get_var_names(subs, ext, taken_names);
@@ -4243,6 +4603,7 @@ fn flat_type_to_err_type(
 
         EffectfulFunc => ErrorType::EffectfulFunc,
         EmptyRecord => ErrorType::Record(SendMap::default(), TypeExt::Closed),
+        EmptyTuple => ErrorType::Tuple(Vec::default(), TypeExt::Closed),
         EmptyTagUnion => ErrorType::TagUnion(SendMap::default(), TypeExt::Closed, pol),
 
         Record(vars_by_field, ext) => {
@@ -4440,12 +4801,10 @@ fn get_fresh_error_var_name(state: &mut ErrorTypeState) -> Lowercase {
     //
     // We want to claim both the "#name" and "name" forms, because if "#name" appears multiple
     // times during error type reporting, we'll use "name" for display.
-    let (name, new_index) = name_type_var(
-        "",
-        state.letters_used,
-        &mut state.taken.iter(),
-        |var, str| var.as_str() == str,
-    );
+    let (name, new_index) =
+        name_type_var("", state.letters_used, state.taken.iter(), |var, str| {
+            var.as_str() == str
+        });
 
     state.letters_used = new_index;
 
@@ -4699,6 +5058,7 @@ impl StorageSubs {
                 ext.map(|v| Self::offset_variable(offsets, v)),
             ),
             FlatType::EmptyRecord => FlatType::EmptyRecord,
+            FlatType::EmptyTuple => FlatType::EmptyTuple,
             FlatType::EmptyTagUnion => FlatType::EmptyTagUnion,
             FlatType::EffectfulFunc => FlatType::EffectfulFunc,
         }
@@ -4998,7 +5358,7 @@ fn storage_copy_var_to_help(env: &mut StorageCopyVarToEnv<'_>, var: Variable) ->
                     Func(new_arguments, new_closure_var, new_ret_var, new_fx_var)
                 }
 
-                same @ EmptyRecord | same @ EmptyTagUnion => same,
+                same @ EmptyRecord | same @ EmptyTuple | same @ EmptyTagUnion => same,
 
                 Record(fields, ext) => {
                     let record_fields = {
@@ -5344,7 +5704,9 @@ fn is_registered(content: &Content) -> bool {
         | Content::RigidVar(_)
         | Content::FlexAbleVar(..)
         | Content::RigidAbleVar(..) => false,
-        Content::Structure(FlatType::EmptyRecord | FlatType::EmptyTagUnion) => false,
+        Content::Structure(
+            FlatType::EmptyRecord | FlatType::EmptyTuple | FlatType::EmptyTagUnion,
+        ) => false,
         Content::ErasedLambda => false,
         Content::Pure | Content::Effectful => false,
 
@@ -5469,7 +5831,7 @@ fn copy_import_to_help(env: &mut CopyImportEnv<'_>, max_rank: Rank, var: Variabl
                     Func(new_arguments, new_closure_var, new_ret_var, new_fx_var)
                 }
 
-                same @ EmptyRecord | same @ EmptyTagUnion => same,
+                same @ EmptyRecord | same @ EmptyTuple | same @ EmptyTagUnion => same,
 
                 Record(fields, ext) => {
                     let record_fields = {
@@ -5855,7 +6217,7 @@ fn instantiate_rigids_help(subs: &mut Subs, max_rank: Rank, initial: Variable) {
                     stack.push(fx_var);
                 }
 
-                EmptyRecord | EmptyTagUnion => (),
+                EmptyRecord | EmptyTuple | EmptyTagUnion => (),
 
                 Record(fields, ext) => {
                     let fields = *fields;
@@ -6009,7 +6371,10 @@ pub fn get_member_lambda_sets_at_region(subs: &Subs, var: Variable, target_regio
                     );
                     stack.push(ext.var());
                 }
-                FlatType::EffectfulFunc | FlatType::EmptyRecord | FlatType::EmptyTagUnion => {}
+                FlatType::EffectfulFunc
+                | FlatType::EmptyRecord
+                | FlatType::EmptyTuple
+                | FlatType::EmptyTagUnion => {}
             },
             Content::Alias(_, _, real_var, _) => {
                 stack.push(*real_var);
@@ -6088,6 +6453,7 @@ fn is_inhabited(subs: &Subs, var: Variable) -> bool {
                 FlatType::EffectfulFunc => {}
                 FlatType::FunctionOrTagUnion(_, _, _) => {}
                 FlatType::EmptyRecord => {}
+                FlatType::EmptyTuple => {}
                 FlatType::EmptyTagUnion => {
                     return false;
                 }
@@ -6101,3 +6467,233 @@ fn is_inhabited(subs: &Subs, var: Variable) -> bool {
 
     true
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record_of(subs: &mut Subs, field: &str, var: Variable) -> Content {
+        let fields = RecordFields::insert_into_subs(
+            subs,
+            [(Lowercase::from(field), RecordField::Required(var))],
+        );
+
+        Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD))
+    }
+
+    #[test]
+    fn debug_dump_numbers_roots_canonically() {
+        let dump = |extra_vars: usize| {
+            let mut subs = Subs::new();
+            for _ in 0..extra_vars {
+                subs.fresh_unnamed_flex_var();
+            }
+
+            let a = subs.fresh_unnamed_flex_var();
+            let b = subs.fresh_unnamed_flex_var();
+            subs.union(a, b, Descriptor::from(Content::FlexVar(None)));
+
+            let content = record_of(&mut subs, "x", b);
+            let record = subs.fresh(Descriptor::from(content));
+
+            subs.debug_dump_from([record, a])
+        };
+
+        let lines: Vec<_> = dump(0).lines().map(str::to_string).collect();
+        assert_eq!(lines[..2], ["v0 = { x : v1 }v2 at 0", "v1 = Flex at 0"]);
+        // `a` is the same root as `b`, so it isn't printed again
+        assert_eq!(lines.len(), 3);
+
+        assert_eq!(dump(0), dump(10));
+    }
+
+    #[test]
+    fn fresh_flex_var_resolves_to_itself() {
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_flex_var(Rank::toplevel());
+        let b = subs.fresh_flex_var(Rank::toplevel());
+
+        assert_eq!(subs.get_root_key_without_compacting(a), a);
+        assert!(!subs.equivalent_without_compacting(a, b));
+        assert_eq!(
+            subs.get_content_without_compacting(a),
+            &Content::FlexVar(None)
+        );
+        assert_eq!(subs.get_rank_mark(a), (Rank::toplevel(), Mark::NONE));
+        assert_eq!(subs.get_copy(a), OptVariable::NONE);
+    }
+
+    #[test]
+    fn fresh_rigid_var_is_named() {
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_rigid_var("a".into(), Rank::GENERALIZED);
+
+        assert_eq!(subs.get_root_key_without_compacting(a), a);
+        match subs.get_content_without_compacting(a) {
+            Content::RigidVar(name_index) => assert_eq!(subs[*name_index].as_str(), "a"),
+            content => panic!("expected a rigid var, got {content:?}"),
+        }
+        assert_eq!(subs.get_rank(a), Rank::GENERALIZED);
+        assert_eq!(subs.get_mark(a), Mark::NONE);
+    }
+
+    #[test]
+    fn explain_chained_flex_vars() {
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let b = subs.fresh_unnamed_flex_var();
+        let c = subs.fresh_unnamed_flex_var();
+
+        let desc = Descriptor {
+            rank: Rank::toplevel(),
+            ..Descriptor::from(Content::FlexVar(None))
+        };
+        subs.union(a, b, desc);
+        subs.union(b, c, desc);
+
+        let root = subs.get_root_key_without_compacting(a);
+        let explained = subs.explain_variable(c);
+
+        assert_eq!(explained.root, root);
+        assert_eq!(explained.content, &Content::FlexVar(None));
+        assert_eq!(explained.rank, Rank::toplevel());
+        assert_eq!(explained.mark, Mark::NONE);
+
+        assert_eq!(subs.explain_variable(a), explained);
+        assert_eq!(subs.explain_variable(b), explained);
+    }
+
+    #[test]
+    fn rollback_undoes_union() {
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let b = subs.fresh_unnamed_flex_var();
+        let x = subs.fresh_unnamed_flex_var();
+        let content = record_of(&mut subs, "x", x);
+
+        let snapshot = subs.snapshot();
+
+        // a ~ b ~ c ~ { x : x }, with c introduced after the snapshot
+        let c = subs.fresh_unnamed_flex_var();
+        subs.union(a, b, Descriptor::from(content));
+        subs.union(b, c, Descriptor::from(content));
+        assert!(subs.equivalent_without_compacting(a, c));
+
+        subs.rollback_to(snapshot);
+
+        assert!(!subs.equivalent_without_compacting(a, b));
+        assert!(!subs.contains(c));
+
+        for var in [a, b] {
+            assert_eq!(
+                subs.get_content_without_compacting(var),
+                &Content::FlexVar(None)
+            );
+        }
+    }
+
+    #[test]
+    fn occurs_check_passes_for_finite_record() {
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let content = record_of(&mut subs, "x", a);
+        let record = subs.fresh(Descriptor::from(content));
+
+        assert_eq!(subs.occurs(record), Ok(()));
+    }
+
+    #[test]
+    fn occurs_check_reports_direct_infinite_record() {
+        // a ~ { x : a }
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let content = record_of(&mut subs, "x", a);
+        let record = subs.fresh(Descriptor::from(content));
+        subs.union(a, record, Descriptor::from(content));
+
+        let root = subs.get_root_key_without_compacting(a);
+
+        assert_eq!(subs.occurs(a), Err((root, vec![root])));
+    }
+
+    #[test]
+    fn occurs_check_records_path_to_recursive_occurrence() {
+        // a ~ { x : { y : a } }
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let inner_content = record_of(&mut subs, "y", a);
+        let inner = subs.fresh(Descriptor::from(inner_content));
+        let outer_content = record_of(&mut subs, "x", inner);
+        let outer = subs.fresh(Descriptor::from(outer_content));
+        subs.union(a, outer, Descriptor::from(outer_content));
+
+        let root = subs.get_root_key_without_compacting(a);
+
+        // The chain leads from the recursive occurrence back to the variable itself.
+        assert_eq!(subs.occurs(a), Err((root, vec![inner, root])));
+
+        // The occurs check leaves no marks behind, so asking again gives the same answer.
+        assert_eq!(subs.occurs(a), Err((root, vec![inner, root])));
+    }
+
+    #[test]
+    fn occurs_in_finds_direct_occurrence() {
+        // { x : a }
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let content = record_of(&mut subs, "x", a);
+        let record = subs.fresh(Descriptor::from(content));
+
+        assert_eq!(subs.occurs_in(a, record), Some(vec![record, a]));
+        assert_eq!(subs.occurs_in(a, a), Some(vec![a]));
+    }
+
+    #[test]
+    fn occurs_in_finds_nested_occurrence() {
+        // { x : { y : a } }
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let inner_content = record_of(&mut subs, "y", a);
+        let inner = subs.fresh(Descriptor::from(inner_content));
+        let outer_content = record_of(&mut subs, "x", inner);
+        let outer = subs.fresh(Descriptor::from(outer_content));
+
+        assert_eq!(subs.occurs_in(a, outer), Some(vec![outer, inner, a]));
+    }
+
+    #[test]
+    fn occurs_in_returns_none_when_absent() {
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let b = subs.fresh_unnamed_flex_var();
+        let content = record_of(&mut subs, "x", a);
+        let record = subs.fresh(Descriptor::from(content));
+
+        assert_eq!(subs.occurs_in(b, record), None);
+        assert_eq!(subs.occurs_in(record, a), None);
+    }
+
+    #[test]
+    fn occurs_in_terminates_on_infinite_type() {
+        // a ~ { x : a }
+        let mut subs = Subs::new();
+
+        let a = subs.fresh_unnamed_flex_var();
+        let b = subs.fresh_unnamed_flex_var();
+        let content = record_of(&mut subs, "x", a);
+        let record = subs.fresh(Descriptor::from(content));
+        subs.union(a, record, Descriptor::from(content));
+
+        assert_eq!(subs.occurs_in(b, a), None);
+    }
+}
@@ -6,7 +6,7 @@ use crate::subs::{
 };
 use roc_collections::all::{HumanIndex, ImMap, ImSet, MutMap, MutSet, SendMap};
 use roc_collections::soa::{index_push_new, slice_extend_new};
-use roc_collections::VecMap;
+use roc_collections::{VecMap, VecSet};
 use roc_error_macros::internal_error;
 use roc_module::called_via::CalledVia;
 use roc_module::ident::{ForeignSymbol, Lowercase, TagName};
@@ -2199,7 +2199,33 @@ impl Type {
         result
     }
 
+    /// All variables of this type that aren't bound by it. The recursion variable of a
+    /// recursive tag union is bound by that union, and so isn't free.
+    pub fn free_variables(&self) -> VecSet<Variable> {
+        self.variables().into_iter().collect()
+    }
+
     pub fn substitute(&mut self, substitutions: &ImMap<Variable, Type>) {
+        self.substitute_help(&|var| substitutions.get(&var), false)
+    }
+
+    /// Returns a copy of this type with the variables in `mapping` replaced, e.g. to
+    /// instantiate an alias's body with the types it's applied to.
+    ///
+    /// Unlike [Type::substitute], the recursion variable of a recursive tag union is bound by
+    /// that union, so neither it nor its occurrences within the union are ever replaced.
+    pub fn substituted(&self, mapping: &VecMap<Variable, Type>) -> Type {
+        let mut typ = self.clone();
+        typ.substitute_help(&|var| mapping.get(&var), true);
+
+        typ
+    }
+
+    fn substitute_help<'t>(
+        &mut self,
+        lookup: &dyn Fn(Variable) -> Option<&'t Type>,
+        bind_recursion_vars: bool,
+    ) {
         use Type::*;
 
         let mut stack = vec![self];
@@ -2207,7 +2233,7 @@ impl Type {
         while let Some(typ) = stack.pop() {
             match typ {
                 Variable(v) => {
-                    if let Some(replacement) = substitutions.get(v) {
+                    if let Some(replacement) = lookup(*v) {
                         *typ = replacement.clone();
                     }
                 }
@@ -2237,17 +2263,28 @@ impl Type {
                     }
                 }
                 RecursiveTagUnion(rec, tags, ext) => {
-                    if let Some(replacement) = substitutions.get(rec) {
-                        let new_rec_var = match replacement {
-                            Type::Variable(v) => *v,
-                            _ => panic!("Recursion var substitution must be a variable"),
-                        };
+                    if bind_recursion_vars {
+                        let rec = *rec;
+                        let lookup_in_union = |var| lookup(var).filter(|_| var != rec);
 
-                        *rec = new_rec_var;
-                    }
+                        for (_, args) in tags {
+                            for arg in args.iter_mut() {
+                                arg.substitute_help(&lookup_in_union, bind_recursion_vars);
+                            }
+                        }
+                    } else {
+                        if let Some(replacement) = lookup(*rec) {
+                            let new_rec_var = match replacement {
+                                Type::Variable(v) => *v,
+                                _ => panic!("Recursion var substitution must be a variable"),
+                            };
 
-                    for (_, args) in tags {
-                        stack.extend(args.iter_mut());
+                            *rec = new_rec_var;
+                        }
+
+                        for (_, args) in tags {
+                            stack.extend(args.iter_mut());
+                        }
                     }
 
                     if let TypeExtension::Open(ext, _) = ext {
@@ -2319,7 +2356,7 @@ impl Type {
                     unspecialized: Uls(v, _, _),
                 } => {
                     debug_assert!(
-                        substitutions.get(v).is_none(),
+                        lookup(*v).is_none(),
                         "unspecialized lambda sets should never be substituted before solving"
                     );
                 }
@@ -2704,6 +2741,81 @@ impl Type {
         }
     }
 
+    fn contains_alias_ext(ext: &TypeExtension, alias: Symbol) -> bool {
+        match ext {
+            TypeExtension::Open(ext, _) => ext.contains_alias(alias),
+            TypeExtension::Closed => false,
+        }
+    }
+
+    /// Whether this type mentions the alias `alias`, either as a delayed alias or as an
+    /// already instantiated one. Unlike [Type::contains_symbol], type constructors applied
+    /// by name (like `List` or an opaque type) don't count.
+    pub fn contains_alias(&self, alias: Symbol) -> bool {
+        use Type::*;
+
+        match self {
+            Function(args, closure, ret, fx) => {
+                ret.contains_alias(alias)
+                    || closure.contains_alias(alias)
+                    || args.iter().any(|arg| arg.contains_alias(alias))
+                    || fx.contains_alias(alias)
+            }
+            FunctionOrTagUnion(_, _, ext) => Self::contains_alias_ext(ext, alias),
+            ClosureTag { captures, .. } => captures.iter().any(|t| t.contains_alias(alias)),
+            RecursiveTagUnion(_, tags, ext) | TagUnion(tags, ext) => {
+                Self::contains_alias_ext(ext, alias)
+                    || tags
+                        .iter()
+                        .flat_map(|v| v.1.iter())
+                        .any(|arg| arg.contains_alias(alias))
+            }
+            Record(fields, ext) => {
+                Self::contains_alias_ext(ext, alias)
+                    || fields
+                        .values()
+                        .any(|arg| arg.as_inner().contains_alias(alias))
+            }
+            Tuple(elems, ext) => {
+                Self::contains_alias_ext(ext, alias)
+                    || elems.iter().any(|(_, arg)| arg.contains_alias(alias))
+            }
+            DelayedAlias(AliasCommon {
+                symbol,
+                type_arguments,
+                lambda_set_variables,
+                ..
+            }) => {
+                *symbol == alias
+                    || type_arguments
+                        .iter()
+                        .any(|v| v.value.typ.contains_alias(alias))
+                    || lambda_set_variables
+                        .iter()
+                        .any(|v| v.0.contains_alias(alias))
+            }
+            Alias {
+                symbol,
+                type_arguments,
+                actual,
+                ..
+            } => {
+                *symbol == alias
+                    || type_arguments.iter().any(|v| v.typ.contains_alias(alias))
+                    || actual.contains_alias(alias)
+            }
+            Apply(_, args, _) => args.iter().any(|arg| arg.value.contains_alias(alias)),
+            RangedNumber(_)
+            | UnspecializedLambdaSet { .. }
+            | EmptyRec
+            | EmptyTagUnion
+            | Error
+            | Variable(_)
+            | Pure
+            | Effectful => false,
+        }
+    }
+
     pub fn symbols(&self) -> Vec<Symbol> {
         symbols_help(self)
     }
@@ -3660,6 +3772,43 @@ impl Alias {
                 .chain(self.type_variables.iter().map(|tv| &tv.region)),
         )
     }
+
+    /// The body of this alias, with its type variables replaced by `args`.
+    ///
+    /// The lambda set, extension and recursion variables of the alias are replaced by fresh
+    /// variables, so that two expansions of the same alias don't share them.
+    pub fn expand(&self, var_store: &mut VarStore, args: &[Variable]) -> Type {
+        debug_assert_eq!(self.type_variables.len(), args.len());
+
+        let mut substitutions: ImMap<Variable, Type> = self
+            .type_variables
+            .iter()
+            .zip(args)
+            .map(|(loc_var, arg)| (loc_var.value.var, Type::Variable(*arg)))
+            .collect();
+
+        for lambda_set in self.lambda_set_variables.iter() {
+            match lambda_set.0 {
+                Type::Variable(var) => {
+                    substitutions.insert(var, Type::Variable(var_store.fresh()));
+                }
+                _ => internal_error!("lambda sets of an alias should only be variables"),
+            }
+        }
+
+        for &var in self
+            .infer_ext_in_output_variables
+            .iter()
+            .chain(self.recursion_variables.iter())
+        {
+            substitutions.insert(var, Type::Variable(var_store.fresh()));
+        }
+
+        let mut typ = self.typ.clone();
+        typ.substitute(&substitutions);
+
+        typ
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -3668,6 +3817,80 @@ pub enum Mismatch {
     TypeNotInRange,
     DisjointLambdaSets,
     DoesNotImplementAbiity(Variable, Symbol),
+    /// Two records did not unify. Lists the fields that one side requires but the other
+    /// (closed) side does not have, and the shared fields whose types did not unify.
+    RecordFieldMismatch {
+        missing_in_left: Vec<Lowercase>,
+        missing_in_right: Vec<Lowercase>,
+        type_mismatches: Vec<(Lowercase, Vec<Mismatch>)>,
+    },
+    /// Two tuples did not unify because one of them is closed, and has fewer elements than the
+    /// other.
+    TupleArityMismatch {
+        left_arity: usize,
+        right_arity: usize,
+    },
+    /// A tag is in both unions, but with a different number of payloads.
+    TagMismatch {
+        tag: TagName,
+        left_arity: usize,
+        right_arity: usize,
+    },
+    /// A tag is in both unions with the same number of payloads, but the payloads at these
+    /// positions did not unify.
+    TagPayloadMismatch {
+        tag: TagName,
+        payloads: Vec<usize>,
+    },
+}
+
+/// What unification found out about why two types did not unify, beyond the types themselves,
+/// so that error messages can name the part of the types that is to blame.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct MismatchDetails {
+    /// The record, tuple and tag union mismatches, from the innermost to the outermost type
+    pub mismatches: Vec<Mismatch>,
+    /// Where the two types differ, as found before unification turned them into errors
+    pub diff: TypeDiff,
+}
+
+/// The structural difference between two types, as computed by `roc_unify::unify::type_diff`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TypeDiff {
+    /// The types match, or could be made to match by unification.
+    #[default]
+    Same,
+    /// The types do not match, and there is no common structure to look inside of,
+    /// e.g. `I64` vs `Str`.
+    Different,
+    /// The same type constructor or alias, applied to arguments of which some differ.
+    Apply(Symbol, Vec<TypeDiff>),
+    /// Functions of the same arity, of which some arguments or the return type differ.
+    Function(Vec<TypeDiff>, Box<TypeDiff>),
+    /// Tuples of the same size, of which some elements differ.
+    Tuple(Vec<TypeDiff>),
+    Record {
+        /// The fields of both records, in sorted order
+        fields: Vec<(Lowercase, TypeDiff)>,
+        /// Fields that only the right record has, and that the closed left record can't gain
+        missing_in_left: Vec<Lowercase>,
+        /// Fields that only the left record has, and that the closed right record can't gain
+        missing_in_right: Vec<Lowercase>,
+    },
+    TagUnion {
+        /// The tags of both unions, in sorted order, with the diffs of their payloads
+        tags: Vec<(TagName, Vec<TypeDiff>)>,
+        /// Tags that only the right union has, and that the closed left union can't gain
+        missing_in_left: Vec<TagName>,
+        /// Tags that only the left union has, and that the closed right union can't gain
+        missing_in_right: Vec<TagName>,
+    },
+}
+
+impl TypeDiff {
+    pub fn is_same(&self) -> bool {
+        matches!(self, TypeDiff::Same)
+    }
 }
 
 pub type DoesNotImplementAbility = Vec<(ErrorType, Symbol)>;
@@ -4148,37 +4371,27 @@ fn write_type_ext(ext: TypeExt, buf: &mut String) {
 
 static THE_LETTER_A: u32 = 'a' as u32;
 
-/// Generates a fresh type variable name, composed of lowercase alphabetic characters in sequence.
+/// Generates a fresh type variable name: `a` through `z`, then `a1` through `z1`, then `a2`, and
+/// so on. Returns the name along with the new number of letters used.
 pub fn name_type_var<I, F: FnMut(&I, &str) -> bool>(
     prefix: &str,
-    letters_used: u32,
-    taken: &mut impl Iterator<Item = I>,
+    mut letters_used: u32,
+    taken: impl Iterator<Item = I> + Clone,
     mut predicate: F,
 ) -> (Lowercase, u32) {
-    // TODO we should arena-allocate this String,
-    // so all the strings in the entire pass only require ~1 allocation.
-    let mut buf = String::with_capacity(prefix.len() + (letters_used as usize) / 26 + 1);
-
-    buf.push_str(prefix);
+    loop {
+        let letter = std::char::from_u32(THE_LETTER_A + (letters_used % 26)).unwrap();
+        let generated_name = match letters_used / 26 {
+            0 => format!("{prefix}{letter}"),
+            round => format!("{prefix}{letter}{round}"),
+        };
 
-    let is_taken = {
-        let mut remaining = letters_used as i32;
+        letters_used += 1;
 
-        while remaining >= 0 {
-            buf.push(std::char::from_u32(THE_LETTER_A + ((remaining as u32) % 26)).unwrap());
-            remaining -= 26;
+        // If the generated name is already taken, try the next one.
+        if !taken.clone().any(|item| predicate(&item, &generated_name)) {
+            return (generated_name.into(), letters_used);
         }
-
-        let generated_name: &str = buf.as_str();
-
-        taken.any(|item| predicate(&item, generated_name))
-    };
-
-    if is_taken {
-        // If the generated name is already taken, try again.
-        name_type_var(prefix, letters_used + 1, taken, predicate)
-    } else {
-        (buf.into(), letters_used + 1)
     }
 }
 
@@ -4187,10 +4400,10 @@ pub fn name_type_var<I, F: FnMut(&I, &str) -> bool>(
 pub fn name_type_var_with_hint<I, F: FnMut(&I, &str) -> bool>(
     prefix: &str,
     hint: &str,
-    taken: &mut impl Iterator<Item = I>,
+    taken: impl Iterator<Item = I> + Clone,
     mut predicate: F,
 ) -> Lowercase {
-    if !taken.any(|item| predicate(&item, hint)) {
+    if !taken.clone().any(|item| predicate(&item, hint)) {
         return format!("{prefix}{hint}").into();
     }
 
@@ -4199,7 +4412,7 @@ pub fn name_type_var_with_hint<I, F: FnMut(&I, &str) -> bool>(
         i += 1;
         let cand = format!("{prefix}{hint}{i}");
 
-        if !taken.any(|item| predicate(&item, &cand)) {
+        if !taken.clone().any(|item| predicate(&item, &cand)) {
             return cand.into();
         }
     }
@@ -4293,6 +4506,8 @@ pub fn gather_tuple_elems_unsorted_iter(
                 }
             }
 
+            Structure(EmptyTuple) => break,
+
             Alias(_, _, actual_var, _) => {
                 // TODO according to elm/compiler: "TODO may be dropping useful alias info here"
                 var = *actual_var;
@@ -4587,6 +4802,7 @@ fn instantiate_lambda_sets_as_unspecialized(
 #[cfg(test)]
 mod test {
     use super::*;
+    use roc_module::symbol::{IdentIds, ModuleIds};
 
     #[test]
     fn instantiate_lambda_sets_as_unspecialized() {
@@ -4661,4 +4877,211 @@ mod test {
             _ => panic!(),
         }
     }
+
+    #[test]
+    fn name_type_var_continues_with_numbered_letters() {
+        let taken = ["b".to_string(), "a1".to_string()];
+        let mut letters_used = 0;
+        let mut names = Vec::new();
+
+        for _ in 0..28 {
+            let (name, new_letters_used) =
+                name_type_var("", letters_used, taken.iter(), |var, str| {
+                    var.as_str() == str
+                });
+            letters_used = new_letters_used;
+            names.push(name.as_str().to_string());
+        }
+
+        assert_eq!(&names[..3], ["a", "c", "d"]);
+        assert_eq!(&names[24..], ["z", "b1", "c1", "d1"]);
+    }
+
+    #[test]
+    fn substituted_replaces_record_alias_variables() {
+        let mut var_store = VarStore::default();
+        let a = var_store.fresh();
+        let ext = var_store.fresh();
+        let str_type = Type::Apply(Symbol::STR_STR, vec![], Region::zero());
+
+        // Pair a : { first : a, second : a }*
+        let record = |field_type: &Type| {
+            let mut fields = SendMap::default();
+            fields.insert("first".into(), RecordField::Required(field_type.clone()));
+            fields.insert("second".into(), RecordField::Required(field_type.clone()));
+
+            Type::Record(
+                fields,
+                TypeExtension::Open(Box::new(Type::Variable(ext)), ExtImplicitOpenness::No),
+            )
+        };
+
+        let mut mapping = VecMap::default();
+        mapping.insert(a, str_type.clone());
+
+        let body = record(&Type::Variable(a));
+
+        assert_eq!(body.substituted(&mapping), record(&str_type));
+        // the original type is left as it was
+        assert!(body.contains_variable(a));
+    }
+
+    #[test]
+    fn substituted_leaves_recursion_variable_alone() {
+        let mut var_store = VarStore::default();
+        let a = var_store.fresh();
+        let rec = var_store.fresh();
+        let str_type = Type::Apply(Symbol::STR_STR, vec![], Region::zero());
+
+        // ConsList a : [Cons a rec, Nil] as rec
+        let cons_list = |elem: Type| {
+            Type::RecursiveTagUnion(
+                rec,
+                vec![
+                    (TagName("Cons".into()), vec![elem, Type::Variable(rec)]),
+                    (TagName("Nil".into()), vec![]),
+                ],
+                TypeExtension::Closed,
+            )
+        };
+
+        let mut mapping = VecMap::default();
+        mapping.insert(a, str_type.clone());
+        mapping.insert(rec, Type::EmptyRec);
+
+        assert_eq!(
+            cons_list(Type::Variable(a)).substituted(&mapping),
+            cons_list(str_type)
+        );
+    }
+
+    /// The `Pair` alias mentioned by [shapes_record], defined in a test module
+    fn pair_symbol() -> Symbol {
+        let home = ModuleIds::default().get_or_insert(&"Test".into());
+
+        Symbol::new(home, IdentIds::default().add_str("Pair"))
+    }
+
+    /// { items : [Cons a rec, Nil] as rec, shape : [Circle a, Rect (Pair b Str)]c }
+    fn shapes_record(a: Variable, b: Variable, c: Variable, rec: Variable) -> Type {
+        let str_type = Type::Apply(Symbol::STR_STR, vec![], Region::zero());
+        let pair = Type::DelayedAlias(AliasCommon {
+            symbol: pair_symbol(),
+            type_arguments: vec![
+                Loc::at_zero(OptAbleType::unbound(Type::Variable(b))),
+                Loc::at_zero(OptAbleType::unbound(str_type)),
+            ],
+            lambda_set_variables: vec![],
+            infer_ext_in_output_types: vec![],
+        });
+
+        let items = Type::RecursiveTagUnion(
+            rec,
+            vec![
+                (
+                    TagName("Cons".into()),
+                    vec![Type::Variable(a), Type::Variable(rec)],
+                ),
+                (TagName("Nil".into()), vec![]),
+            ],
+            TypeExtension::Closed,
+        );
+        let shape = Type::TagUnion(
+            vec![
+                (TagName("Circle".into()), vec![Type::Variable(a)]),
+                (TagName("Rect".into()), vec![pair]),
+            ],
+            TypeExtension::Open(Box::new(Type::Variable(c)), ExtImplicitOpenness::No),
+        );
+
+        let mut fields = SendMap::default();
+        fields.insert("items".into(), RecordField::Required(items));
+        fields.insert("shape".into(), RecordField::Required(shape));
+
+        Type::Record(fields, TypeExtension::Closed)
+    }
+
+    #[test]
+    fn contains_alias_finds_nested_aliases() {
+        let mut var_store = VarStore::default();
+        let [a, b, c, rec] = std::array::from_fn(|_| var_store.fresh());
+        let typ = shapes_record(a, b, c, rec);
+
+        assert!(typ.contains_alias(pair_symbol()));
+        // `Str` is mentioned, but it isn't an alias
+        assert!(typ.contains_symbol(Symbol::STR_STR));
+        assert!(!typ.contains_alias(Symbol::STR_STR));
+        assert!(!typ.contains_alias(Symbol::LIST_LIST));
+    }
+
+    #[test]
+    fn free_variables_skips_recursion_variables() {
+        let mut var_store = VarStore::default();
+        let [a, b, c, rec] = std::array::from_fn(|_| var_store.fresh());
+        let typ = shapes_record(a, b, c, rec);
+
+        let mut free: Vec<_> = typ.free_variables().into_vec();
+        free.sort();
+
+        let mut expected = vec![a, b, c];
+        expected.sort();
+
+        assert_eq!(free, expected);
+    }
+
+    /// Pair a b : { first : a, second : {} -[l]-> b }ext
+    fn pair_alias(var_store: &mut VarStore) -> Alias {
+        let [a, b, lambda_set, ext] = std::array::from_fn(|_| var_store.fresh());
+
+        let mut fields = SendMap::default();
+        fields.insert("first".into(), RecordField::Required(Type::Variable(a)));
+        fields.insert(
+            "second".into(),
+            RecordField::Required(Type::Function(
+                vec![Type::EmptyRec],
+                Box::new(Type::Variable(lambda_set)),
+                Box::new(Type::Variable(b)),
+                Box::new(Type::Pure),
+            )),
+        );
+
+        Alias {
+            region: Region::zero(),
+            type_variables: vec![
+                Loc::at_zero(AliasVar::unbound("a".into(), a)),
+                Loc::at_zero(AliasVar::unbound("b".into(), b)),
+            ],
+            lambda_set_variables: vec![LambdaSet(Type::Variable(lambda_set))],
+            infer_ext_in_output_variables: vec![ext],
+            recursion_variables: MutSet::default(),
+            typ: Type::Record(
+                fields,
+                TypeExtension::Open(Box::new(Type::Variable(ext)), ExtImplicitOpenness::No),
+            ),
+            kind: AliasKind::Structural,
+        }
+    }
+
+    #[test]
+    fn alias_expansions_get_fresh_lambda_set_and_extension_variables() {
+        let mut var_store = VarStore::default();
+        let alias = pair_alias(&mut var_store);
+        let [x, y] = std::array::from_fn(|_| var_store.fresh());
+
+        let first = alias.expand(&mut var_store, &[x, y]).variables();
+        let second = alias.expand(&mut var_store, &[x, y]).variables();
+
+        // the arguments, plus a lambda set and an extension variable
+        assert_eq!(first.len(), 4);
+        assert_eq!(second.len(), 4);
+
+        // the two expansions only share the arguments
+        let mut shared: Vec<_> = first.iter().filter(|v| second.contains(v)).collect();
+        shared.sort();
+        assert_eq!(shared, [&x, &y]);
+
+        // and neither mentions the variables of the alias itself
+        let own = alias.typ.variables();
+        assert!(!first.iter().chain(second.iter()).any(|v| own.contains(v)));
+    }
 }
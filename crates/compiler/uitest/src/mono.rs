@@ -2,7 +2,7 @@ use std::io;
 
 use bumpalo::Bump;
 use roc_collections::MutMap;
-use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError, Threading};
+use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError, ShadowPolicy, Threading};
 use roc_module::symbol::{Interns, Symbol};
 use roc_mono::{
     ir::{Proc, ProcLayout},
@@ -45,6 +45,8 @@ pub(crate) fn write_compiled_ir<'a>(
     let load_config = LoadConfig {
         target: roc_target::Target::LinuxX64,
         function_kind: compiler_settings.function_kind,
+        step_budget: None,
+        shadow_policy: ShadowPolicy::Disallow,
         threading: Threading::Single,
         render: roc_reporting::report::RenderTarget::Generic,
         palette: roc_reporting::report::DEFAULT_PALETTE,
@@ -309,6 +309,7 @@ impl<'a> TestCase<'a> {
                 "print_only_under_alias" => infer_opts.print_only_under_alias = true,
                 "print_ranks" => infer_opts.print_ranks = true,
                 "print_variables" => infer_opts.print_variables = true,
+                "hide_lambda_sets" => infer_opts.hide_lambda_sets = true,
                 other => return Err(format!("unknown infer option: {other:?}").into()),
             }
         }
@@ -16,8 +16,10 @@ use roc_types::subs::{
     OptVariable, RecordFields, Subs, SubsIndex, SubsSlice, TagExt, TupleElems, UlsOfVar,
     UnionLabels, UnionLambdas, UnionTags, Variable, VariableSubsSlice,
 };
+pub use roc_types::types::TypeDiff;
 use roc_types::types::{
-    AliasKind, DoesNotImplementAbility, ErrorType, Mismatch, Polarity, RecordField, Uls,
+    AliasKind, DoesNotImplementAbility, ErrorType, Mismatch, MismatchDetails, Polarity,
+    RecordField, Uls,
 };
 
 use crate::env::Env;
@@ -179,7 +181,13 @@ pub enum Unified<M: MetaCollector = NoCollector> {
         /// polymorphic over metadata collection to avoid unnecessary memory usage.
         extra_metadata: M,
     },
-    Failure(Pool, ErrorType, ErrorType, DoesNotImplementAbility),
+    Failure(
+        Pool,
+        ErrorType,
+        ErrorType,
+        DoesNotImplementAbility,
+        MismatchDetails,
+    ),
 }
 
 impl<M: MetaCollector> Unified<M> {
@@ -264,6 +272,12 @@ pub struct Outcome<M: MetaCollector> {
     extra_metadata: M,
 }
 
+impl<M: MetaCollector> Outcome<M> {
+    pub fn mismatches(&self) -> &[Mismatch] {
+        &self.mismatches
+    }
+}
+
 impl<M: MetaCollector> Outcome<M> {
     fn union(&mut self, other: Self) {
         let Self {
@@ -355,6 +369,12 @@ fn unify_help<M: MetaCollector>(
             extra_metadata,
         }
     } else {
+        // Diff the types before the failed unification turns both of them into errors
+        let mut details = MismatchDetails {
+            diff: type_diff(env, var1, var2),
+            ..Default::default()
+        };
+
         let error_context = if mismatches.contains(&Mismatch::TypeNotInRange) {
             ErrorTypeContext::ExpandRanges
         } else {
@@ -366,19 +386,26 @@ fn unify_help<M: MetaCollector>(
 
         env.union(var1, var2, Content::Error.into());
 
-        let do_not_implement_ability = mismatches
-            .into_iter()
-            .filter_map(|mismatch| match mismatch {
+        let mut do_not_implement_ability = Vec::new();
+
+        for mismatch in mismatches {
+            match mismatch {
                 Mismatch::DoesNotImplementAbiity(var, ab) => {
                     let err_type =
                         env.var_to_error_type_contextual(var, error_context, observed_pol);
-                    Some((err_type, ab))
+                    do_not_implement_ability.push((err_type, ab));
                 }
-                _ => None,
-            })
-            .collect();
+                Mismatch::RecordFieldMismatch { .. }
+                | Mismatch::TupleArityMismatch { .. }
+                | Mismatch::TagMismatch { .. }
+                | Mismatch::TagPayloadMismatch { .. } => details.mismatches.push(mismatch),
+                Mismatch::TypeMismatch
+                | Mismatch::TypeNotInRange
+                | Mismatch::DisjointLambdaSets => {}
+            }
+        }
 
-        Unified::Failure(vars, type1, type2, do_not_implement_ability)
+        Unified::Failure(vars, type1, type2, do_not_implement_ability, details)
     }
 }
 
@@ -1972,9 +1999,16 @@ fn unify_record<M: MetaCollector>(
             let only_in_2 = RecordFields::insert_into_subs(env, separate.only_in_2);
             let flat_type = FlatType::Record(only_in_2, ext2);
             let sub_record = fresh(env, pool, ctx, Structure(flat_type));
-            let ext_outcome = unify_pool(env, pool, ext1, sub_record, ctx.mode);
+            let mut ext_outcome = unify_pool(env, pool, ext1, sub_record, ctx.mode);
 
             if !ext_outcome.mismatches.is_empty() {
+                let missing_in_left = record_field_names(env, only_in_2);
+                ext_outcome.mismatches.push(Mismatch::RecordFieldMismatch {
+                    missing_in_left,
+                    missing_in_right: Vec::new(),
+                    type_mismatches: Vec::new(),
+                });
+
                 return ext_outcome;
             }
 
@@ -1989,9 +2023,16 @@ fn unify_record<M: MetaCollector>(
         let only_in_1 = RecordFields::insert_into_subs(env, separate.only_in_1);
         let flat_type = FlatType::Record(only_in_1, ext1);
         let sub_record = fresh(env, pool, ctx, Structure(flat_type));
-        let ext_outcome = unify_pool(env, pool, sub_record, ext2, ctx.mode);
+        let mut ext_outcome = unify_pool(env, pool, sub_record, ext2, ctx.mode);
 
         if !ext_outcome.mismatches.is_empty() {
+            let missing_in_right = record_field_names(env, only_in_1);
+            ext_outcome.mismatches.push(Mismatch::RecordFieldMismatch {
+                missing_in_left: Vec::new(),
+                missing_in_right,
+                type_mismatches: Vec::new(),
+            });
+
             return ext_outcome;
         }
 
@@ -2014,13 +2055,27 @@ fn unify_record<M: MetaCollector>(
         let sub1 = fresh(env, pool, ctx, Structure(flat_type1));
         let sub2 = fresh(env, pool, ctx, Structure(flat_type2));
 
-        let rec1_outcome = unify_pool(env, pool, ext1, sub2, ctx.mode);
+        let mut rec1_outcome = unify_pool(env, pool, ext1, sub2, ctx.mode);
         if !rec1_outcome.mismatches.is_empty() {
+            let missing_in_left = record_field_names(env, only_in_2);
+            rec1_outcome.mismatches.push(Mismatch::RecordFieldMismatch {
+                missing_in_left,
+                missing_in_right: Vec::new(),
+                type_mismatches: Vec::new(),
+            });
+
             return rec1_outcome;
         }
 
-        let rec2_outcome = unify_pool(env, pool, sub1, ext2, ctx.mode);
+        let mut rec2_outcome = unify_pool(env, pool, sub1, ext2, ctx.mode);
         if !rec2_outcome.mismatches.is_empty() {
+            let missing_in_right = record_field_names(env, only_in_1);
+            rec2_outcome.mismatches.push(Mismatch::RecordFieldMismatch {
+                missing_in_left: Vec::new(),
+                missing_in_right,
+                type_mismatches: Vec::new(),
+            });
+
             return rec2_outcome;
         }
 
@@ -2050,6 +2105,10 @@ fn unify_tuple<M: MetaCollector>(
     let (separate, ext1, ext2) = separate_tuple_elems(env, elems1, ext1, elems2, ext2);
 
     let shared_elems = separate.in_both;
+    let arity_mismatch = Mismatch::TupleArityMismatch {
+        left_arity: shared_elems.len() + separate.only_in_1.len(),
+        right_arity: shared_elems.len() + separate.only_in_2.len(),
+    };
 
     if separate.only_in_1.is_empty() {
         if separate.only_in_2.is_empty() {
@@ -2070,9 +2129,11 @@ fn unify_tuple<M: MetaCollector>(
             let only_in_2 = TupleElems::insert_into_subs(env, separate.only_in_2);
             let flat_type = FlatType::Tuple(only_in_2, ext2);
             let sub_record = fresh(env, pool, ctx, Structure(flat_type));
-            let ext_outcome = unify_pool(env, pool, ext1, sub_record, ctx.mode);
+            let mut ext_outcome = unify_pool(env, pool, ext1, sub_record, ctx.mode);
 
             if !ext_outcome.mismatches.is_empty() {
+                ext_outcome.mismatches.push(arity_mismatch);
+
                 return ext_outcome;
             }
 
@@ -2093,9 +2154,11 @@ fn unify_tuple<M: MetaCollector>(
         let only_in_1 = TupleElems::insert_into_subs(env, separate.only_in_1);
         let flat_type = FlatType::Tuple(only_in_1, ext1);
         let sub_record = fresh(env, pool, ctx, Structure(flat_type));
-        let ext_outcome = unify_pool(env, pool, sub_record, ext2, ctx.mode);
+        let mut ext_outcome = unify_pool(env, pool, sub_record, ext2, ctx.mode);
 
         if !ext_outcome.mismatches.is_empty() {
+            ext_outcome.mismatches.push(arity_mismatch);
+
             return ext_outcome;
         }
 
@@ -2147,6 +2210,13 @@ fn unify_tuple<M: MetaCollector>(
     }
 }
 
+fn record_field_names(subs: &Subs, fields: RecordFields) -> Vec<Lowercase> {
+    fields
+        .iter_all()
+        .map(|(name_index, _, _)| subs[name_index].clone())
+        .collect()
+}
+
 enum OtherFields {
     None,
     Other(RecordFields, RecordFields),
@@ -2164,7 +2234,7 @@ fn unify_shared_fields<M: MetaCollector>(
     ext: Variable,
 ) -> Outcome<M> {
     let mut matching_fields = Vec::with_capacity(shared_fields.len());
-    let num_shared_fields = shared_fields.len();
+    let mut type_mismatches = Vec::new();
 
     let mut whole_outcome = Outcome::default();
 
@@ -2177,7 +2247,9 @@ fn unify_shared_fields<M: MetaCollector>(
             ctx.mode,
         );
 
-        if local_outcome.mismatches.is_empty() {
+        if !local_outcome.mismatches.is_empty() {
+            type_mismatches.push((name, local_outcome.mismatches));
+        } else {
             use RecordField::*;
 
             // Unification of optional fields
@@ -2192,6 +2264,7 @@ fn unify_shared_fields<M: MetaCollector>(
             let actual = match (actual, expected) {
                 (Demanded(_), Optional(_)) | (Optional(_), Demanded(_)) => {
                     // this is an error, but we continue to give better error messages
+                    type_mismatches.push((name, vec![Mismatch::TypeMismatch]));
                     continue;
                 }
 
@@ -2212,12 +2285,14 @@ fn unify_shared_fields<M: MetaCollector>(
                 (RigidOptional(_), Demanded(_) | Required(_) | RigidRequired(_))
                 | (Demanded(_) | Required(_) | RigidRequired(_), RigidOptional(_)) => {
                     // this is an error, but we continue to give better error messages
+                    type_mismatches.push((name, vec![Mismatch::TypeMismatch]));
                     continue;
                 }
 
                 // rigid required
                 (RigidRequired(_), Optional(_)) | (Optional(_), RigidRequired(_)) => {
                     // this is an error, but we continue to give better error messages
+                    type_mismatches.push((name, vec![Mismatch::TypeMismatch]));
                     continue;
                 }
                 (RigidRequired(a), Demanded(b) | Required(b))
@@ -2232,7 +2307,7 @@ fn unify_shared_fields<M: MetaCollector>(
         }
     }
 
-    if num_shared_fields == matching_fields.len() {
+    if type_mismatches.is_empty() {
         // pull fields in from the ext_var
 
         let (ext_fields, new_ext_var) = RecordFields::empty().sorted_iterator_and_ext(env, ext);
@@ -2281,7 +2356,14 @@ fn unify_shared_fields<M: MetaCollector>(
         whole_outcome.union(merge_outcome);
         whole_outcome
     } else {
-        mismatch!("in unify_shared_fields")
+        let mut outcome: Outcome<M> = mismatch!("in unify_shared_fields");
+        outcome.mismatches.push(Mismatch::RecordFieldMismatch {
+            missing_in_left: Vec::new(),
+            missing_in_right: Vec::new(),
+            type_mismatches,
+        });
+
+        outcome
     }
 }
 
@@ -3068,17 +3150,22 @@ fn unify_shared_tags<M: MetaCollector>(
     ext: TagExt,
 ) -> Outcome<M> {
     let mut matching_tags = Vec::default();
+    let mut tag_mismatches = Vec::new();
     let num_shared_tags = shared_tags.len();
 
     let mut total_outcome = Outcome::default();
 
     for (name, (actual_vars, expected_vars)) in shared_tags {
         let mut matching_vars = Vec::with_capacity(actual_vars.len());
+        let mut mismatched_payloads = Vec::new();
 
         let actual_len = actual_vars.len();
         let expected_len = expected_vars.len();
 
-        for (actual_index, expected_index) in actual_vars.into_iter().zip(expected_vars.into_iter())
+        for (payload_index, (actual_index, expected_index)) in actual_vars
+            .into_iter()
+            .zip(expected_vars.into_iter())
+            .enumerate()
         {
             let actual = env[actual_index];
             let expected = env[expected_index];
@@ -3121,13 +3208,26 @@ fn unify_shared_tags<M: MetaCollector>(
                 let merged_var = choose_merged_var(env, actual, expected);
 
                 matching_vars.push(merged_var);
+            } else {
+                mismatched_payloads.push(payload_index);
             }
 
             total_outcome.union(outcome);
         }
 
         // only do this check after unification so the error message has more info
-        if actual_len == expected_len && actual_len == matching_vars.len() {
+        if actual_len != expected_len {
+            tag_mismatches.push(Mismatch::TagMismatch {
+                tag: name,
+                left_arity: actual_len,
+                right_arity: expected_len,
+            });
+        } else if !mismatched_payloads.is_empty() {
+            tag_mismatches.push(Mismatch::TagPayloadMismatch {
+                tag: name,
+                payloads: mismatched_payloads,
+            });
+        } else {
             matching_tags.push((name, matching_vars));
         }
     }
@@ -3186,11 +3286,14 @@ fn unify_shared_tags<M: MetaCollector>(
         total_outcome.union(merge_outcome);
         total_outcome
     } else {
-        mismatch!(
+        let mut outcome: Outcome<M> = mismatch!(
             "Problem with Tag Union\nThere should be {:?} matching tags, but I only got \n{:?}",
             num_shared_tags,
             &matching_tags
-        )
+        );
+        outcome.mismatches.extend(tag_mismatches);
+
+        outcome
     }
 }
 
@@ -3247,6 +3350,16 @@ fn unify_flat_type<M: MetaCollector>(
             unify_record(env, pool, ctx, *fields1, *ext1, *fields2, *ext2)
         }
 
+        (EmptyTuple, EmptyTuple) => merge(env, ctx, Structure(*left)),
+
+        (Tuple(elems, ext), EmptyTuple) if elems.is_empty() => {
+            unify_pool(env, pool, *ext, ctx.second, ctx.mode)
+        }
+
+        (EmptyTuple, Tuple(elems, ext)) if elems.is_empty() => {
+            unify_pool(env, pool, ctx.first, *ext, ctx.mode)
+        }
+
         (Tuple(elems1, ext1), Tuple(elems2, ext2)) => {
             unify_tuple(env, pool, ctx, *elems1, *ext1, *elems2, *ext2)
         }
@@ -4077,3 +4190,595 @@ fn unify_two_function_or_tag_unions<M: MetaCollector>(
     outcome.union(merge_outcome);
     outcome
 }
+
+fn all_same(diffs: &[TypeDiff]) -> bool {
+    diffs.iter().all(TypeDiff::is_same)
+}
+
+/// Walk two types in parallel, and find the subterms where they differ, so that error messages
+/// can point out just those.
+///
+/// Failed unifications turn both types into errors, so this must be called on the types as they
+/// were before unifying them. Flex variables match anything, since unification could make them
+/// match; rigid variables only match themselves.
+pub fn type_diff(subs: &Subs, left: Variable, right: Variable) -> TypeDiff {
+    type_diff_help(subs, &mut Vec::new(), left, right)
+}
+
+fn type_diff_help(
+    subs: &Subs,
+    visiting: &mut Vec<(Variable, Variable)>,
+    left: Variable,
+    right: Variable,
+) -> TypeDiff {
+    if subs.equivalent_without_compacting(left, right) {
+        return TypeDiff::Same;
+    }
+
+    let roots = (
+        subs.get_root_key_without_compacting(left),
+        subs.get_root_key_without_compacting(right),
+    );
+
+    if visiting.contains(&roots) {
+        // We are already comparing these two types further up, in a recursive type
+        return TypeDiff::Same;
+    }
+
+    visiting.push(roots);
+
+    let diff = match (
+        subs.get_content_without_compacting(left),
+        subs.get_content_without_compacting(right),
+    ) {
+        (FlexVar(_) | FlexAbleVar(..) | RangedNumber(_), _)
+        | (_, FlexVar(_) | FlexAbleVar(..) | RangedNumber(_)) => TypeDiff::Same,
+        (RecursionVar { structure, .. }, _) => type_diff_help(subs, visiting, *structure, right),
+        (_, RecursionVar { structure, .. }) => type_diff_help(subs, visiting, left, *structure),
+        (
+            Alias(left_symbol, left_args, left_real, _),
+            Alias(right_symbol, right_args, right_real, _),
+        ) => {
+            if left_symbol == right_symbol && left_args.len() == right_args.len() {
+                let args = diff_slices(
+                    subs,
+                    visiting,
+                    left_args.type_variables(),
+                    right_args.type_variables(),
+                );
+
+                if all_same(&args) {
+                    TypeDiff::Same
+                } else {
+                    TypeDiff::Apply(*left_symbol, args)
+                }
+            } else if type_diff_help(subs, visiting, *left_real, *right_real).is_same() {
+                TypeDiff::Same
+            } else {
+                // Different aliases are as far as we can narrow it down, e.g. `I64` vs `U8`
+                TypeDiff::Different
+            }
+        }
+        (Alias(_, _, real, _), _) => type_diff_help(subs, visiting, *real, right),
+        (_, Alias(_, _, real, _)) => type_diff_help(subs, visiting, left, *real),
+        (Structure(left_flat), Structure(right_flat)) => {
+            flat_type_diff(subs, visiting, left_flat, right_flat)
+        }
+        _ => TypeDiff::Different,
+    };
+
+    visiting.pop();
+
+    diff
+}
+
+fn flat_type_diff(
+    subs: &Subs,
+    visiting: &mut Vec<(Variable, Variable)>,
+    left: &FlatType,
+    right: &FlatType,
+) -> TypeDiff {
+    use FlatType::*;
+
+    match (left, right) {
+        (Apply(left_symbol, left_args), Apply(right_symbol, right_args))
+            if left_symbol == right_symbol && left_args.len() == right_args.len() =>
+        {
+            let args = diff_slices(subs, visiting, *left_args, *right_args);
+
+            if all_same(&args) {
+                TypeDiff::Same
+            } else {
+                TypeDiff::Apply(*left_symbol, args)
+            }
+        }
+        (Func(left_args, _, left_ret, _), Func(right_args, _, right_ret, _))
+            if left_args.len() == right_args.len() =>
+        {
+            let args = diff_slices(subs, visiting, *left_args, *right_args);
+            let ret = type_diff_help(subs, visiting, *left_ret, *right_ret);
+
+            if all_same(&args) && ret.is_same() {
+                TypeDiff::Same
+            } else {
+                TypeDiff::Function(args, Box::new(ret))
+            }
+        }
+        (EffectfulFunc, EffectfulFunc) => TypeDiff::Same,
+        (Tuple(left_elems, left_ext), Tuple(right_elems, right_ext)) => {
+            let left_elems: Vec<_> = left_elems.sorted_iterator(subs, *left_ext).collect();
+            let right_elems: Vec<_> = right_elems.sorted_iterator(subs, *right_ext).collect();
+
+            let same_indices = left_elems.len() == right_elems.len()
+                && left_elems
+                    .iter()
+                    .zip(right_elems.iter())
+                    .all(|((left_index, _), (right_index, _))| left_index == right_index);
+
+            if !same_indices {
+                return TypeDiff::Different;
+            }
+
+            let elems: Vec<_> = left_elems
+                .into_iter()
+                .zip(right_elems)
+                .map(|((_, left_var), (_, right_var))| {
+                    type_diff_help(subs, visiting, left_var, right_var)
+                })
+                .collect();
+
+            if all_same(&elems) {
+                TypeDiff::Same
+            } else {
+                TypeDiff::Tuple(elems)
+            }
+        }
+        (Record(..) | EmptyRecord, Record(..) | EmptyRecord) => {
+            let (left_fields, left_closed) = record_fields(subs, left);
+            let (right_fields, right_closed) = record_fields(subs, right);
+
+            let (both, missing_in_left, missing_in_right) =
+                pair_labels(left_fields, left_closed, right_fields, right_closed);
+
+            let fields: Vec<_> = both
+                .into_iter()
+                .map(|(name, left_var, right_var)| {
+                    (name, type_diff_help(subs, visiting, left_var, right_var))
+                })
+                .collect();
+
+            if fields.iter().all(|(_, diff)| diff.is_same())
+                && missing_in_left.is_empty()
+                && missing_in_right.is_empty()
+            {
+                TypeDiff::Same
+            } else {
+                TypeDiff::Record {
+                    fields,
+                    missing_in_left,
+                    missing_in_right,
+                }
+            }
+        }
+        (
+            TagUnion(..) | RecursiveTagUnion(..) | EmptyTagUnion,
+            TagUnion(..) | RecursiveTagUnion(..) | EmptyTagUnion,
+        ) => {
+            let (left_tags, left_closed) = union_tags(subs, left);
+            let (right_tags, right_closed) = union_tags(subs, right);
+
+            let (both, missing_in_left, missing_in_right) =
+                pair_labels(left_tags, left_closed, right_tags, right_closed);
+
+            let mut tags = Vec::with_capacity(both.len());
+
+            for (tag_name, left_payloads, right_payloads) in both {
+                if left_payloads.len() != right_payloads.len() {
+                    // A tag can't be made to match a tag of another arity
+                    return TypeDiff::Different;
+                }
+
+                let payloads = left_payloads
+                    .iter()
+                    .zip(right_payloads.iter())
+                    .map(|(left_var, right_var)| {
+                        type_diff_help(subs, visiting, *left_var, *right_var)
+                    })
+                    .collect::<Vec<_>>();
+
+                tags.push((tag_name, payloads));
+            }
+
+            if tags.iter().all(|(_, payloads)| all_same(payloads))
+                && missing_in_left.is_empty()
+                && missing_in_right.is_empty()
+            {
+                TypeDiff::Same
+            } else {
+                TypeDiff::TagUnion {
+                    tags,
+                    missing_in_left,
+                    missing_in_right,
+                }
+            }
+        }
+        _ => TypeDiff::Different,
+    }
+}
+
+fn diff_slices(
+    subs: &Subs,
+    visiting: &mut Vec<(Variable, Variable)>,
+    left: VariableSubsSlice,
+    right: VariableSubsSlice,
+) -> Vec<TypeDiff> {
+    let left = subs.get_subs_slice(left).to_vec();
+    let right = subs.get_subs_slice(right).to_vec();
+
+    left.into_iter()
+        .zip(right)
+        .map(|(left_var, right_var)| type_diff_help(subs, visiting, left_var, right_var))
+        .collect()
+}
+
+/// The sorted fields of a record type, and whether the record is closed
+fn record_fields(subs: &Subs, flat: &FlatType) -> (Vec<(Lowercase, Variable)>, bool) {
+    match flat {
+        FlatType::Record(fields, ext) => {
+            let (fields, ext) = fields.sorted_iterator_and_ext(subs, *ext);
+            let fields = fields
+                .map(|(name, field)| (name, field.into_inner()))
+                .collect();
+            let closed = matches!(
+                subs.get_content_without_compacting(ext),
+                Structure(FlatType::EmptyRecord)
+            );
+
+            (fields, closed)
+        }
+        _ => (Vec::new(), true),
+    }
+}
+
+/// The sorted tags of a tag union type, and whether the union is closed
+fn union_tags(subs: &Subs, flat: &FlatType) -> (Vec<(TagName, Vec<Variable>)>, bool) {
+    match flat {
+        FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
+            let (tags, ext) = tags.sorted_iterator_and_ext(subs, *ext);
+            let tags = tags
+                .map(|(name, payloads)| (name, payloads.to_vec()))
+                .collect();
+            let closed = matches!(
+                subs.get_content_without_compacting(ext.var()),
+                Structure(FlatType::EmptyTagUnion)
+            );
+
+            (tags, closed)
+        }
+        _ => (Vec::new(), true),
+    }
+}
+
+/// Pair up the labels two records or tag unions have in common. A label that only one side has
+/// is only missing from the other side if that side is closed, since otherwise unification
+/// could add it.
+#[allow(clippy::type_complexity)]
+fn pair_labels<L: PartialEq, V>(
+    left: Vec<(L, V)>,
+    left_closed: bool,
+    mut right: Vec<(L, V)>,
+    right_closed: bool,
+) -> (Vec<(L, V, V)>, Vec<L>, Vec<L>) {
+    let mut both = Vec::new();
+    let mut missing_in_right = Vec::new();
+
+    for (label, left_value) in left {
+        match right
+            .iter()
+            .position(|(right_label, _)| *right_label == label)
+        {
+            Some(index) => {
+                let (_, right_value) = right.remove(index);
+                both.push((label, left_value, right_value));
+            }
+            None if right_closed => missing_in_right.push(label),
+            None => {}
+        }
+    }
+
+    let missing_in_left = if left_closed {
+        right.into_iter().map(|(label, _)| label).collect()
+    } else {
+        Vec::new()
+    };
+
+    (both, missing_in_left, missing_in_right)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use roc_checkmate::with_checkmate;
+
+    fn record(subs: &mut Subs, fields: &[(&str, Variable)], ext: Variable) -> Variable {
+        let fields = RecordFields::insert_into_subs(
+            subs,
+            fields
+                .iter()
+                .map(|(name, var)| (Lowercase::from(*name), RecordField::Required(*var))),
+        );
+
+        subs.fresh(Descriptor::from(Structure(FlatType::Record(fields, ext))))
+    }
+
+    fn closed_record(subs: &mut Subs, fields: &[(&str, Variable)]) -> Variable {
+        record(subs, fields, Variable::EMPTY_RECORD)
+    }
+
+    fn closed_tag_union(subs: &mut Subs, tags: &[(&str, &[Variable])]) -> Variable {
+        let tags = UnionTags::insert_into_subs(
+            subs,
+            tags.iter()
+                .map(|(name, payloads)| (TagName((*name).into()), payloads.to_vec())),
+        );
+        let ext = TagExt::Any(Variable::EMPTY_TAG_UNION);
+
+        subs.fresh(Descriptor::from(Structure(FlatType::TagUnion(tags, ext))))
+    }
+
+    fn closed_tuple(subs: &mut Subs, elems: &[Variable]) -> Variable {
+        let elems = TupleElems::insert_into_subs(subs, elems.iter().copied().enumerate());
+
+        subs.fresh(Descriptor::from(Structure(FlatType::Tuple(
+            elems,
+            Variable::EMPTY_TUPLE,
+        ))))
+    }
+
+    /// `[Cons Str rec, Nil] as rec`
+    fn cons_list(subs: &mut Subs) -> Variable {
+        let union_var = subs.fresh_unnamed_flex_var();
+        let rec = subs.fresh(Descriptor::from(RecursionVar {
+            structure: union_var,
+            opt_name: None,
+        }));
+        let tags = UnionTags::insert_into_subs(
+            subs,
+            [
+                (TagName("Cons".into()), vec![Variable::STR, rec]),
+                (TagName("Nil".into()), vec![]),
+            ],
+        );
+        let ext = TagExt::Any(Variable::EMPTY_TAG_UNION);
+
+        subs.set_content(
+            union_var,
+            Structure(FlatType::RecursiveTagUnion(rec, tags, ext)),
+        );
+
+        union_var
+    }
+
+    fn unify_vars(subs: &mut Subs, left: Variable, right: Variable) -> Vec<Mismatch> {
+        let mut env = with_checkmate!({
+            on => Env::new(subs, None),
+            off => Env::new(subs),
+        });
+        let outcome: Outcome<NoCollector> =
+            unify_pool(&mut env, &mut Vec::new(), left, right, UnificationMode::EQ);
+
+        outcome.mismatches().to_vec()
+    }
+
+    fn missing_fields(missing_in_left: &[&str], missing_in_right: &[&str]) -> Mismatch {
+        let names = |names: &[&str]| names.iter().map(|name| Lowercase::from(*name)).collect();
+
+        Mismatch::RecordFieldMismatch {
+            missing_in_left: names(missing_in_left),
+            missing_in_right: names(missing_in_right),
+            type_mismatches: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn closed_records_with_the_same_fields() {
+        let mut subs = Subs::new();
+        let left = closed_record(&mut subs, &[("name", Variable::STR)]);
+        let right = closed_record(&mut subs, &[("name", Variable::STR)]);
+
+        assert_eq!(unify_vars(&mut subs, left, right), vec![]);
+    }
+
+    #[test]
+    fn closed_record_missing_a_field() {
+        let mut subs = Subs::new();
+        let left = closed_record(&mut subs, &[("age", Variable::U8), ("name", Variable::STR)]);
+        let right = closed_record(&mut subs, &[("name", Variable::STR)]);
+
+        let mismatches = unify_vars(&mut subs, left, right);
+
+        assert!(mismatches.contains(&missing_fields(&[], &["age"])));
+    }
+
+    #[test]
+    fn closed_records_missing_fields_on_both_sides() {
+        let mut subs = Subs::new();
+        let left = closed_record(&mut subs, &[("age", Variable::U8)]);
+        let right = closed_record(&mut subs, &[("name", Variable::STR)]);
+
+        let mismatches = unify_vars(&mut subs, left, right);
+
+        // The left side is checked first.
+        assert!(mismatches.contains(&missing_fields(&["name"], &[])));
+    }
+
+    #[test]
+    fn closed_records_with_a_field_of_different_type() {
+        let mut subs = Subs::new();
+        let left = closed_record(&mut subs, &[("name", Variable::STR)]);
+        let right = closed_record(&mut subs, &[("name", Variable::U8)]);
+
+        let mismatches = unify_vars(&mut subs, left, right);
+
+        assert!(mismatches.iter().any(|mismatch| matches!(
+            mismatch,
+            Mismatch::RecordFieldMismatch { missing_in_left, missing_in_right, type_mismatches }
+                if missing_in_left.is_empty()
+                    && missing_in_right.is_empty()
+                    && type_mismatches.len() == 1
+                    && type_mismatches[0].0.as_str() == "name"
+        )));
+    }
+
+    #[test]
+    fn open_record_gains_fields_of_closed_record() {
+        let mut subs = Subs::new();
+        let ext = subs.fresh_unnamed_flex_var();
+        let left = record(&mut subs, &[("name", Variable::STR)], ext);
+        let right = closed_record(&mut subs, &[("age", Variable::U8), ("name", Variable::STR)]);
+
+        assert_eq!(unify_vars(&mut subs, left, right), vec![]);
+    }
+
+    #[test]
+    fn closed_record_lacks_field_of_open_record() {
+        let mut subs = Subs::new();
+        let ext = subs.fresh_unnamed_flex_var();
+        let left = record(&mut subs, &[("age", Variable::U8)], ext);
+        let right = closed_record(&mut subs, &[("name", Variable::STR)]);
+
+        let mismatches = unify_vars(&mut subs, left, right);
+
+        assert!(mismatches.contains(&missing_fields(&[], &["age"])));
+    }
+
+    #[test]
+    fn tag_unions_with_differing_payload_arity() {
+        let mut subs = Subs::new();
+        let left = closed_tag_union(
+            &mut subs,
+            &[("Err", &[Variable::U8]), ("Ok", &[Variable::STR])],
+        );
+        let right = closed_tag_union(
+            &mut subs,
+            &[
+                ("Err", &[Variable::U8]),
+                ("Ok", &[Variable::STR, Variable::STR]),
+            ],
+        );
+
+        let mismatches = unify_vars(&mut subs, left, right);
+
+        assert!(mismatches.contains(&Mismatch::TagMismatch {
+            tag: TagName("Ok".into()),
+            left_arity: 1,
+            right_arity: 2,
+        }));
+    }
+
+    #[test]
+    fn tag_unions_with_mismatched_payload() {
+        let mut subs = Subs::new();
+        let left = closed_tag_union(
+            &mut subs,
+            &[("Err", &[Variable::U8]), ("Ok", &[Variable::STR])],
+        );
+        let right = closed_tag_union(
+            &mut subs,
+            &[("Err", &[Variable::STR]), ("Ok", &[Variable::STR])],
+        );
+
+        let mismatches = unify_vars(&mut subs, left, right);
+
+        assert!(mismatches.contains(&Mismatch::TagPayloadMismatch {
+            tag: TagName("Err".into()),
+            payloads: vec![0],
+        }));
+    }
+
+    #[test]
+    fn closed_tuples_with_differing_arity() {
+        let mut subs = Subs::new();
+        let left = closed_tuple(&mut subs, &[Variable::U8, Variable::U8]);
+        let right = closed_tuple(&mut subs, &[Variable::U8, Variable::U8, Variable::U8]);
+
+        let mismatches = unify_vars(&mut subs, left, right);
+
+        assert!(mismatches.contains(&Mismatch::TupleArityMismatch {
+            left_arity: 2,
+            right_arity: 3,
+        }));
+    }
+
+    #[test]
+    fn closed_tuples_with_the_same_arity() {
+        let mut subs = Subs::new();
+        let left = closed_tuple(&mut subs, &[Variable::U8, Variable::STR]);
+        let right = closed_tuple(&mut subs, &[Variable::U8, Variable::STR]);
+
+        assert_eq!(unify_vars(&mut subs, left, right), vec![]);
+    }
+
+    #[test]
+    fn type_diff_flags_only_the_differing_field() {
+        let mut subs = Subs::new();
+        let left = closed_record(&mut subs, &[("x", Variable::I64), ("y", Variable::STR)]);
+        let right = closed_record(&mut subs, &[("x", Variable::I64), ("y", Variable::I64)]);
+
+        assert_eq!(
+            type_diff(&subs, left, right),
+            TypeDiff::Record {
+                fields: vec![
+                    ("x".into(), TypeDiff::Same),
+                    ("y".into(), TypeDiff::Different)
+                ],
+                missing_in_left: vec![],
+                missing_in_right: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn type_diff_of_matching_records() {
+        let mut subs = Subs::new();
+        let left = closed_record(&mut subs, &[("x", Variable::I64), ("y", Variable::STR)]);
+        let right = closed_record(&mut subs, &[("x", Variable::I64), ("y", Variable::STR)]);
+
+        assert_eq!(type_diff(&subs, left, right), TypeDiff::Same);
+    }
+
+    #[test]
+    fn type_diff_only_flags_fields_a_closed_record_lacks() {
+        let mut subs = Subs::new();
+        let ext = subs.fresh_unnamed_flex_var();
+        let open = record(&mut subs, &[("x", Variable::I64)], ext);
+        let closed = closed_record(&mut subs, &[("y", Variable::STR)]);
+
+        assert_eq!(
+            type_diff(&subs, open, closed),
+            TypeDiff::Record {
+                fields: vec![],
+                missing_in_left: vec![],
+                missing_in_right: vec!["x".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn type_diff_terminates_on_recursive_types() {
+        let mut subs = Subs::new();
+        let left = cons_list(&mut subs);
+        let right = cons_list(&mut subs);
+
+        assert_eq!(type_diff(&subs, left, right), TypeDiff::Same);
+    }
+
+    #[test]
+    fn recursive_tag_union_unifies_with_itself() {
+        let mut subs = Subs::new();
+        let left = cons_list(&mut subs);
+        let right = cons_list(&mut subs);
+
+        assert_eq!(unify_vars(&mut subs, left, right), vec![]);
+    }
+}
@@ -8,7 +8,7 @@ use roc_collections::VecSet;
 use roc_highlight::highlight_roc_code_inline;
 use roc_load::docs::{DocEntry, TypeAnnotation};
 use roc_load::docs::{ModuleDocumentation, RecordField};
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, ShadowPolicy, Threading};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_parse::ast::FunctionArrow;
@@ -681,6 +681,8 @@ pub fn load_module_for_docs(filename: PathBuf) -> LoadedModule {
     let load_config = LoadConfig {
         target: roc_target::Target::LinuxX64, // This is just type-checking for docs, so "target" doesn't matter
         function_kind: roc_solve::FunctionKind::LambdaSet,
+        step_budget: None,
+        shadow_policy: ShadowPolicy::Disallow,
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading: Threading::AllAvailable,
@@ -1143,7 +1145,7 @@ fn doc_url<'a>(
     if module_name.is_empty() {
         // This is an unqualified lookup, so look for the ident
         // in scope!
-        match scope.lookup_str(ident, Region::zero()) {
+        match scope.resolve_str(ident, Region::zero()) {
             Ok(symbol) => {
                 // Get the exact module_name from scope. It could be the
                 // current module's name, but it also could be a different
@@ -12,7 +12,9 @@ use roc_build::{
 use roc_collections::MutMap;
 use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_gen_llvm::run_roc::RocCallResult;
-use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_load::{
+    ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingProblem, ShadowPolicy, Threading,
+};
 use roc_mono::ir::{generate_glue_procs, CrashTag, GlueProc, OptLevel};
 use roc_mono::layout::{GlobalLayoutInterner, LayoutCache, LayoutInterner};
 use roc_packaging::cache::{self, RocCacheDir};
@@ -59,6 +61,7 @@ pub fn generate(
                 emit_debug_info: false,
                 emit_llvm_ir: false,
                 fuzz: false,
+                overflow_checks: true,
             };
 
             let load_config = standard_load_config(
@@ -329,6 +332,7 @@ fn number_lambda_sets(subs: &Subs, initial: Variable) -> Vec<Variable> {
                 }
 
                 EmptyRecord => (),
+                EmptyTuple => (),
                 EmptyTagUnion => (),
                 EffectfulFunc => internal_error!(),
 
@@ -430,6 +434,8 @@ pub fn load_types(
         LoadConfig {
             target,
             function_kind,
+            step_budget: None,
+            shadow_policy: ShadowPolicy::Disallow,
             render: RenderTarget::Generic,
             palette: DEFAULT_PALETTE,
             threading,
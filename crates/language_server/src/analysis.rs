@@ -113,6 +113,7 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
         None,
         roc_target::Target::LinuxX64,
         roc_load::FunctionKind::LambdaSet,
+        None,
         roc_reporting::report::RenderTarget::LanguageServer,
         RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
         roc_reporting::report::DEFAULT_PALETTE,
@@ -143,6 +144,7 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
     let LoadedModule {
         interns,
         mut can_problems,
+        mut can_warnings,
         mut type_problems,
         mut declarations_by_id,
         sources,
@@ -173,6 +175,7 @@ pub(crate) fn global_analysis(doc_info: DocInfo) -> Vec<AnalyzedDocument> {
         interns: &interns,
         module_id_to_url: module_id_to_url_from_sources(&sources),
         can_problems: &mut can_problems,
+        can_warnings: &mut can_warnings,
         type_problems: &mut type_problems,
         declarations_by_id: &mut declarations_by_id,
         typechecked: &mut typechecked,
@@ -263,6 +266,7 @@ struct AnalyzedDocumentBuilder<'a> {
     interns: &'a Interns,
     module_id_to_url: ModuleIdToUrl,
     can_problems: &'a mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    can_warnings: &'a mut MutMap<ModuleId, Vec<roc_problem::can::Warning>>,
     type_problems: &'a mut MutMap<ModuleId, Vec<TypeError>>,
     declarations_by_id: &'a mut MutMap<ModuleId, Declarations>,
     typechecked: &'a mut MutMap<ModuleId, CheckedModule>,
@@ -370,6 +374,8 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
 
         let can_problems = self.can_problems.remove(&module_id).unwrap_or_default();
 
+        let can_warnings = self.can_warnings.remove(&module_id).unwrap_or_default();
+
         let type_problems = self.type_problems.remove(&module_id).unwrap_or_default();
 
         for can_problem in can_problems {
@@ -378,6 +384,12 @@ impl<'a> AnalyzedDocumentBuilder<'a> {
             }
         }
 
+        for can_warning in can_warnings {
+            if let Some(diag) = can_warning.into_lsp_diagnostic(&fmt) {
+                all_problems.push(diag);
+            }
+        }
+
         for type_problem in type_problems {
             if let Some(diag) = type_problem.into_lsp_diagnostic(&fmt) {
                 all_problems.push(diag);
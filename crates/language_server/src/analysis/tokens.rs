@@ -777,6 +777,8 @@ impl IterTokens for Loc<Pattern<'_>> {
             Pattern::RecordDestructure(ps) => ps.iter_tokens(arena),
             Pattern::RequiredField(_field, p) => p.iter_tokens(arena),
             Pattern::OptionalField(_field, p) => p.iter_tokens(arena),
+            Pattern::RecordRest(None) => bumpvec![in arena;],
+            Pattern::RecordRest(Some(_)) => onetoken(Token::Variable, region, arena),
             Pattern::NumLiteral(_) => onetoken(Token::Number, region, arena),
             Pattern::NonBase10Literal { .. } => onetoken(Token::Number, region, arena),
             Pattern::FloatLiteral(_) => onetoken(Token::Number, region, arena),
@@ -134,8 +134,8 @@ pub(crate) mod diag {
                     )
                 }
                 LoadingProblem::ParsingFailed(fe) => {
-                    let problem = &fe.problem.problem;
-                    format!("Failed to parse Roc source file: {problem:?}")
+                    let problems = &fe.problem.problem;
+                    format!("Failed to parse Roc source file: {problems:?}")
                 }
                 LoadingProblem::UnexpectedHeader(header) => {
                     format!("Unexpected header: {}", header)
@@ -222,6 +222,37 @@ pub(crate) mod diag {
         }
     }
 
+    impl<'a> IntoLspDiagnostic<'a> for roc_problem::can::Warning {
+        type Feed = ProblemFmt<'a>;
+
+        fn into_lsp_diagnostic(self, fmt: &'a ProblemFmt<'a>) -> Option<Diagnostic> {
+            let range = self.region().to_range(fmt.line_info);
+
+            let report = roc_reporting::report::can_warning(
+                fmt.alloc,
+                fmt.line_info,
+                fmt.path.to_path_buf(),
+                self,
+            );
+
+            let severity = report.severity.into_lsp_severity();
+            let mut msg = String::new();
+            report.render_language_server(&mut msg, fmt.alloc);
+
+            Some(Diagnostic {
+                range,
+                severity: Some(severity),
+                code: None,
+                code_description: None,
+                source: None,
+                message: msg,
+                related_information: None,
+                tags: None,
+                data: None,
+            })
+        }
+    }
+
     impl<'a> IntoLspDiagnostic<'a> for TypeError {
         type Feed = ProblemFmt<'a>;
 
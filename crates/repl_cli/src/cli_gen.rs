@@ -4,7 +4,7 @@ use libloading::Library;
 use roc_build::link::llvm_module_to_dylib;
 use roc_collections::all::MutSet;
 
-use roc_gen_llvm::llvm::build::LlvmBackendMode;
+use roc_gen_llvm::llvm::build::{LlvmBackendMode, SourceFiles};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
 use roc_gen_llvm::{run_jit_function, run_jit_function_dynamic_type};
 use roc_load::{EntryPoint, MonomorphizedModule};
@@ -215,6 +215,8 @@ fn mono_module_to_dylib_llvm<'a>(
         mode: LlvmBackendMode::GenTest, // so roc_panic is generated
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        overflow_checks: true,
+        source_files: SourceFiles::default(),
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
@@ -1,5 +1,5 @@
 use bumpalo::Bump;
-use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError, Threading};
+use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError, ShadowPolicy, Threading};
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_problem::Severity;
 use roc_reporting::report::Palette;
@@ -10,7 +10,7 @@ use roc_fmt::annotation::{Newlines, Parens};
 use roc_load::{LoadingProblem, MonomorphizedModule};
 use roc_parse::ast::Expr;
 use roc_region::all::LineInfo;
-use roc_reporting::report::{can_problem, type_problem, RocDocAllocator};
+use roc_reporting::report::{can_problem, can_warning, type_problem, RocDocAllocator};
 use roc_solve::FunctionKind;
 use roc_target::Target;
 
@@ -65,6 +65,8 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         LoadConfig {
             target,
             function_kind: FunctionKind::LambdaSet,
+            step_budget: None,
+            shadow_policy: ShadowPolicy::Disallow,
             render: roc_reporting::report::RenderTarget::ColorTerminal,
             palette,
             threading: Threading::Single,
@@ -98,6 +100,7 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         interns,
         sources,
         can_problems,
+        can_warnings,
         type_problems,
         ..
     } = &mut loaded;
@@ -109,9 +112,10 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
 
     for (home, (module_path, src)) in sources.iter() {
         let can_probs = can_problems.remove(home).unwrap_or_default();
+        let can_warns = can_warnings.remove(home).unwrap_or_default();
         let type_probs = type_problems.remove(home).unwrap_or_default();
 
-        let error_count = can_probs.len() + type_probs.len();
+        let error_count = can_probs.len() + can_warns.len() + type_probs.len();
 
         if error_count == 0 {
             continue;
@@ -146,6 +150,18 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
             }
         }
 
+        for warning in can_warns {
+            // Same filtering as for problems above, e.g. for unused def warnings.
+            if warning.region().end().offset as usize >= bytes_before_expr {
+                let report = can_warning(&alloc, &line_info, module_path.clone(), warning);
+                let mut buf = String::new();
+
+                report.render_color_terminal(&mut buf, &alloc, &palette);
+
+                warnings.push(buf);
+            }
+        }
+
         for problem in type_probs {
             if let Some(report) = type_problem(&alloc, &line_info, module_path.clone(), problem) {
                 let severity = report.severity;
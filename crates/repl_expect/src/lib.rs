@@ -95,7 +95,9 @@ mod test {
     use pretty_assertions::assert_eq;
     use roc_error_macros::internal_error;
     use roc_gen_llvm::{llvm::build::LlvmBackendMode, run_roc::RocCallResult, run_roc_dylib};
-    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, Threading};
+    use roc_load::{
+        ExecutionMode, FunctionKind, LoadConfig, LoadMonomorphizedError, ShadowPolicy, Threading,
+    };
     use roc_packaging::cache::RocCacheDir;
     use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
     use target_lexicon::Triple;
@@ -120,6 +122,8 @@ mod test {
         let load_config = LoadConfig {
             target,
             function_kind,
+            step_budget: None,
+            shadow_policy: ShadowPolicy::Disallow,
             render: RenderTarget::ColorTerminal,
             palette: DEFAULT_PALETTE,
             threading: Threading::Single,
@@ -1205,7 +1209,7 @@ mod test {
                 t : (
                     Str,
                     Str,
-                )a
+                )
                 t = ("One", "Two")
                 "#
             ),
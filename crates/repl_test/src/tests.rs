@@ -1221,7 +1221,7 @@ fn aliases_named_err_and_ok() {
             v = (Blah, Stuff)
             v"#
         ),
-        r#"(Blah, Stuff) : ( [Blah], [Stuff] )*"#,
+        r#"(Blah, Stuff) : ( [Blah], [Stuff] )"#,
     );
 }
 
@@ -1490,7 +1490,7 @@ fn tuple() {
             ("a", 2u32)
             "#
         ),
-        r#"("a", 2) : ( Str, U32 )*"#,
+        r#"("a", 2) : ( Str, U32 )"#,
     );
 }
 
@@ -1502,7 +1502,7 @@ fn nested_tuple() {
             ("a", (2u32, 3u32))
             "#
         ),
-        r#"("a", (2, 3)) : ( Str, ( U32, U32 )a )a"#,
+        r#"("a", (2, 3)) : ( Str, ( U32, U32 ) )"#,
     );
 }
 
@@ -64,9 +64,12 @@ pub fn report_problems(
     sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
     interns: &Interns,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    can_warnings: &mut MutMap<ModuleId, Vec<roc_problem::can::Warning>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
 ) -> Problems {
-    use crate::report::{can_problem, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE};
+    use crate::report::{
+        can_problem, can_reports, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE,
+    };
     use roc_problem::Severity::*;
 
     let palette = DEFAULT_PALETTE;
@@ -76,6 +79,10 @@ pub fn report_problems(
         total_problems += problems.len();
     }
 
+    for problems in can_warnings.values() {
+        total_problems += problems.len();
+    }
+
     for problems in type_problems.values() {
         total_problems += problems.len();
     }
@@ -123,7 +130,7 @@ pub fn report_problems(
         // Shadowing errors often cause cryptic type errors. To make it easy to spot the root cause,
         // we print the shadowing errors last.
         let problems = can_problems.remove(home).unwrap_or_default();
-        let (shadowing_errs, mut ordered): (Vec<Problem>, Vec<Problem>) =
+        let (shadowing_errs, ordered): (Vec<Problem>, Vec<Problem>) =
             problems.into_iter().partition(|p| {
                 matches!(
                     p,
@@ -134,10 +141,22 @@ pub fn report_problems(
                     }
                 )
             });
-        ordered.extend(shadowing_errs);
+        let module_warnings = can_warnings.remove(home).unwrap_or_default();
+
+        let mut reports = can_reports(
+            &alloc,
+            &lines,
+            module_path.clone(),
+            ordered,
+            module_warnings,
+        );
+        reports.extend(
+            shadowing_errs
+                .into_iter()
+                .map(|problem| can_problem(&alloc, &lines, module_path.clone(), problem)),
+        );
 
-        for problem in ordered.into_iter() {
-            let report = can_problem(&alloc, &lines, module_path.clone(), problem);
+        for report in reports {
             let severity = report.severity;
             let mut buf = String::new();
 
@@ -158,7 +177,7 @@ pub fn report_problems(
         }
     }
 
-    debug_assert!(can_problems.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len());
+    debug_assert!(can_problems.is_empty() && can_warnings.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems, {:?} can_warnings and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), can_warnings.len(), type_problems.len());
     debug_assert_eq!(errors.len() + warnings.len(), total_problems);
 
     let problems_reported;
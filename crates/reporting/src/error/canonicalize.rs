@@ -5,7 +5,7 @@ use roc_module::symbol::DERIVABLE_ABILITIES;
 use roc_problem::can::PrecedenceProblem::BothNonAssociative;
 use roc_problem::can::{
     BadPattern, CycleEntry, ExtensionTypeKind, FloatErrorKind, IntErrorKind, Problem, RuntimeError,
-    ScopeModuleSource, ShadowKind,
+    ScopeModuleSource, ShadowKind, Warning,
 };
 use roc_problem::Severity;
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Loc, Region};
@@ -23,6 +23,9 @@ const UNUSED_DEF: &str = "UNUSED DEFINITION";
 const UNUSED_IMPORT: &str = "UNUSED IMPORT";
 const IMPORT_NAME_CONFLICT: &str = "IMPORT NAME CONFLICT";
 const EXPLICIT_BUILTIN_IMPORT: &str = "EXPLICIT BUILTIN IMPORT";
+const IMPORT_SHADOWS_BUILTIN: &str = "IMPORT SHADOWS BUILTIN";
+const UNUSED_ALIAS: &str = "UNUSED TYPE ALIAS";
+const UNDERSCORE_BINDING_USED: &str = "UNDERSCORE BINDING USED";
 const UNUSED_ALIAS_PARAM: &str = "UNUSED TYPE ALIAS PARAMETER";
 const UNDECLARED_TYPE_VARIABLE: &str = "UNDECLARED TYPE VARIABLE";
 const WILDCARD_NOT_ALLOWED: &str = "WILDCARD NOT ALLOWED HERE";
@@ -34,6 +37,7 @@ const DUPLICATE_TAG_NAME: &str = "DUPLICATE TAG NAME";
 const INVALID_UNICODE: &str = "INVALID UNICODE";
 pub const CIRCULAR_DEF: &str = "CIRCULAR DEFINITION";
 const DUPLICATE_NAME: &str = "DUPLICATE NAME";
+const CYCLIC_ALIAS: &str = "CYCLIC ALIAS";
 const VALUE_NOT_EXPOSED: &str = "NOT EXPOSED";
 const MODULE_NOT_IMPORTED: &str = "MODULE NOT IMPORTED";
 const INGESTED_FILE_ERROR: &str = "INGESTED FILE ERROR";
@@ -80,39 +84,6 @@ pub fn can_problem<'b>(
     let severity = problem.severity();
 
     match problem {
-        Problem::UnusedDef(symbol, region) => {
-            let line =
-                r#" then remove it so future readers of your code don't wonder why it is there."#;
-
-            doc = alloc.stack([
-                alloc
-                    .symbol_unqualified(symbol)
-                    .append(alloc.reflow(" is not used anywhere in your code.")),
-                alloc.region(lines.convert_region(region), severity),
-                alloc
-                    .reflow("If you didn't intend on using ")
-                    .append(alloc.symbol_unqualified(symbol))
-                    .append(alloc.reflow(line)),
-            ]);
-
-            title = UNUSED_DEF.to_string();
-        }
-        Problem::UnusedImport(symbol, region) => {
-            doc = alloc.stack([
-                alloc.concat([
-                    alloc.symbol_qualified(symbol),
-                    alloc.reflow(" is not used in this module."),
-                ]),
-                alloc.region(lines.convert_region(region), severity),
-                alloc.concat([
-                    alloc.reflow("Since "),
-                    alloc.symbol_qualified(symbol),
-                    alloc.reflow(" isn't used, you don't need to import it."),
-                ]),
-            ]);
-
-            title = UNUSED_IMPORT.to_string();
-        }
         Problem::UnusedModuleImport(module_id, region) => {
             doc = alloc.stack([
                 alloc.concat([
@@ -683,6 +654,33 @@ pub fn can_problem<'b>(
 
             title = DUPLICATE_FIELD_NAME.to_string();
         }
+        Problem::DuplicateRecordFieldPattern {
+            field_name,
+            field_region,
+            record_region,
+            replaced_region,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This record pattern destructures the "),
+                    alloc.record_field(field_name.clone()),
+                    alloc.reflow(" field twice!"),
+                ]),
+                alloc.region_all_the_things(
+                    lines.convert_region(record_region),
+                    lines.convert_region(replaced_region),
+                    lines.convert_region(field_region),
+                    Annotation::Error,
+                ),
+                alloc.concat([
+                    alloc.reflow("A record pattern can only mention each field once. Remove all but one of the "),
+                    alloc.record_field(field_name),
+                    alloc.reflow(" destructures from this pattern."),
+                ]),
+            ]);
+
+            title = DUPLICATE_FIELD_NAME.to_string();
+        }
         Problem::DuplicateTag {
             tag_name,
             tag_union_region,
@@ -1250,6 +1248,30 @@ pub fn can_problem<'b>(
             ]);
             title = "MULTIPLE LIST REST PATTERNS".to_string();
         }
+        Problem::MultipleRecordRestPattern { region } => {
+            doc = alloc.stack([
+                alloc.reflow("This record pattern has multiple rest patterns:"),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("A record pattern can only have one "),
+                    alloc.parser_suggestion(".."),
+                    alloc.reflow(" pattern! Can you remove this additional one?"),
+                ]),
+            ]);
+            title = "MULTIPLE RECORD REST PATTERNS".to_string();
+        }
+        Problem::RecordRestPatternNotLast { region } => {
+            doc = alloc.stack([
+                alloc.reflow("This rest pattern is not the last field of its record pattern:"),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("The "),
+                    alloc.parser_suggestion(".."),
+                    alloc.reflow(" pattern takes the fields that are left over, so it must come after all the others."),
+                ]),
+            ]);
+            title = "MISPLACED RECORD REST PATTERN".to_string();
+        }
         Problem::BadTypeArguments {
             symbol,
             region,
@@ -1493,6 +1515,165 @@ pub fn can_problem<'b>(
     }
 }
 
+pub fn can_warning<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    warning: Warning,
+) -> Report<'b> {
+    let doc;
+    let title;
+    let severity = Severity::Warning;
+
+    match warning {
+        Warning::Shadowing {
+            original_region,
+            shadow,
+        } => {
+            let (res_title, res_doc) = report_shadowing(
+                alloc,
+                lines,
+                original_region,
+                shadow,
+                ShadowKind::Variable,
+                severity,
+            );
+
+            doc = res_doc;
+            title = res_title.to_string();
+        }
+        Warning::UnusedDef(symbol, region) => {
+            let line =
+                r#" then remove it so future readers of your code don't wonder why it is there."#;
+
+            doc = alloc.stack([
+                alloc
+                    .symbol_unqualified(symbol)
+                    .append(alloc.reflow(" is not used anywhere in your code.")),
+                alloc.region(lines.convert_region(region), severity),
+                alloc
+                    .reflow("If you didn't intend on using ")
+                    .append(alloc.symbol_unqualified(symbol))
+                    .append(alloc.reflow(line)),
+            ]);
+
+            title = UNUSED_DEF.to_string();
+        }
+        Warning::UnusedImport(symbol, region) => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.symbol_qualified(symbol),
+                    alloc.reflow(" is not used in this module."),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("Since "),
+                    alloc.symbol_qualified(symbol),
+                    alloc.reflow(" isn't used, you don't need to import it."),
+                ]),
+            ]);
+
+            title = UNUSED_IMPORT.to_string();
+        }
+        Warning::UnusedAlias(symbol, region) => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The type "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" is not used anywhere in your code."),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("If you didn't intend on using "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" then remove it so future readers of your code don't wonder why it is there."),
+                ]),
+            ]);
+
+            title = UNUSED_ALIAS.to_string();
+        }
+        Warning::ShadowsBuiltin {
+            region,
+            new_symbol,
+            builtin_symbol,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This import exposes "),
+                    alloc.symbol_qualified(new_symbol),
+                    alloc.reflow(":"),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("It replaces the builtin "),
+                    alloc.symbol_qualified(builtin_symbol),
+                    alloc.reflow(", which is otherwise always in scope. From here on, "),
+                    alloc.symbol_unqualified(new_symbol),
+                    alloc.reflow(" refers to the imported one."),
+                ]),
+                alloc.concat([
+                    alloc.reflow("To keep the builtin, stop exposing "),
+                    alloc.symbol_unqualified(new_symbol),
+                    alloc.reflow(" in this import and write "),
+                    alloc.symbol_qualified(new_symbol),
+                    alloc.reflow(" wherever you mean the imported one."),
+                ]),
+            ]);
+
+            title = IMPORT_SHADOWS_BUILTIN.to_string();
+        }
+        Warning::UsedUnderscoreBinding {
+            name: _,
+            region,
+            declaration_region,
+        } => {
+            doc = alloc.stack([
+                alloc.reflow("This variable's name starts with an underscore:"),
+                alloc.region(lines.convert_region(declaration_region), severity),
+                alloc.reflow("But then it is used here:"),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.reflow("A variable's name can only start with an underscore if the variable is unused. Since you are using this variable, you could remove the underscore from its name in both places."),
+            ]);
+
+            title = UNDERSCORE_BINDING_USED.to_string();
+        }
+    };
+
+    Report {
+        title,
+        filename,
+        doc,
+        severity,
+    }
+}
+
+/// Reports the canonicalization problems and warnings of a module in the order they occur in
+/// the source. Reports starting at the same position keep the order they were found in, and
+/// problems without a region come last.
+pub fn can_reports<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    problems: Vec<Problem>,
+    warnings: Vec<Warning>,
+) -> Vec<Report<'b>> {
+    let problems = problems.into_iter().map(|problem| {
+        let region = problem.region();
+
+        (region, can_problem(alloc, lines, filename.clone(), problem))
+    });
+    let warnings = warnings.into_iter().map(|warning| {
+        let region = Some(warning.region());
+
+        (region, can_warning(alloc, lines, filename.clone(), warning))
+    });
+
+    let mut reports: Vec<_> = problems.chain(warnings).collect();
+    reports.sort_by_key(|(region, _)| (region.is_none(), region.map(|region| region.start())));
+
+    reports.into_iter().map(|(_, report)| report).collect()
+}
+
 fn list_builtin_abilities<'a>(alloc: &'a RocDocAllocator<'a>) -> RocDocBuilder<'a> {
     alloc.intersperse(
         DERIVABLE_ABILITIES
@@ -2069,14 +2250,17 @@ fn pretty_runtime_error<'b>(
         RuntimeError::LookupNotInScope {
             loc_name,
             suggestion_options: options,
+            closest_suggestions,
             underscored_suggestion_region,
         } => {
+            let suggestions = rank_suggestions(&loc_name.value, options, closest_suggestions);
+
             doc = not_found(
                 alloc,
                 lines,
                 loc_name.region,
                 &loc_name.value,
-                options,
+                suggestions,
                 underscored_suggestion_region,
                 severity,
             );
@@ -2107,7 +2291,9 @@ fn pretty_runtime_error<'b>(
                 QualifiedIdentifier => " qualified ",
                 EmptySingleQuote => " empty character literal ",
                 MultipleCharsInSingleQuote => " overfull literal ",
-                DuplicateListRestPattern => " second rest pattern ",
+                DuplicateListRestPattern | DuplicateRecordRestPattern => " second rest pattern ",
+                DuplicateRecordField => " record ",
+                RecordRestPatternNotLast => " rest pattern ",
             };
 
             let tip = match problem {
@@ -2125,6 +2311,15 @@ fn pretty_runtime_error<'b>(
                 DuplicateListRestPattern => alloc
                     .tip()
                     .append(alloc.reflow("List patterns can only have one rest pattern")),
+                DuplicateRecordField => alloc
+                    .tip()
+                    .append(alloc.reflow("Record patterns can only destructure each field once")),
+                DuplicateRecordRestPattern => alloc
+                    .tip()
+                    .append(alloc.reflow("Record patterns can only have one rest pattern")),
+                RecordRestPatternNotLast => alloc.tip().append(
+                    alloc.reflow("The rest pattern of a record pattern must be its last field"),
+                ),
             };
 
             doc = alloc.stack([
@@ -2518,6 +2713,22 @@ fn pretty_runtime_error<'b>(
 
             title = MISSING_DEFINITION;
         }
+        RuntimeError::OverlappingSpecialization {
+            ability,
+            member,
+            for_type,
+        } => {
+            doc = alloc.stack([alloc.concat([
+                alloc.symbol_unqualified(for_type),
+                alloc.reflow(" already has a specialization of "),
+                alloc.symbol_unqualified(member),
+                alloc.reflow(" from the "),
+                alloc.symbol_foreign_qualified(ability),
+                alloc.reflow(" ability, so I can't register another one."),
+            ])]);
+
+            title = "OVERLAPPING SPECIALIZATION";
+        }
         RuntimeError::EmptySingleQuote(region) => {
             let tip = alloc
                 .tip()
@@ -2636,6 +2847,155 @@ fn pretty_runtime_error<'b>(
 
             title = OPAQUE_OVER_APPLIED;
         }
+        RuntimeError::OpaqueArityMismatch {
+            opaque,
+            expected,
+            got,
+            region,
+        } => {
+            let needed_arguments = if expected == 1 {
+                alloc.reflow("1 type argument")
+            } else {
+                alloc
+                    .text(expected.to_string())
+                    .append(alloc.reflow(" type arguments"))
+            };
+
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The "),
+                    alloc.symbol_unqualified(opaque),
+                    alloc.reflow(" opaque type expects "),
+                    needed_arguments,
+                    alloc.reflow(", but it got "),
+                    alloc.text(got.to_string()),
+                    alloc.reflow(" instead:"),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+            ]);
+
+            title = if got > expected {
+                "TOO MANY TYPE ARGUMENTS"
+            } else {
+                "TOO FEW TYPE ARGUMENTS"
+            };
+        }
+        RuntimeError::DuplicateAlias {
+            alias,
+            original_region,
+            duplicate_region,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The "),
+                    alloc.symbol_unqualified(alias),
+                    alloc.reflow(" type alias is defined more than once. It was first defined here:"),
+                ]),
+                alloc.region(lines.convert_region(original_region), severity),
+                alloc.reflow("But then it's defined a second time here:"),
+                alloc.region(lines.convert_region(duplicate_region), severity),
+                alloc.reflow("Since these aliases have the same name, it's easy to use the wrong one by accident. Give one of them a new name."),
+            ]);
+
+            title = DUPLICATE_NAME;
+        }
+        RuntimeError::DuplicateTypeVariable {
+            name,
+            first_region,
+            duplicate_region,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The type variable "),
+                    alloc.type_variable(name),
+                    alloc.reflow(" is declared more than once. It was first declared here:"),
+                ]),
+                alloc.region(lines.convert_region(first_region), severity),
+                alloc.reflow("But then it's declared a second time here:"),
+                alloc.region(lines.convert_region(duplicate_region), severity),
+                alloc.reflow(
+                    "All type variables of an alias need distinct names. Rename one of them.",
+                ),
+            ]);
+
+            title = DUPLICATE_NAME;
+        }
+        RuntimeError::DuplicatePatternBinding {
+            name,
+            first_region,
+            second_region,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This pattern binds "),
+                    alloc.ident(name),
+                    alloc.reflow(" more than once. It is first bound here:"),
+                ]),
+                alloc.region(lines.convert_region(first_region), severity),
+                alloc.reflow("But then it's bound a second time here:"),
+                alloc.region(lines.convert_region(second_region), severity),
+                alloc.reflow("Each name in a pattern must be distinct. Rename one of them."),
+            ]);
+
+            title = DUPLICATE_NAME;
+        }
+        RuntimeError::RecursiveAliasWithoutRecursionVar { symbol, region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" alias refers to itself:"),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.reflow(
+                    "Recursion in aliases is only allowed if recursion happens behind a \
+                    tagged union, at least one variant of which is not recursive.",
+                ),
+                alloc.concat([
+                    alloc.hint("Try defining "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" as a recursive tag union instead."),
+                ]),
+            ]);
+
+            title = CYCLIC_ALIAS;
+        }
+        RuntimeError::UnboundTypeVariablesInAlias {
+            alias,
+            region,
+            unbound,
+        } => {
+            let intro = if let [name] = unbound.as_slice() {
+                alloc.concat([
+                    alloc.reflow(" refers to the type variable "),
+                    alloc.type_variable(name.clone()),
+                    alloc.reflow(", which is not declared:"),
+                ])
+            } else {
+                alloc.concat([
+                    alloc.reflow(" refers to the type variables "),
+                    alloc.intersperse(
+                        unbound.into_iter().map(|name| alloc.type_variable(name)),
+                        alloc.reflow(", "),
+                    ),
+                    alloc.reflow(", which are not declared:"),
+                ])
+            };
+
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The definition of "),
+                    alloc.symbol_unqualified(alias),
+                    intro,
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.tip().append(alloc.reflow(
+                    "You can declare type variables by putting them right before the : symbol, separated by spaces.",
+                )),
+            ]);
+
+            title = UNDECLARED_TYPE_VARIABLE;
+        }
         RuntimeError::DegenerateBranch(region) => {
             doc = alloc.stack([
                 alloc.reflow("This branch pattern does not bind all symbols its body needs:"),
@@ -2733,21 +3093,40 @@ pub fn to_circular_def_doc<'b>(
     }
 }
 
+/// The closest suggestions were already ranked during canonicalization; make sure they're
+/// listed first, and fill up the remaining slots with whatever else is in scope.
+fn rank_suggestions(
+    name: &Ident,
+    options: MutSet<Box<str>>,
+    closest_suggestions: Vec<Box<str>>,
+) -> Vec<Box<str>> {
+    let other_options: Vec<&str> = options
+        .iter()
+        .map(|v| v.as_ref())
+        .filter(|v| {
+            !closest_suggestions
+                .iter()
+                .any(|closest| closest.as_ref() == *v)
+        })
+        .collect();
+    let other_options = suggest::sort(name.as_inline_str().as_str(), other_options);
+
+    let mut suggestions = closest_suggestions;
+    suggestions.extend(other_options.into_iter().map(Box::from));
+    suggestions.truncate(4);
+
+    suggestions
+}
+
 fn not_found<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
     region: roc_region::all::Region,
     name: &Ident,
-    options: MutSet<Box<str>>,
+    suggestions: Vec<Box<str>>,
     underscored_suggestion_region: Option<Region>,
     severity: Severity,
 ) -> RocDocBuilder<'b> {
-    let mut suggestions = suggest::sort(
-        name.as_inline_str().as_str(),
-        options.iter().map(|v| v.as_ref()).collect(),
-    );
-    suggestions.truncate(4);
-
     let default_no = alloc.concat([
         alloc.reflow("Is there an "),
         alloc.keyword("import"),
@@ -124,6 +124,41 @@ fn to_syntax_report<'a>(
                 severity,
             }
         }
+        UnclosedDelimiter { open_region } => {
+            let doc = alloc.stack([
+                alloc.reflow(r"This delimiter is never closed:"),
+                alloc.region(lines.convert_region(*open_region), severity),
+                alloc.reflow(
+                    "I treated it as closed right before the next top-level definition, \
+                    so I could keep looking for other problems.",
+                ),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "UNCLOSED DELIMITER".to_string(),
+                severity,
+            }
+        }
+        ReservedKeyword { keyword, region } => {
+            let doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword(keyword),
+                    alloc.reflow(" is used as a name, but it is a reserved keyword:"),
+                ]),
+                alloc.region(lines.convert_region(*region), severity),
+                alloc.reflow("Try picking a different name, so I can tell it is not the keyword."),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "RESERVED KEYWORD".to_string(),
+                severity,
+            }
+        }
         SyntaxError::Eof(region) => {
             let doc = alloc.stack([
                 alloc.reflow("End of Field"),
@@ -161,7 +196,7 @@ fn to_syntax_report<'a>(
 
         // If you're adding or changing syntax, please handle the case with a
         // good error message above instead of adding more unhandled cases below.
-        InvalidPattern | BadUtf8 | Todo | ReservedKeyword(_) | NotYetImplemented(_) | Space(_) => {
+        InvalidPattern | BadUtf8 | Todo | NotYetImplemented(_) | Space(_) => {
             to_unhandled_parse_error_report(
                 alloc,
                 lines,
@@ -578,6 +613,10 @@ fn to_expr_report<'a>(
             to_malformed_number_literal_report(alloc, lines, filename, pos)
         }
 
+        &EExpr::Number(ENumber::InvalidDigitSeparator(pos), _) => {
+            to_invalid_digit_separator_report(alloc, lines, filename, pos)
+        }
+
         EExpr::Ability(err, pos) => to_ability_def_report(alloc, lines, filename, err, *pos),
 
         EExpr::IndentEnd(pos) => {
@@ -1130,6 +1169,31 @@ fn to_str_report<'a>(
                 severity,
             }
         }
+        EString::EmptyInterpolation(pos) => {
+            let surroundings = Region::new(start, pos);
+            let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+
+            let doc = alloc.stack([
+                alloc.reflow(r"This string interpolation is empty:"),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                alloc.concat([
+                    alloc.reflow(r"Put an expression between the parentheses, like "),
+                    alloc.parser_suggestion("\"The count is $(count)\""),
+                    alloc.reflow(r". To write a literal "),
+                    alloc.parser_suggestion("$"),
+                    alloc.reflow(r", escape it like "),
+                    alloc.parser_suggestion("\\$"),
+                    alloc.reflow("."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "EMPTY INTERPOLATION".to_string(),
+                severity,
+            }
+        }
         EString::EndlessSingleQuote(pos) => {
             let surroundings = Region::new(start, pos);
             let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
@@ -1192,6 +1256,23 @@ fn to_str_report<'a>(
                         ]),
                     ])
                 }
+                ESingleQuote::MultipleChars => {
+                    alloc.stack([
+                        alloc.concat([
+                            alloc.reflow(r"I am part way through parsing this scalar literal (character literal), "),
+                            alloc.reflow(r"but it contains more than one character, so it's not a valid scalar."),
+                        ]),
+                        alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+                        alloc.concat([
+                            alloc.reflow(r"You could change it to something like "),
+                            alloc.parser_suggestion("'a'"),
+                            alloc.reflow(" or "),
+                            alloc.parser_suggestion("'\\n'"),
+                            alloc.reflow(". "),
+                            alloc.reflow("Note, roc strings use double quotes, like \"hello\".")
+                        ]),
+                    ])
+                }
                 ESingleQuote::InterpolationNotAllowed => {
                     alloc.stack([
                         alloc.concat([
@@ -2289,6 +2370,9 @@ fn to_pattern_report<'a>(
         &EPattern::NumLiteral(ENumber::End, pos) => {
             to_malformed_number_literal_report(alloc, lines, filename, pos)
         }
+        &EPattern::NumLiteral(ENumber::InvalidDigitSeparator(pos), _) => {
+            to_invalid_digit_separator_report(alloc, lines, filename, pos)
+        }
         // If you're adding or changing syntax, please handle the case with a
         // good error message above instead of adding more unhandled cases below.
         EPattern::AsKeyword(pos)
@@ -2677,6 +2761,36 @@ fn to_malformed_number_literal_report<'a>(
     }
 }
 
+fn to_invalid_digit_separator_report<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    pos: Position,
+) -> Report<'a> {
+    let surroundings = Region::new(pos, pos);
+    let region = LineColumnRegion::from_pos(lines.convert_pos(pos));
+    let severity = Severity::RuntimeError;
+
+    let doc = alloc.stack([
+        alloc.reflow(r"This underscore in a number literal is not between two digits:"),
+        alloc.region_with_subregion(lines.convert_region(surroundings), region, severity),
+        alloc.concat([
+            alloc.reflow("Underscores can separate groups of digits, like in "),
+            alloc.parser_suggestion("1_000_000"),
+            alloc.reflow(
+                ", but they cannot start or end a number, or come right after one another.",
+            ),
+        ]),
+    ]);
+
+    Report {
+        filename,
+        doc,
+        title: "INVALID NUMBER LITERAL".to_string(),
+        severity,
+    }
+}
+
 fn to_type_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
@@ -22,8 +22,8 @@ use roc_solve_problem::{
 use roc_std::RocDec;
 use roc_types::pretty_print::{Parens, WILDCARD};
 use roc_types::types::{
-    AbilitySet, AliasKind, Category, EarlyReturnKind, ErrorType, IndexOrField, PatternCategory,
-    Polarity, Reason, RecordField, TypeExt,
+    AbilitySet, AliasKind, Category, EarlyReturnKind, ErrorType, IndexOrField, Mismatch,
+    MismatchDetails, PatternCategory, Polarity, Reason, RecordField, TypeDiff, TypeExt,
 };
 use std::path::PathBuf;
 use ven_pretty::{text, DocAllocator};
@@ -57,8 +57,16 @@ pub fn type_problem<'b>(
         };
 
     match problem {
-        BadExpr(region, category, found, expected) => Some(to_expr_report(
-            alloc, lines, filename, severity, region, category, found, expected,
+        BadExpr(region, category, found, expected, mismatch_details) => Some(to_expr_report(
+            alloc,
+            lines,
+            filename,
+            severity,
+            region,
+            category,
+            found,
+            expected,
+            mismatch_details,
         )),
         BadPattern(region, category, found, expected) => Some(to_pattern_report(
             alloc, lines, filename, severity, region, category, found, expected,
@@ -226,6 +234,24 @@ pub fn type_problem<'b>(
                 severity,
             })
         }
+        BudgetExceeded(budget) => {
+            let stack = [
+                text!(
+                    alloc,
+                    "I gave up type checking this module after {} unifications.",
+                    budget
+                ),
+                alloc.reflow(
+                    "Types I had not finished inferring by then may be reported incorrectly.",
+                ),
+            ];
+            Some(Report {
+                title: "TYPE CHECKING BUDGET EXCEEDED".to_string(),
+                filename,
+                doc: alloc.stack(stack),
+                severity,
+            })
+        }
         IngestedFileUnsupportedType(file_path, typ) => {
             let stack = [
                 alloc.concat([
@@ -299,6 +325,7 @@ pub fn type_problem<'b>(
                     alloc,
                     actual_type,
                     expected_type,
+                    &MismatchDetails::default(),
                     ExpectationContext::Arbitrary,
                     alloc.reflow("This is the type I inferred:"),
                     alloc.concat([
@@ -772,6 +799,7 @@ fn report_mismatch<'b>(
     category: &Category,
     found: ErrorType,
     expected_type: ErrorType,
+    mismatch_details: &MismatchDetails,
     region: roc_region::all::Region,
     opt_highlight: Option<roc_region::all::Region>,
     problem: RocDocBuilder<'b>,
@@ -795,6 +823,7 @@ fn report_mismatch<'b>(
             alloc,
             found,
             expected_type,
+            mismatch_details,
             ExpectationContext::Arbitrary,
             add_category(alloc, this_is, category),
             instead_of,
@@ -883,6 +912,7 @@ fn to_expr_report<'b>(
     category: Category,
     found: ErrorType,
     expected: Expected<ErrorType>,
+    mismatch_details: MismatchDetails,
 ) -> Report<'b> {
     match expected {
         Expected::NoExpectation(expected_type) => {
@@ -923,6 +953,7 @@ fn to_expr_report<'b>(
                 alloc,
                 found,
                 expected_type,
+                &mismatch_details,
                 ExpectationContext::Arbitrary,
                 add_category(alloc, alloc.text("It is"), &category),
                 alloc.text("But you are trying to use it as:"),
@@ -1053,6 +1084,7 @@ fn to_expr_report<'b>(
                     alloc,
                     found,
                     expected_type,
+                    &mismatch_details,
                     expectation_context,
                     add_category(alloc, alloc.text(it_is), &category),
                     if is_suffixed_stmt {
@@ -1217,6 +1249,7 @@ fn to_expr_report<'b>(
                     &category,
                     found,
                     expected_type,
+                    &mismatch_details,
                     region,
                     Some(expr_region),
                     alloc.concat([
@@ -1252,6 +1285,7 @@ fn to_expr_report<'b>(
                     &category,
                     found,
                     expected_type,
+                    &mismatch_details,
                     region,
                     Some(expr_region),
                     alloc.concat([
@@ -1278,6 +1312,7 @@ fn to_expr_report<'b>(
                 &category,
                 found,
                 expected_type,
+                &mismatch_details,
                 expr_region,
                 Some(region),
                 alloc.concat([
@@ -1318,6 +1353,7 @@ fn to_expr_report<'b>(
                     &category,
                     found,
                     expected_type,
+                    &mismatch_details,
                     region,
                     Some(expr_region),
                     alloc.reflow("This list contains elements with different types:"),
@@ -1334,6 +1370,7 @@ fn to_expr_report<'b>(
                 &category,
                 found,
                 expected_type,
+                &mismatch_details,
                 region,
                 Some(expr_region),
                 alloc.concat([
@@ -1370,6 +1407,7 @@ fn to_expr_report<'b>(
                                 &category,
                                 found,
                                 expected_type,
+                                &mismatch_details,
                                 region,
                                 Some(expr_region),
                                 alloc.reflow("Something is off with this record update:"),
@@ -1567,6 +1605,7 @@ fn to_expr_report<'b>(
                     &category,
                     found,
                     expected_type,
+                    &mismatch_details,
                     expr_region,
                     Some(region),
                     alloc.concat([
@@ -1592,6 +1631,7 @@ fn to_expr_report<'b>(
                 &category,
                 found,
                 expected_type,
+                &mismatch_details,
                 region,
                 Some(expr_region),
                 alloc.text("This numeric literal is being used improperly:"),
@@ -1644,6 +1684,7 @@ fn to_expr_report<'b>(
                     &category,
                     found,
                     expected_type,
+                    &mismatch_details,
                     region,
                     Some(expr_region),
                     problem,
@@ -1685,6 +1726,7 @@ fn to_expr_report<'b>(
                     &category,
                     found,
                     expected_type,
+                    &mismatch_details,
                     region,
                     Some(expr_region),
                     problem,
@@ -1725,6 +1767,7 @@ fn to_expr_report<'b>(
                         alloc,
                         found,
                         expected_type,
+                        &mismatch_details,
                         ExpectationContext::WhenCondition,
                         add_category(alloc, this_is, &category),
                         wanted,
@@ -1798,6 +1841,7 @@ fn to_expr_report<'b>(
                         alloc,
                         found,
                         expected_type,
+                        &mismatch_details,
                         ExpectationContext::WhenCondition,
                         add_category(alloc, this_is, &category),
                         wanted,
@@ -1855,6 +1899,7 @@ fn to_expr_report<'b>(
                     alloc,
                     found,
                     expected_type,
+                    &mismatch_details,
                     ExpectationContext::Arbitrary,
                     add_category(alloc, alloc.text("It"), &category),
                     alloc.reflow("But I expected the function to have return type:"),
@@ -1930,6 +1975,7 @@ fn to_expr_report<'b>(
                     alloc,
                     found,
                     expected_type,
+                    &mismatch_details,
                     ExpectationContext::Arbitrary,
                     add_category(alloc, alloc.text("It is"), &category),
                     alloc.concat([
@@ -2030,6 +2076,7 @@ fn type_comparison<'b>(
     alloc: &'b RocDocAllocator<'b>,
     actual: ErrorType,
     expected: ErrorType,
+    mismatch_details: &MismatchDetails,
     expectation_context: ExpectationContext<'b>,
     i_am_seeing: RocDocBuilder<'b>,
     instead_of: RocDocBuilder<'b>,
@@ -2048,11 +2095,10 @@ fn type_comparison<'b>(
         lines.push(alloc.concat(context_hints));
     }
 
-    lines.extend(problems_to_tip(
-        alloc,
-        comparison.problems,
-        expectation_context,
-    ));
+    lines.extend(
+        problems_to_tip(alloc, comparison.problems, expectation_context)
+            .or_else(|| mismatches_to_tip(alloc, mismatch_details)),
+    );
 
     alloc.stack(lines)
 }
@@ -2644,6 +2690,173 @@ fn problems_to_tip<'b>(
     }
 }
 
+/// A tip built from what unification found out about the types, for when comparing the
+/// error types themselves did not point at anything in particular.
+fn mismatches_to_tip<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    mismatch_details: &MismatchDetails,
+) -> Option<RocDocBuilder<'b>> {
+    // The outermost mismatch comes last, and is the one the types in the report show.
+    match mismatch_details.mismatches.last()? {
+        Mismatch::RecordFieldMismatch {
+            missing_in_left,
+            missing_in_right,
+            type_mismatches,
+        } => {
+            let (fields, singular, plural) = if !missing_in_left.is_empty() {
+                (
+                    missing_in_left.clone(),
+                    ("This record is missing the ", " field."),
+                    ("This record is missing the ", " fields."),
+                )
+            } else if !missing_in_right.is_empty() {
+                (
+                    missing_in_right.clone(),
+                    ("The ", " field is not expected here."),
+                    ("The ", " fields are not expected here."),
+                )
+            } else if !type_mismatches.is_empty() {
+                let mut paths = Vec::new();
+
+                for (field, _) in type_mismatches {
+                    let field_diff = match &mismatch_details.diff {
+                        TypeDiff::Record { fields, .. } => fields
+                            .iter()
+                            .find(|(name, _)| name == field)
+                            .map(|(_, diff)| diff),
+                        _ => None,
+                    };
+
+                    wrong_field_paths(
+                        field_diff.unwrap_or(&TypeDiff::Same),
+                        field.to_string(),
+                        &mut paths,
+                    );
+                }
+
+                (
+                    paths.into_iter().map(Lowercase::from).collect(),
+                    ("The ", " field has the wrong type."),
+                    ("The ", " fields have the wrong types."),
+                )
+            } else {
+                return None;
+            };
+
+            let (before, after) = if fields.len() == 1 { singular } else { plural };
+
+            let fields = fields
+                .into_iter()
+                .map(|field| alloc.record_field(field))
+                .collect();
+
+            Some(alloc.concat([
+                alloc.tip(),
+                alloc.reflow(before),
+                and_list(alloc, fields),
+                alloc.reflow(after),
+            ]))
+        }
+        Mismatch::TupleArityMismatch {
+            left_arity,
+            right_arity,
+        } => Some(alloc.concat([
+            alloc.tip(),
+            alloc.reflow("The tuple has "),
+            alloc.text(elements_count(*left_arity)),
+            alloc.reflow(" in the first type, but "),
+            alloc.text(elements_count(*right_arity)),
+            alloc.reflow(" in the second."),
+        ])),
+        Mismatch::TagMismatch {
+            tag,
+            left_arity,
+            right_arity,
+        } => Some(alloc.concat([
+            alloc.tip(),
+            alloc.reflow("The "),
+            alloc.tag_name(tag.clone()),
+            alloc.reflow(" tag has "),
+            alloc.text(payloads_count(*left_arity)),
+            alloc.reflow(" in the first type, but "),
+            alloc.text(payloads_count(*right_arity)),
+            alloc.reflow(" in the second."),
+        ])),
+        Mismatch::TagPayloadMismatch { tag, payloads } => {
+            let (before, after) = if payloads.len() == 1 {
+                (" payload of the ", " tag has the wrong type.")
+            } else {
+                (" payloads of the ", " tag have the wrong types.")
+            };
+            let payloads = payloads
+                .iter()
+                .map(|index| alloc.text(HumanIndex::zero_based(*index).ordinal()))
+                .collect();
+
+            Some(alloc.concat([
+                alloc.tip(),
+                alloc.reflow("The "),
+                and_list(alloc, payloads),
+                alloc.reflow(before),
+                alloc.tag_name(tag.clone()),
+                alloc.reflow(after),
+            ]))
+        }
+        Mismatch::TypeMismatch
+        | Mismatch::TypeNotInRange
+        | Mismatch::DisjointLambdaSets
+        | Mismatch::DoesNotImplementAbiity(..) => None,
+    }
+}
+
+/// Follows a field that has the wrong type into the records nested in it, as far as `diff` can
+/// tell where they differ, so that the tip can say `.person.age` rather than just `.person`.
+fn wrong_field_paths(diff: &TypeDiff, path: String, paths: &mut Vec<String>) {
+    match diff {
+        TypeDiff::Record {
+            fields,
+            missing_in_left,
+            missing_in_right,
+        } if missing_in_left.is_empty() && missing_in_right.is_empty() => {
+            for (name, field_diff) in fields.iter().filter(|(_, diff)| !diff.is_same()) {
+                wrong_field_paths(field_diff, format!("{path}.{name}"), paths);
+            }
+        }
+        _ => paths.push(path),
+    }
+}
+
+fn elements_count(count: usize) -> String {
+    match count {
+        1 => "1 element".to_string(),
+        _ => format!("{count} elements"),
+    }
+}
+
+fn payloads_count(count: usize) -> String {
+    match count {
+        0 => "no payloads".to_string(),
+        1 => "1 payload".to_string(),
+        _ => format!("{count} payloads"),
+    }
+}
+
+/// Lists things like "a, b and c"
+fn and_list<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    mut items: Vec<RocDocBuilder<'b>>,
+) -> RocDocBuilder<'b> {
+    match items.pop() {
+        None => alloc.nil(),
+        Some(last) if items.is_empty() => last,
+        Some(last) => alloc.concat([
+            alloc.intersperse(items, alloc.reflow(", ")),
+            alloc.reflow(" and "),
+            last,
+        ]),
+    }
+}
+
 pub mod suggest {
     use roc_module::ident::Lowercase;
 
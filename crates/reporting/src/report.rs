@@ -12,7 +12,7 @@ use byte_unit::Byte;
 #[cfg(not(target_family = "wasm"))]
 use roc_packaging::https::Problem;
 
-pub use crate::error::canonicalize::can_problem;
+pub use crate::error::canonicalize::{can_problem, can_reports, can_warning};
 pub use crate::error::parse::parse_problem;
 pub use crate::error::r#type::type_problem;
 
@@ -735,6 +735,14 @@ impl<'a> RocDocAllocator<'a> {
     ) -> DocBuilder<'a, Self, Annotation> {
         // debug_assert!(region.contains(&sub_region));
 
+        // Symbols the compiler generated get a zero region, and pointing at the start of the
+        // file for those would only be misleading.
+        if region.is_synthetic() && sub_region.is_synthetic() {
+            return self.note("").append(self.reflow(
+                "This was generated by the compiler, so there is no source code to show.",
+            ));
+        }
+
         // If the outer region takes more than 1 full screen (~60 lines), only show the inner region
         if region.end().line.saturating_sub(region.start().line) > 60 {
             // If the inner region contains the outer region (or if they are the same),
@@ -83,17 +83,19 @@ impl CanExpr {
                 // rules multiple times unnecessarily.
                 let loc_expr = desugar::desugar_expr(&mut env, &mut scope, &loc_expr);
 
-                scope.add_alias(
-                    Symbol::NUM_INT,
-                    Region::zero(),
-                    vec![Loc::at_zero(AliasVar::unbound(
-                        "a".into(),
-                        Variable::EMPTY_RECORD,
-                    ))],
-                    vec![],
-                    Type::EmptyRec,
-                    roc_types::types::AliasKind::Structural,
-                );
+                scope
+                    .add_alias(
+                        Symbol::NUM_INT,
+                        Region::zero(),
+                        vec![Loc::at_zero(AliasVar::unbound(
+                            "a".into(),
+                            Variable::EMPTY_RECORD,
+                        ))],
+                        vec![],
+                        Type::EmptyRec,
+                        roc_types::types::AliasKind::Structural,
+                    )
+                    .unwrap();
 
                 let (loc_expr, output) = canonicalize_expr(
                     &mut env,
@@ -12,7 +12,8 @@ use roc_module::symbol::ModuleId;
 use roc_region::all::Region;
 use roc_solve::{
     module::{SolveConfig, Solved},
-    solve, Aliases,
+    solve::{self, GeneralizationTrace},
+    Aliases,
 };
 use roc_solve_problem::TypeError;
 use roc_types::subs::{Subs, Variable};
@@ -24,6 +25,7 @@ pub struct SolvedExprOut {
     pub problems: Vec<TypeError>,
     pub var: Variable,
     pub subs: Solved<Subs>,
+    pub generalizations: Vec<GeneralizationTrace>,
 }
 
 #[derive(Default)]
@@ -50,6 +52,8 @@ impl SolvedExpr {
             module_params: None,
             module_params_vars: VecMap::default(),
             host_exposed_symbols: None,
+            trace_generalization: true,
+            step_budget: None,
             #[cfg(debug_assertions)]
             checkmate: None,
         };
@@ -68,6 +72,7 @@ impl SolvedExpr {
             problems,
             var: constrained_expr_out.var,
             subs: solve_output.solved,
+            generalizations: solve_output.generalizations,
         }
     }
 
@@ -83,3 +88,87 @@ impl SolvedExpr {
         self.constrained_expr.home()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::SolvedExpr;
+    use roc_module::symbol::Symbol;
+    use roc_types::num::number_default;
+    use roc_types::subs::{Content, FlatType, GetSubsSlice, Rank};
+
+    #[test]
+    fn identity_generalizes_argument_and_result() {
+        let solved_expr = SolvedExpr::default();
+        let out = solved_expr.solve_expr("id = \\x -> x\n\nid");
+        let subs = out.subs.inner();
+
+        let trace = out
+            .generalizations
+            .iter()
+            .find(|trace| !trace.symbols.is_empty())
+            .expect("`id` was not generalized");
+
+        let (arg, ret) = trace
+            .generalized
+            .iter()
+            .find_map(|&var| match subs.get_content_without_compacting(var) {
+                Content::Structure(FlatType::Func(args, _, ret, _)) => {
+                    Some((subs.get_subs_slice(*args)[0], *ret))
+                }
+                _ => None,
+            })
+            .expect("the type of `id` was not generalized");
+
+        for var in [arg, ret] {
+            let explained = subs.explain_variable(var);
+
+            assert_eq!(explained.rank, Rank::GENERALIZED);
+            assert!(trace.generalized.contains(&explained.root));
+            assert!(!trace.kept.iter().any(|(kept, _)| *kept == explained.root));
+        }
+    }
+
+    fn default_of(input: &str) -> Option<Symbol> {
+        let solved_expr = SolvedExpr::default();
+        let out = solved_expr.solve_expr(input);
+
+        number_default(out.subs.inner(), out.var)
+    }
+
+    #[test]
+    fn standalone_int_literal_defaults_to_i64() {
+        assert_eq!(default_of("5"), Some(Symbol::NUM_I64));
+    }
+
+    #[test]
+    fn standalone_frac_literal_defaults_to_dec() {
+        assert_eq!(default_of("1.5"), Some(Symbol::NUM_DEC));
+    }
+
+    #[test]
+    fn typed_literal_is_not_defaulted() {
+        assert_eq!(default_of("5u8"), None);
+    }
+
+    #[test]
+    fn polymorphic_literal_is_not_defaulted() {
+        let solved_expr = SolvedExpr::default();
+        let out = solved_expr.solve_expr("x = 5\n\nx");
+        let subs = out.subs.inner();
+
+        let trace = out
+            .generalizations
+            .iter()
+            .find(|trace| !trace.symbols.is_empty())
+            .expect("`x` was not generalized");
+
+        // `x` itself stays `Num *`, to be decided by each use...
+        assert!(trace
+            .generalized
+            .iter()
+            .all(|&var| number_default(subs, var).is_none()));
+
+        // ...while this use of `x` has nothing else constraining it.
+        assert_eq!(number_default(subs, out.var), Some(Symbol::NUM_I64));
+    }
+}